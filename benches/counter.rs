@@ -11,7 +11,7 @@ enum Action {
     Decrement,
 }
 
-fn reducer(state: &State, action: &Action) -> State {
+fn reducer(state: &State, action: Action) -> State {
     match action {
         Action::Increment => state + 1,
         Action::Decrement => state - 1,
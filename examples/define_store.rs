@@ -0,0 +1,49 @@
+use redux_rs::define_store;
+
+// The state of a small todo list.
+#[derive(Default)]
+struct State {
+    todos: Vec<String>,
+}
+
+// The actions, adding and removing todos.
+enum Action {
+    Add(String),
+    Remove(usize),
+    Clear,
+}
+
+// This generates a `todos` module with the reducer and a `make_store` constructor, one handler per action.
+define_store! {
+    mod todos {
+        state: State = State::default(),
+        action: Action,
+        handlers {
+            Add(todo) => |state| {
+                let mut todos = state.todos.clone();
+                todos.push(todo);
+                State { todos }
+            },
+            Remove(index) => |state| {
+                let mut todos = state.todos.clone();
+                todos.remove(index);
+                State { todos }
+            },
+            Clear => |_state| State::default(),
+        }
+    }
+}
+
+fn main() {
+    // The store is ready to use, without writing the reducer by hand.
+    let mut store = todos::make_store();
+
+    store.subscribe(|state: &State| {
+        println!("Todos: {:?}", state.todos);
+    });
+
+    store.dispatch(Action::Add("Write the reducer".to_string()));
+    store.dispatch(Action::Add("Skip writing the reducer".to_string()));
+    store.dispatch(Action::Remove(0));
+    store.dispatch(Action::Clear);
+}
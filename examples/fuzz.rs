@@ -0,0 +1,46 @@
+use redux_rs::test::{fuzz_dispatch, Arbitrary, Rng, XorShift};
+use redux_rs::Store;
+
+// A bounded counter.
+type State = i8;
+
+// Increment and decrement actions for the counter.
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+// Pick increments and decrements at random.
+impl Arbitrary for Action {
+    fn arbitrary(rng: &mut dyn Rng) -> Self {
+        match rng.next_u64() % 2 {
+            0 => Action::Increment,
+            _ => Action::Decrement,
+        }
+    }
+}
+
+// Reducer for the counter, saturating instead of overflowing.
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state.saturating_add(1),
+        Action::Decrement => state.saturating_sub(1),
+    }
+}
+
+fn main() {
+    // Create the store.
+    let mut store = Store::new(reducer, 0);
+
+    // Dispatch lots of random actions, which must never overflow.
+    fuzz_dispatch(
+        &mut store,
+        100_000,
+        &mut XorShift::new(7),
+        |state: &State| (State::MIN..=State::MAX).contains(state),
+    );
+
+    // Print final value.
+    println!("Survived fuzzing! Final value: {}", store.state());
+}
@@ -6,6 +6,7 @@
 use redux_rs::Store;
 use std::default::Default;
 
+#[allow(dead_code)]
 #[derive(Clone, Debug)]
 struct Todo {
     name: &'static str,
@@ -42,7 +43,7 @@ fn main() {
             let mut todos = state.todos.clone();
             match action {
                 TodoAction::Insert(name) => {
-                    let todo = Todo { name: name };
+                    let todo = Todo { name };
                     todos.push(todo);
                 }
             };
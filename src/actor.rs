@@ -0,0 +1,183 @@
+use crate::Store;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// A store living on a dedicated worker thread, for offloading expensive reducers.
+///
+/// Actions are sent to the worker over a channel, so dispatching never blocks.
+/// After each action, the worker publishes a snapshot of the state, which can be read or waited for from any thread.
+/// Middleware and subscriptions of the underlying store run on the worker thread.
+///
+/// The store is created on the worker thread by a factory, so neither the reducer nor the middleware have to be `Send`.
+/// Dropping the actor processes the remaining actions, then stops the worker.
+///
+/// Warning: this requires `State` to be `Clone` and `Send`, and `Action` to be `Send`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{ActorStore, Store};
+/// #
+/// fn reducer(state: &u64, increment: u64) -> u64 {
+///     state + increment
+/// }
+///
+/// let actor = ActorStore::spawn(|| Store::new(reducer, 0));
+///
+/// actor.dispatch(1);
+/// actor.dispatch(2);
+///
+/// assert_eq!(actor.wait_for(|state| *state == 3), 3);
+/// ```
+pub struct ActorStore<State, Action> {
+    sender: Option<Sender<Action>>,
+    snapshot: Arc<Snapshot<State>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+/// The latest state published by the worker, along with a condition variable signalling new ones.
+struct Snapshot<State> {
+    state: Mutex<State>,
+    published: Condvar,
+    dead: AtomicBool,
+}
+
+/// Marks the snapshot as dead if the worker unwinds, waking up every waiter.
+struct MarkDeadOnPanic<State>(Arc<Snapshot<State>>);
+
+impl<State> Drop for MarkDeadOnPanic<State> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            let _state = self.0.state.lock();
+            self.0.dead.store(true, Ordering::SeqCst);
+            self.0.published.notify_all();
+        }
+    }
+}
+
+impl<State, Action> ActorStore<State, Action>
+where
+    State: Clone + Send + 'static,
+    Action: Send + 'static,
+{
+    /// Spawns a worker thread running the store created by `make_store`.
+    pub fn spawn<F>(make_store: F) -> Self
+    where
+        F: FnOnce() -> Store<State, Action> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let (ready_sender, ready_receiver) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            let mut store = make_store();
+            let snapshot = Arc::new(Snapshot {
+                state: Mutex::new(store.state().clone()),
+                published: Condvar::new(),
+                dead: AtomicBool::new(false),
+            });
+            if ready_sender.send(snapshot.clone()).is_err() {
+                return;
+            }
+            let _guard = MarkDeadOnPanic(snapshot.clone());
+
+            for action in receiver {
+                store.dispatch(action);
+                *snapshot.state.lock().unwrap() = store.state().clone();
+                snapshot.published.notify_all();
+            }
+        });
+
+        let snapshot = ready_receiver
+            .recv()
+            .expect("the store factory panicked on the worker thread");
+        Self {
+            sender: Some(sender),
+            snapshot,
+            worker: Some(worker),
+        }
+    }
+
+    /// Sends an action to the worker, without waiting for it to be processed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker thread panicked, e.g. within the reducer.
+    pub fn dispatch(&self, action: Action) {
+        self.sender
+            .as_ref()
+            .expect("the worker is running until the actor is dropped")
+            .send(action)
+            .expect("the worker thread panicked");
+    }
+
+    /// Returns a copy of the latest state published by the worker.
+    pub fn state(&self) -> State {
+        self.snapshot.state.lock().unwrap().clone()
+    }
+
+    /// Blocks until the worker publishes a state satisfying `predicate`, returning a copy of it.
+    ///
+    /// Returns immediately if the latest state already satisfies it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker thread panicked before publishing such a state.
+    pub fn wait_for<P: Fn(&State) -> bool>(&self, predicate: P) -> State {
+        let state = self.snapshot.state.lock().unwrap();
+        let state = self
+            .snapshot
+            .published
+            .wait_while(state, |state| !predicate(state) && !self.is_dead())
+            .unwrap();
+        if !predicate(&state) {
+            panic!("the worker thread panicked");
+        }
+        state.clone()
+    }
+
+    /// Blocks like [`wait_for`](#method.wait_for), giving up after `timeout`.
+    ///
+    /// Returns `None` if no published state satisfied `predicate` in time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker thread panicked before publishing such a state.
+    pub fn wait_for_timeout<P: Fn(&State) -> bool>(
+        &self,
+        predicate: P,
+        timeout: Duration,
+    ) -> Option<State> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.snapshot.state.lock().unwrap();
+        while !predicate(&state) {
+            if self.is_dead() {
+                panic!("the worker thread panicked");
+            }
+            let remaining = deadline.checked_duration_since(Instant::now())?;
+            state = self
+                .snapshot
+                .published
+                .wait_timeout(state, remaining)
+                .unwrap()
+                .0;
+        }
+        Some(state.clone())
+    }
+
+    /// Returns `true` if the worker thread panicked.
+    fn is_dead(&self) -> bool {
+        self.snapshot.dead.load(Ordering::SeqCst)
+    }
+}
+
+impl<State, Action> Drop for ActorStore<State, Action> {
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
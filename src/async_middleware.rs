@@ -0,0 +1,203 @@
+use crate::{Box, Store};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A boxed future resolving to the action to pass on, if any.
+pub type ActionFuture<Action> = Pin<Box<dyn Future<Output = Option<Action>>>>;
+
+pub trait AsyncMiddleware<State, Action> {
+    fn next(&self, store: &Store<State, Action>, action: Action) -> ActionFuture<Action>;
+}
+
+/// Function signature for an async middleware.
+///
+/// Async middleware works like regular middleware, except it resolves to the `Option<Action>` asynchronously.
+/// The returned future cannot borrow the store: read whatever is needed from the store before creating it.
+/// Requires the `async` feature.
+///
+/// Async middleware only runs when dispatching using [`Store::dispatch_async`](struct.Store.html#method.dispatch_async).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// # fn reducer(state: &u8, value: u8) -> u8 {
+/// #     value
+/// # }
+/// #
+/// async fn fetch(value: u8) -> u8 {
+///     value * 2
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.add_async_middleware(|_: &Store<u8, u8>, value: u8| async move {
+///     Some(fetch(value).await)
+/// });
+/// ```
+impl<State, Action, Function, Output> AsyncMiddleware<State, Action> for Function
+where
+    Function: Fn(&Store<State, Action>, Action) -> Output,
+    Output: Future<Output = Option<Action>> + 'static,
+{
+    fn next(&self, store: &Store<State, Action>, action: Action) -> ActionFuture<Action> {
+        Box::pin(self(store, action))
+    }
+}
+
+pub trait Stream {
+    type Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+}
+
+/// Function signature for a stream, an asynchronous source of items.
+///
+/// The function gets polled for the next item, returning `Poll::Ready(None)` once the stream ended.
+/// Streams from other libraries, e.g. `futures::Stream`, can be adapted by polling them from such a function.
+/// Requires the `async` feature.
+///
+/// See [`Store::dispatch_stream`](struct.Store.html#method.dispatch_stream).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Stream;
+/// use std::task::Poll;
+///
+/// let mut actions = vec![1, 2, 3].into_iter();
+/// let stream = move |_: &mut std::task::Context<'_>| Poll::Ready(actions.next());
+/// ```
+impl<Item, Function> Stream for Function
+where
+    Function: FnMut(&mut Context<'_>) -> Poll<Option<Item>> + Unpin,
+{
+    type Item = Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Item>> {
+        (*self)(cx)
+    }
+}
+
+/// A boxed task, as handed to a spawn function by [`Store::dispatch_from_future`](struct.Store.html#method.dispatch_from_future).
+pub type TaskFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A boxed future resolving after some time.
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+pub trait Timer {
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+/// Function signature for a timer.
+///
+/// A timer returns a future resolving once the given duration passed.
+/// Async features of the store waiting for some time use it, so tests can control time using a
+/// [`TestScheduler`](test/struct.TestScheduler.html).
+/// By default, the store sleeps using a helper thread, which works with any executor.
+/// Requires the `async` feature.
+///
+/// See [`Store::set_timer`](struct.Store.html#method.set_timer).
+impl<Function> Timer for Function
+where
+    Function: Fn(Duration) -> SleepFuture,
+{
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        self(duration)
+    }
+}
+
+/// Sleeps using a helper thread.
+pub(crate) fn thread_sleep(duration: Duration) -> SleepFuture {
+    Box::pin(Delay::new(duration))
+}
+
+/// Error returned when an async dispatch did not complete in time.
+///
+/// See [`Store::dispatch_async_timeout`](struct.Store.html#method.dispatch_async_timeout).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DispatchTimeout;
+
+impl fmt::Display for DispatchTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dispatch timed out")
+    }
+}
+
+impl std::error::Error for DispatchTimeout {}
+
+/// Future resolving once a deadline passed, using a helper thread to wake the task.
+struct Delay {
+    deadline: Instant,
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            waker: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let now = Instant::now();
+        if now >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        match &self.waker {
+            Some(waker) => *waker.lock().unwrap() = cx.waker().clone(),
+            None => {
+                let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                let timer_waker = waker.clone();
+                let remaining = self.deadline - now;
+                thread::spawn(move || {
+                    thread::sleep(remaining);
+                    timer_waker.lock().unwrap().wake_by_ref();
+                });
+                self.waker = Some(waker);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// Future racing another future against a delay.
+pub(crate) struct Timeout<F> {
+    future: Pin<Box<F>>,
+    delay: SleepFuture,
+}
+
+impl<F: Future> Timeout<F> {
+    pub(crate) fn new(future: F, delay: SleepFuture) -> Self {
+        Self {
+            future: Box::pin(future),
+            delay,
+        }
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, DispatchTimeout>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        match self.delay.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(DispatchTimeout)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Box;
+use core::mem;
+
+/// Storage holding the state of a store, e.g. to keep a very large state in a memory-mapped file or a database.
+///
+/// The store reads the state through [`get`](#tymethod.get), and the reducer modifies it in place through [`get_mut`](#tymethod.get_mut),
+/// after which [`commit`](#method.commit) gets called so the backend can persist the changes.
+/// Since both hand out references, a backend keeps the state (or the slices of it in use) loaded while the store is using it.
+///
+/// See [`Store::new_with_backend`](struct.Store.html#method.new_with_backend).
+///
+/// # Performance
+///
+/// Stores created otherwise keep their state inline, without any indirection.
+/// A backend adds a dynamic call to every state access, plus whatever `commit` costs after every reduction,
+/// so it is only worth it when the state does not fit comfortably in memory.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{StateBackend, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// struct LoggingBackend {
+///     state: u8
+/// }
+///
+/// impl StateBackend<u8> for LoggingBackend {
+///     fn get(&self) -> &u8 {
+///         &self.state
+///     }
+///
+///     fn get_mut(&mut self) -> &mut u8 {
+///         &mut self.state
+///     }
+///
+///     fn commit(&mut self) {
+///         println!("Persisting {}", self.state);
+///     }
+///
+///     fn into_state(self: Box<Self>) -> u8 {
+///         self.state
+///     }
+/// }
+///
+/// let mut store = Store::new_with_backend(reducer, LoggingBackend { state: 0 });
+/// store.dispatch(1); // Prints "Persisting 1".
+/// ```
+pub trait StateBackend<State> {
+    /// Returns the state.
+    fn get(&self) -> &State;
+
+    /// Returns the state, allowing to modify it in place.
+    fn get_mut(&mut self) -> &mut State;
+
+    /// Replaces the state, returning the previous one.
+    fn set(&mut self, state: State) -> State {
+        let previous = mem::replace(self.get_mut(), state);
+        self.commit();
+        previous
+    }
+
+    /// Persists changes made through [`get_mut`](#tymethod.get_mut), doing nothing by default.
+    fn commit(&mut self) {}
+
+    /// Consumes the backend, returning the state.
+    fn into_state(self: Box<Self>) -> State;
+}
+
+/// A backend keeping the state in memory, behaving like a store without a backend.
+///
+/// See [`StateBackend`](trait.StateBackend.html).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InMemoryBackend<State> {
+    state: State,
+}
+
+impl<State> InMemoryBackend<State> {
+    /// Creates a backend holding `state`.
+    pub fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl<State> StateBackend<State> for InMemoryBackend<State> {
+    fn get(&self) -> &State {
+        &self.state
+    }
+
+    fn get_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    fn into_state(self: Box<Self>) -> State {
+        self.state
+    }
+}
+
+/// Where a store keeps its state: inline by default, or in a backend.
+pub(crate) enum StateStorage<State> {
+    Inline(State),
+    Backend(Box<dyn StateBackend<State>>),
+}
+
+impl<State> StateStorage<State> {
+    pub(crate) fn get(&self) -> &State {
+        match self {
+            StateStorage::Inline(state) => state,
+            StateStorage::Backend(backend) => backend.get(),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self) -> &mut State {
+        match self {
+            StateStorage::Inline(state) => state,
+            StateStorage::Backend(backend) => backend.get_mut(),
+        }
+    }
+
+    pub(crate) fn set(&mut self, state: State) -> State {
+        match self {
+            StateStorage::Inline(current) => mem::replace(current, state),
+            StateStorage::Backend(backend) => backend.set(state),
+        }
+    }
+
+    pub(crate) fn commit(&mut self) {
+        if let StateStorage::Backend(backend) = self {
+            backend.commit();
+        }
+    }
+
+    pub(crate) fn into_state(self) -> State {
+        match self {
+            StateStorage::Inline(state) => state,
+            StateStorage::Backend(backend) => backend.into_state(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::Vec;
+
+/// A unique id assigned to every dispatched action, in dispatch order.
+///
+/// See [`Store::action_graph`](struct.Store.html#method.action_graph).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ActionId(usize);
+
+/// The causality graph of the dispatched actions.
+///
+/// An action queued (e.g. by a thunk-like middleware) while another one was being dispatched records the latter as its cause,
+/// so following the causes of an action answers "why did this action fire?".
+/// Requires the `causality` feature.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// fn double_middleware(store: &Store<u8, u8>, increment: u8) -> Option<u8> {
+///     if increment > 1 {
+///         store.enqueue(increment / 2);
+///         store.enqueue(increment / 2);
+///         None
+///     } else {
+///         Some(increment)
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(double_middleware);
+///
+/// store.dispatch(2);
+/// store.drain_pending();
+///
+/// let graph = store.action_graph();
+/// let root = graph.roots()[0];
+/// assert_eq!(graph.children(root).len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ActionGraph {
+    causes: Vec<Option<ActionId>>,
+}
+
+impl ActionGraph {
+    /// Returns the number of actions dispatched so far.
+    pub fn len(&self) -> usize {
+        self.causes.len()
+    }
+
+    /// Returns whether no action was dispatched yet.
+    pub fn is_empty(&self) -> bool {
+        self.causes.is_empty()
+    }
+
+    /// Returns the ids of all actions, in dispatch order.
+    pub fn ids(&self) -> impl Iterator<Item = ActionId> {
+        (0..self.causes.len()).map(ActionId)
+    }
+
+    /// Returns the action which caused an action, if any.
+    pub fn cause(&self, id: ActionId) -> Option<ActionId> {
+        self.causes.get(id.0).copied().flatten()
+    }
+
+    /// Returns the chain of causes of an action, from its direct cause up to the action dispatched from outside.
+    pub fn causes(&self, id: ActionId) -> Vec<ActionId> {
+        let mut causes = Vec::new();
+        let mut current = id;
+        while let Some(cause) = self.cause(current) {
+            causes.push(cause);
+            current = cause;
+        }
+        causes
+    }
+
+    /// Returns the actions caused by an action, in dispatch order.
+    pub fn children(&self, id: ActionId) -> Vec<ActionId> {
+        self.ids()
+            .filter(|&child| self.cause(child) == Some(id))
+            .collect()
+    }
+
+    /// Returns the actions dispatched from outside, without a cause.
+    pub fn roots(&self) -> Vec<ActionId> {
+        self.ids().filter(|&id| self.cause(id).is_none()).collect()
+    }
+}
+
+/// Keeps track of the actions being dispatched.
+#[derive(Default)]
+pub(crate) struct Causality {
+    graph: ActionGraph,
+    current: Vec<ActionId>,
+}
+
+impl Causality {
+    pub(crate) fn graph(&self) -> &ActionGraph {
+        &self.graph
+    }
+
+    /// Returns the id of the innermost action being dispatched.
+    pub(crate) fn current(&self) -> Option<ActionId> {
+        self.current.last().copied()
+    }
+
+    /// Assigns an id to an action about to be dispatched.
+    pub(crate) fn begin(&mut self, cause: Option<ActionId>) {
+        let id = ActionId(self.graph.causes.len());
+        self.graph.causes.push(cause);
+        self.current.push(id);
+    }
+
+    /// Marks the innermost action as dispatched.
+    pub(crate) fn end(&mut self) {
+        self.current.pop();
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Rc;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// Function signature for a clock.
+///
+/// Features depending on time read it through a clock, so tests can control it using a [`ManualClock`](struct.ManualClock.html).
+/// `Instant::now` is the clock used by default.
+impl<Function> Clock for Function
+where
+    Function: Fn() -> Instant,
+{
+    fn now(&self) -> Instant {
+        self()
+    }
+}
+
+/// A clock only advancing when told to.
+///
+/// Clones share the same time, so a clone can be handed to a store while the original one drives time in a test.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Clock, ManualClock};
+/// use std::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+///
+/// clock.advance(Duration::from_secs(1));
+/// assert_eq!(clock.now() - start, Duration::from_secs(1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    now: Rc<Cell<Instant>>,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at the current time.
+    pub fn new() -> Self {
+        Self {
+            now: Rc::new(Cell::new(Instant::now())),
+        }
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
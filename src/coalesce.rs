@@ -0,0 +1,169 @@
+use crate::{Clock, Middleware, MiddlewareId, Rc, Store, Vec};
+use std::cell::{Cell, RefCell};
+use std::mem;
+use std::time::{Duration, Instant};
+
+/// A middleware batching rapid actions into a single composite one, e.g. for high-frequency input like mouse moves.
+///
+/// Actions get buffered (and halted) until the buffer is flushed, either once it holds a given number of actions
+/// or once an action arrives after a time window started by the first buffered one is over.
+/// On flush, the buffered actions are merged using the combining function and the result is passed on instead.
+///
+/// Since nothing gets dispatched in the meantime, actions still buffered once input stops, e.g. at the end of a frame,
+/// have to be delivered using [`flush`](#method.flush).
+/// Clones share their buffer, so keep a clone around to flush it after adding the middleware to a store.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{CoalesceMiddleware, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(CoalesceMiddleware::every(3, |increments: Vec<u8>| increments.iter().sum()));
+///
+/// store.dispatch(1);
+/// store.dispatch(2);
+/// assert_eq!(*store.state(), 0);
+///
+/// store.dispatch(3);
+/// assert_eq!(*store.state(), 6);
+/// ```
+///
+/// Flushing the trailing actions:
+///
+/// ```
+/// # use redux_rs::{CoalesceMiddleware, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let coalesce = CoalesceMiddleware::every(3, |increments: Vec<u8>| increments.iter().sum());
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(coalesce.clone());
+///
+/// store.dispatch(1);
+/// store.dispatch(2);
+/// coalesce.flush(&mut store);
+/// assert_eq!(*store.state(), 3);
+/// ```
+pub struct CoalesceMiddleware<Action> {
+    combine: Rc<dyn Fn(Vec<Action>) -> Action>,
+    flush: Flush,
+    clock: Rc<dyn Clock>,
+    buffer: Rc<RefCell<Vec<Action>>>,
+    started: Rc<Cell<Option<Instant>>>,
+    id: Rc<Cell<Option<MiddlewareId>>>,
+}
+
+/// When a [`CoalesceMiddleware`](struct.CoalesceMiddleware.html) flushes its buffer.
+#[derive(Clone, Copy)]
+enum Flush {
+    Count(usize),
+    Window(Duration),
+}
+
+impl<Action> CoalesceMiddleware<Action> {
+    /// Creates a middleware combining every `count` actions into one.
+    pub fn every<F>(count: usize, combine: F) -> Self
+    where
+        F: Fn(Vec<Action>) -> Action + 'static,
+    {
+        Self::with_flush(Flush::Count(count), combine)
+    }
+
+    /// Creates a middleware combining all actions dispatched within `window` into one.
+    ///
+    /// The window starts with the first buffered action, and the buffer is flushed by the first action arriving once it is over.
+    pub fn within<F>(window: Duration, combine: F) -> Self
+    where
+        F: Fn(Vec<Action>) -> Action + 'static,
+    {
+        Self::with_flush(Flush::Window(window), combine)
+    }
+
+    fn with_flush<F>(flush: Flush, combine: F) -> Self
+    where
+        F: Fn(Vec<Action>) -> Action + 'static,
+    {
+        Self {
+            combine: Rc::new(combine),
+            flush,
+            clock: Rc::new(Instant::now),
+            buffer: Rc::new(RefCell::new(Vec::new())),
+            started: Rc::new(Cell::new(None)),
+            id: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Uses the given clock to measure time windows.
+    ///
+    /// See [`Clock`](trait.Clock.html).
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Rc::new(clock);
+        self
+    }
+
+    /// Dispatches the buffered actions right away, combined into one, e.g. at the end of a frame.
+    ///
+    /// The combined action goes through the middleware following this one only, so it is not buffered again.
+    /// While dispatching is paused, it is queued like any other action (see [`Store::pause_dispatch`](struct.Store.html#method.pause_dispatch)).
+    /// Does nothing if no action is buffered.
+    pub fn flush<State>(&self, store: &mut Store<State, Action>) {
+        let actions = mem::take(&mut *self.buffer.borrow_mut());
+        self.started.set(None);
+        if actions.is_empty() {
+            return;
+        }
+
+        store.dispatch_after(self.id.get(), (self.combine)(actions));
+    }
+
+    /// Buffers an action, returning the buffered actions if they are due.
+    fn buffer(&self, action: Action) -> Option<Vec<Action>> {
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.push(action);
+
+        let due = match self.flush {
+            Flush::Count(count) => buffer.len() >= count,
+            Flush::Window(window) => {
+                let now = self.clock.now();
+                let started = self.started.get().unwrap_or(now);
+                self.started.set(Some(started));
+                now.duration_since(started) >= window
+            }
+        };
+
+        if due {
+            self.started.set(None);
+            Some(mem::take(&mut *buffer))
+        } else {
+            None
+        }
+    }
+}
+
+impl<Action> Clone for CoalesceMiddleware<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            combine: self.combine.clone(),
+            flush: self.flush,
+            clock: self.clock.clone(),
+            buffer: self.buffer.clone(),
+            started: self.started.clone(),
+            id: self.id.clone(),
+        }
+    }
+}
+
+impl<State, Action> Middleware<State, Action> for CoalesceMiddleware<Action> {
+    fn next(&self, store: &Store<State, Action>, action: Action) -> Option<Action> {
+        self.id.set(store.running_middleware());
+        self.buffer(action).map(|actions| (self.combine)(actions))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Rc;
+use core::cell::RefCell;
+
+pub trait ContextReducible<State, Action, Context> {
+    fn reduce(&self, state: &State, action: Action, context: &Context) -> State;
+}
+
+/// Function signature for a reducer receiving read-only context.
+///
+/// Context is external data the reducer depends on without being part of the state or actions, such as the locale or configuration.
+/// Rather than capturing it, the reducer receives it explicitly, so it stays pure over its inputs.
+///
+/// See [`Store::new_with_context`](struct.Store.html#method.new_with_context).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::ContextReducible;
+/// #
+/// struct Config {
+///     step: u8
+/// }
+///
+/// fn reducer(state: &u8, _: (), config: &Config) -> u8 {
+///     state + config.step
+/// }
+///
+/// assert_eq!(reducer.reduce(&1, (), &Config { step: 2 }), 3);
+/// ```
+impl<State, Action, Context, Function> ContextReducible<State, Action, Context> for Function
+where
+    Function: Fn(&State, Action, &Context) -> State,
+{
+    fn reduce(&self, state: &State, action: Action, context: &Context) -> State {
+        self(state, action, context)
+    }
+}
+
+/// The context of a store, kept aside so it can be replaced.
+pub(crate) struct ContextCell<Context>(pub(crate) Rc<RefCell<Context>>);
@@ -0,0 +1,99 @@
+/// What a [`counting_reducer`](fn.counting_reducer.html) does when the counter would overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Clamps the counter at its bounds.
+    Saturate,
+    /// Wraps around at the bounds, e.g. from `i8::MAX` to `i8::MIN`.
+    Wrap,
+    /// Ignores the action, leaving the counter unchanged.
+    Halt,
+    /// Panics, in release builds as well.
+    Panic,
+}
+
+/// Actions understood by a [`counting_reducer`](fn.counting_reducer.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CounterAction {
+    /// Adds one to the counter.
+    Increment,
+    /// Subtracts one from the counter.
+    Decrement,
+}
+
+/// Integers usable as the state of a [`counting_reducer`](fn.counting_reducer.html).
+///
+/// This is implemented for all primitive integer types.
+pub trait CounterValue: Copy {
+    /// Steps by one, returning `None` on overflow.
+    fn checked_step(self, action: CounterAction) -> Option<Self>;
+
+    /// Steps by one, clamping at the bounds.
+    fn saturating_step(self, action: CounterAction) -> Self;
+
+    /// Steps by one, wrapping around at the bounds.
+    fn wrapping_step(self, action: CounterAction) -> Self;
+}
+
+macro_rules! impl_counter_value {
+    ($($type:ty),*) => {
+        $(
+            impl CounterValue for $type {
+                fn checked_step(self, action: CounterAction) -> Option<Self> {
+                    match action {
+                        CounterAction::Increment => self.checked_add(1),
+                        CounterAction::Decrement => self.checked_sub(1),
+                    }
+                }
+
+                fn saturating_step(self, action: CounterAction) -> Self {
+                    match action {
+                        CounterAction::Increment => self.saturating_add(1),
+                        CounterAction::Decrement => self.saturating_sub(1),
+                    }
+                }
+
+                fn wrapping_step(self, action: CounterAction) -> Self {
+                    match action {
+                        CounterAction::Increment => self.wrapping_add(1),
+                        CounterAction::Decrement => self.wrapping_sub(1),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_counter_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Creates a counter reducer, handling overflow according to `policy`.
+///
+/// A plain `state + 1` panics on overflow in debug builds and silently wraps in release builds:
+/// this makes the choice explicit instead.
+/// See [`OverflowPolicy`](enum.OverflowPolicy.html) for the available behaviors.
+///
+/// To use it with another action type, map the actions first, e.g. using a closure.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{counting_reducer, CounterAction, OverflowPolicy, Store};
+/// #
+/// let mut store = Store::new(counting_reducer(OverflowPolicy::Saturate), i8::MAX - 1);
+///
+/// store.dispatch(CounterAction::Increment);
+/// store.dispatch(CounterAction::Increment);
+/// assert_eq!(*store.state(), i8::MAX);
+/// ```
+pub fn counting_reducer<State: CounterValue>(
+    policy: OverflowPolicy,
+) -> impl Fn(&State, CounterAction) -> State {
+    move |state: &State, action: CounterAction| match policy {
+        OverflowPolicy::Saturate => state.saturating_step(action),
+        OverflowPolicy::Wrap => state.wrapping_step(action),
+        OverflowPolicy::Halt => state.checked_step(action).unwrap_or(*state),
+        OverflowPolicy::Panic => match state.checked_step(action) {
+            Some(state) => state,
+            None => panic!("counter overflowed on {:?}", action),
+        },
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Middleware, Rc, Store};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A middleware counting how many times each type of action was dispatched.
+///
+/// The type of an action is given by a key extractor, and actions are always passed on.
+/// Clones share their counts, so keep a clone around to read them after adding the middleware to a store.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{CountingMiddleware, Store};
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// # fn reducer(state: &i8, action: Action) -> i8 {
+/// #     match action {
+/// #         Action::Increment => state + 1,
+/// #         Action::Decrement => state - 1
+/// #     }
+/// # }
+/// #
+/// let counting = CountingMiddleware::new(|action: &Action| match action {
+///     Action::Increment => "increment",
+///     Action::Decrement => "decrement"
+/// });
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(counting.clone());
+///
+/// store.dispatch(Action::Increment);
+/// store.dispatch(Action::Increment);
+/// assert_eq!(counting.counts()["increment"], 2);
+/// ```
+pub struct CountingMiddleware<Action> {
+    key: Rc<dyn Fn(&Action) -> &'static str>,
+    counts: Rc<RefCell<HashMap<&'static str, u64>>>,
+}
+
+impl<Action> CountingMiddleware<Action> {
+    /// Creates a middleware counting actions by the key returned by `key`.
+    pub fn new<F: Fn(&Action) -> &'static str + 'static>(key: F) -> Self {
+        Self {
+            key: Rc::new(key),
+            counts: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of dispatches per action type so far.
+    ///
+    /// Action types never dispatched are missing.
+    pub fn counts(&self) -> HashMap<&'static str, u64> {
+        self.counts.borrow().clone()
+    }
+
+    /// Resets all counts.
+    pub fn reset(&self) {
+        self.counts.borrow_mut().clear();
+    }
+}
+
+impl<Action> Clone for CountingMiddleware<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            counts: self.counts.clone(),
+        }
+    }
+}
+
+impl<State, Action> Middleware<State, Action> for CountingMiddleware<Action> {
+    fn next(&self, _: &Store<State, Action>, action: Action) -> Option<Action> {
+        *self
+            .counts
+            .borrow_mut()
+            .entry((self.key)(&action))
+            .or_insert(0) += 1;
+        Some(action)
+    }
+}
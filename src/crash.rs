@@ -0,0 +1,171 @@
+use crate::Store;
+use core::fmt::Debug;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// The details of a crash, passed to the reporter of a [`CrashReporterMiddleware`](struct.CrashReporterMiddleware.html).
+pub struct CrashReport<'a, State> {
+    /// The state when the crash happened, i.e. before the action was reduced unless a subscription panicked.
+    pub state: &'a State,
+    /// The action being dispatched, formatted using `Debug`.
+    pub action: String,
+    /// The panic message, if it was a string.
+    pub message: Option<String>,
+    /// A backtrace of where the panic was raised, see [`Backtrace::force_capture`](https://doc.rust-lang.org/std/backtrace/struct.Backtrace.html#method.force_capture).
+    ///
+    /// It is captured by a panic hook chained to the previously installed one, and only installed while a dispatch is being watched.
+    /// If the hook got replaced in the meantime, the backtrace is disabled.
+    pub backtrace: Backtrace,
+}
+
+/// A middleware reporting panics raised while dispatching, before raising them again.
+///
+/// It wraps the whole dispatch: the middleware chain, the around middleware, the reducer and subscriptions.
+/// Every action is formatted up front, as the action is consumed by the time a crash happens.
+/// See [`Store::set_crash_reporter`](struct.Store.html#method.set_crash_reporter).
+///
+/// Warning: this requires `Action` to be `Debug`.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use redux_rs::{CrashReport, CrashReporterMiddleware, Store};
+/// #
+/// fn reducer(state: &u8, divisor: u8) -> u8 {
+///     state / divisor
+/// }
+///
+/// let mut store = Store::new(reducer, 255);
+/// store.set_crash_reporter(CrashReporterMiddleware::new(|report: &CrashReport<u8>| {
+///     eprintln!("crashed dispatching {} on {}", report.action, report.state);
+/// }));
+///
+/// store.dispatch(0); // Reports the crash, then panics.
+/// ```
+pub struct CrashReporterMiddleware<Reporter> {
+    reporter: Reporter,
+}
+
+impl<Reporter> CrashReporterMiddleware<Reporter> {
+    /// Creates a middleware passing every crash to `reporter`.
+    pub fn new(reporter: Reporter) -> Self {
+        Self { reporter }
+    }
+}
+
+type Reporter<State> = Box<dyn Fn(&CrashReport<'_, State>)>;
+
+/// A crash reporter set on a store, along with the way to format its actions.
+pub(crate) struct CrashReporting<State, Action> {
+    reporter: Reporter<State>,
+    format_action: fn(&Action) -> String,
+}
+
+impl<State, Action> CrashReporting<State, Action> {
+    pub(crate) fn new<Reporter>(middleware: CrashReporterMiddleware<Reporter>) -> Self
+    where
+        Action: Debug,
+        Reporter: Fn(&CrashReport<'_, State>) + 'static,
+    {
+        Self {
+            reporter: Box::new(middleware.reporter),
+            format_action: |action| format!("{:?}", action),
+        }
+    }
+
+    /// Runs `dispatch`, reporting a panic it raises.
+    ///
+    /// Returns the panic payload, for the caller to raise it again.
+    pub(crate) fn watch<F>(
+        &self,
+        store: &mut Store<State, Action>,
+        action: Action,
+        dispatch: F,
+    ) -> Option<Box<dyn Any + Send>>
+    where
+        F: FnOnce(&mut Store<State, Action>, Action),
+    {
+        let formatted = (self.format_action)(&action);
+
+        install_backtrace_hook();
+        PANIC_BACKTRACE.with(|backtrace| backtrace.replace(None));
+        CATCHING.with(|catching| catching.set(catching.get() + 1));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| dispatch(&mut *store, action)));
+        CATCHING.with(|catching| catching.set(catching.get() - 1));
+        uninstall_backtrace_hook();
+
+        let payload = result.err()?;
+        let backtrace = PANIC_BACKTRACE.with(|backtrace| backtrace.borrow_mut().take());
+        (self.reporter)(&CrashReport {
+            state: store.state(),
+            action: formatted,
+            message: panic_message(payload.as_ref()),
+            backtrace: backtrace.unwrap_or_else(Backtrace::disabled),
+        });
+        Some(payload)
+    }
+}
+
+thread_local! {
+    /// How many crash reporters are waiting for a panic on this thread.
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    /// The backtrace of the last panic raised while a crash reporter was waiting.
+    static PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+type RestoreHook = Box<dyn FnOnce() + Send>;
+
+/// The number of dispatches watched across threads, along with a way to restore the hook in place before the first one.
+static BACKTRACE_HOOK: Mutex<(usize, Option<RestoreHook>)> = Mutex::new((0, None));
+
+/// Installs a panic hook keeping the backtrace of panics raised under a crash reporter, chained to the previous hook.
+///
+/// The hook stays installed until every watched dispatch is over, see [`uninstall_backtrace_hook`].
+fn install_backtrace_hook() {
+    let mut hook = BACKTRACE_HOOK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if hook.0 == 0 {
+        let previous = Arc::new(panic::take_hook());
+        let chained = previous.clone();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) > 0 {
+                PANIC_BACKTRACE
+                    .with(|backtrace| backtrace.replace(Some(Backtrace::force_capture())));
+            }
+            chained(info);
+        }));
+        hook.1 = Some(Box::new(move || {
+            drop(panic::take_hook());
+            match Arc::try_unwrap(previous) {
+                Ok(previous) => panic::set_hook(previous),
+                Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+            }
+        }));
+    }
+    hook.0 += 1;
+}
+
+/// Restores the previous panic hook once no dispatch is watched anymore.
+fn uninstall_backtrace_hook() {
+    let mut hook = BACKTRACE_HOOK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    hook.0 -= 1;
+    if hook.0 == 0 {
+        if let Some(restore) = hook.1.take() {
+            restore();
+        }
+    }
+}
+
+/// Extracts the message of a panic, if it was a string.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
@@ -0,0 +1,44 @@
+/// The severity of a diagnostics message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Fine-grained steps of every dispatch.
+    Trace,
+    /// Noteworthy events, such as a middleware halting an action.
+    Debug,
+}
+
+pub trait DiagnosticsLogger {
+    fn log(&self, level: LogLevel, message: &str);
+}
+
+/// Function signature for a diagnostics logger.
+///
+/// A diagnostics logger receives messages about the store's own control flow:
+/// actions being dispatched, middleware halting them, the reducer running and subscriptions being notified.
+/// This requires the `log` feature, without which the store is not instrumented at all.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{LogLevel, Store};
+/// #
+/// # fn reducer(state: &u8, _: ()) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.set_diagnostics_logger(|level: LogLevel, message: &str| {
+///     eprintln!("[{:?}] {}", level, message);
+/// });
+///
+/// store.dispatch(());
+/// ```
+impl<Function> DiagnosticsLogger for Function
+where
+    Function: Fn(LogLevel, &str),
+{
+    fn log(&self, level: LogLevel, message: &str) {
+        self(level, message)
+    }
+}
@@ -0,0 +1,39 @@
+/// States which can be compared, producing a patch describing what changed.
+///
+/// See [`Store::diff_from_checkpoint`](struct.Store.html#method.diff_from_checkpoint).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Diff;
+/// #
+/// #[derive(Clone)]
+/// struct State {
+///     user: Option<String>,
+///     unread: u32
+/// }
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Patch {
+///     user: Option<Option<String>>,
+///     unread: Option<u32>
+/// }
+///
+/// impl Diff for State {
+///     type Patch = Patch;
+///
+///     fn diff(&self, newer: &Self) -> Patch {
+///         Patch {
+///             user: Some(newer.user.clone()).filter(|user| *user != self.user),
+///             unread: Some(newer.unread).filter(|unread| *unread != self.unread)
+///         }
+///     }
+/// }
+/// ```
+pub trait Diff {
+    /// The description of a change.
+    type Patch;
+
+    /// Returns what changed from `self` to `newer`.
+    fn diff(&self, newer: &Self) -> Self::Patch;
+}
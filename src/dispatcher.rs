@@ -0,0 +1,30 @@
+use crate::Store;
+
+/// A handle queueing actions to a store, while its state is borrowed.
+///
+/// Created by [`Store::split`](struct.Store.html#method.split).
+/// Queued actions are dispatched by [`Store::drain_pending`](struct.Store.html#method.drain_pending).
+pub struct Dispatcher<'a, State, Action> {
+    store: &'a Store<State, Action>,
+}
+
+impl<'a, State, Action> Dispatcher<'a, State, Action> {
+    pub(crate) fn new(store: &'a Store<State, Action>) -> Self {
+        Self { store }
+    }
+
+    /// Queues an action to the store.
+    ///
+    /// See [`Store::enqueue`](struct.Store.html#method.enqueue).
+    pub fn dispatch(&self, action: Action) {
+        self.store.enqueue(action);
+    }
+}
+
+impl<State, Action> Clone for Dispatcher<'_, State, Action> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<State, Action> Copy for Dispatcher<'_, State, Action> {}
@@ -0,0 +1,55 @@
+use crate::{Box, Vec};
+use core::any::Any;
+use core::cell::RefCell;
+
+type EffectSubscriber = Box<dyn Fn(&dyn Any)>;
+
+/// Delivers effects emitted during dispatches to their subscribers.
+///
+/// Effects are type-erased, so a store can carry effects of any number of types.
+pub(crate) struct EffectBus {
+    subscribers: Vec<EffectSubscriber>,
+    collected: RefCell<Option<Vec<Box<dyn Any>>>>,
+}
+
+impl EffectBus {
+    pub(crate) fn new() -> Self {
+        Self {
+            subscribers: Vec::new(),
+            collected: RefCell::new(None),
+        }
+    }
+
+    pub(crate) fn subscribe<E: 'static, F: Fn(&E) + 'static>(&mut self, callback: F) {
+        self.subscribers.push(Box::new(move |effect: &dyn Any| {
+            if let Some(effect) = effect.downcast_ref::<E>() {
+                callback(effect);
+            }
+        }));
+    }
+
+    pub(crate) fn emit<E: 'static>(&self, effect: E) {
+        self.subscribers
+            .iter()
+            .for_each(|subscriber| subscriber(&effect));
+        if let Some(collected) = self.collected.borrow_mut().as_mut() {
+            collected.push(Box::new(effect));
+        }
+    }
+
+    /// Starts keeping emitted effects around.
+    pub(crate) fn start_collecting(&self) {
+        self.collected.replace(Some(Vec::new()));
+    }
+
+    /// Stops keeping emitted effects around, returning the ones of type `E`.
+    pub(crate) fn stop_collecting<E: 'static>(&self) -> Vec<E> {
+        self.collected
+            .replace(None)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|effect| effect.downcast::<E>().ok())
+            .map(|effect| *effect)
+            .collect()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Reducible;
+use std::collections::HashMap;
+
+/// An action along with metadata, such as where it came from.
+///
+/// A store dispatching envelopes lets middleware read and modify the metadata without changing the action itself.
+/// Reducers written for the bare action can be used with [`enveloped`](fn.enveloped.html).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{enveloped, ActionEnvelope, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let mut store = Store::new(enveloped(reducer), 0);
+/// store.add_middleware(|_: &Store<u8, ActionEnvelope<u8>>, envelope: ActionEnvelope<u8>| {
+///     if envelope.meta("source") == Some("replay") {
+///         println!("Replaying an increment by {}", envelope.action());
+///     }
+///     Some(envelope)
+/// });
+///
+/// store.dispatch(1.into());
+/// store.dispatch(ActionEnvelope::new(2).with_meta("source", "replay"));
+/// assert_eq!(*store.state(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionEnvelope<Action> {
+    action: Action,
+    meta: HashMap<String, String>,
+}
+
+impl<Action> ActionEnvelope<Action> {
+    /// Wraps an action, without any metadata.
+    pub fn new(action: Action) -> Self {
+        Self {
+            action,
+            meta: HashMap::new(),
+        }
+    }
+
+    /// Adds an entry to the metadata, replacing the previous value of `key` if any.
+    pub fn with_meta<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the wrapped action.
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+
+    /// Returns the wrapped action, like [`action`](#method.action).
+    ///
+    /// This reads naturally when observing envelopes, e.g. with [`Store::on_action`](struct.Store.html#method.on_action).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{enveloped, ActionEnvelope, Store};
+    /// #
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Action {
+    ///     Navigate(&'static str),
+    ///     Increment
+    /// }
+    ///
+    /// # fn reducer(state: &u8, action: Action) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// let mut store = Store::new(enveloped(reducer), 0);
+    ///
+    /// store.on_action(
+    ///     |envelope: &ActionEnvelope<Action>| matches!(envelope.inner(), Action::Navigate(_)),
+    ///     |envelope: &ActionEnvelope<Action>| println!("Navigation happened: {:?}", envelope.inner())
+    /// );
+    ///
+    /// store.dispatch(Action::Navigate("/home").into());
+    /// ```
+    pub fn inner(&self) -> &Action {
+        &self.action
+    }
+
+    /// Unwraps the action, dropping the metadata.
+    pub fn into_action(self) -> Action {
+        self.action
+    }
+
+    /// Returns the metadata value for `key`, if any.
+    pub fn meta(&self, key: &str) -> Option<&str> {
+        self.meta.get(key).map(String::as_str)
+    }
+
+    /// Returns all metadata, allowing to modify it.
+    pub fn meta_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.meta
+    }
+}
+
+impl<Action> From<Action> for ActionEnvelope<Action> {
+    fn from(action: Action) -> Self {
+        Self::new(action)
+    }
+}
+
+/// Adapts a reducer to envelopes, reducing the wrapped action and ignoring the metadata.
+///
+/// See [`ActionEnvelope`](struct.ActionEnvelope.html).
+pub fn enveloped<State, Action, R>(reducer: R) -> impl Fn(&State, ActionEnvelope<Action>) -> State
+where
+    R: Reducible<State, Action>,
+{
+    move |state: &State, envelope: ActionEnvelope<Action>| {
+        reducer.reduce(state, envelope.into_action())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Box, Vec};
+use core::any::{Any, TypeId};
+
+/// A map holding at most one value of each type, to share arbitrary data tied to a store.
+///
+/// Accessible using [`Store::extensions`](struct.Store.html#method.extensions) and
+/// [`Store::extensions_mut`](struct.Store.html#method.extensions_mut), including from within middleware.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Extensions;
+/// #
+/// struct Counter(u32);
+///
+/// let mut extensions = Extensions::new();
+/// extensions.insert(Counter(1));
+///
+/// if let Some(counter) = extensions.get_mut::<Counter>() {
+///     counter.0 += 1;
+/// }
+/// assert_eq!(extensions.get::<Counter>().map(|counter| counter.0), Some(2));
+/// ```
+#[derive(Default)]
+pub struct Extensions {
+    values: Vec<(TypeId, Box<dyn Any>)>,
+}
+
+impl Extensions {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value, returning the previous value of the same type, if any.
+    pub fn insert<T: 'static>(&mut self, value: T) -> Option<T> {
+        let previous = self.remove::<T>();
+        self.values.push((TypeId::of::<T>(), Box::new(value)));
+        previous
+    }
+
+    /// Returns the value of the given type, if any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<T>())
+            .and_then(|(_, value)| value.downcast_ref())
+    }
+
+    /// Returns the value of the given type mutably, if any.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .iter_mut()
+            .find(|(id, _)| *id == TypeId::of::<T>())
+            .and_then(|(_, value)| value.downcast_mut())
+    }
+
+    /// Removes the value of the given type, returning it if any.
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        let index = self
+            .values
+            .iter()
+            .position(|(id, _)| *id == TypeId::of::<T>())?;
+        self.values
+            .swap_remove(index)
+            .1
+            .downcast()
+            .ok()
+            .map(|value| *value)
+    }
+}
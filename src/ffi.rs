@@ -0,0 +1,111 @@
+//! A C interface to a store of integers.
+//!
+//! The store is monomorphized to `i64` states and actions, and handed out as an opaque [`ReduxStore`](struct.ReduxStore.html) pointer.
+//! The reducer and subscriptions are C function pointers.
+//!
+//! Requires the `ffi` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use redux_rs::ffi::*;
+//! use std::ffi::c_void;
+//! use std::ptr;
+//!
+//! extern "C" fn reducer(state: i64, increment: i64) -> i64 {
+//!     state + increment
+//! }
+//!
+//! extern "C" fn print_state(state: i64, _: *mut c_void) {
+//!     println!("New state: {}", state);
+//! }
+//!
+//! unsafe {
+//!     let store = redux_store_new(reducer, 0);
+//!     redux_store_subscribe(store, print_state, ptr::null_mut());
+//!
+//!     redux_store_dispatch(store, 2); // Prints "New state: 2".
+//!     assert_eq!(redux_store_get_state(store), 2);
+//!
+//!     redux_store_free(store);
+//! }
+//! ```
+
+use crate::{Box, Store};
+use core::ffi::c_void;
+
+/// A reducer implemented in C, returning the new state.
+pub type ReduxReducer = extern "C" fn(state: i64, action: i64) -> i64;
+
+/// A subscription implemented in C, receiving the new state along with the user data it was registered with.
+pub type ReduxSubscription = extern "C" fn(state: i64, user_data: *mut c_void);
+
+/// An opaque store of `i64` states and actions.
+pub struct ReduxStore(Store<i64, i64>);
+
+/// Creates a store, to be freed using [`redux_store_free`](fn.redux_store_free.html).
+#[no_mangle]
+pub extern "C" fn redux_store_new(reducer: ReduxReducer, initial_state: i64) -> *mut ReduxStore {
+    let store = Store::new(
+        move |state: &i64, action: i64| reducer(*state, action),
+        initial_state,
+    );
+    Box::into_raw(Box::new(ReduxStore(store)))
+}
+
+/// Dispatches an action.
+///
+/// # Safety
+///
+/// `store` must have been returned by [`redux_store_new`](fn.redux_store_new.html) and not been freed yet.
+/// Null pointers are ignored.
+#[no_mangle]
+pub unsafe extern "C" fn redux_store_dispatch(store: *mut ReduxStore, action: i64) {
+    if let Some(store) = store.as_mut() {
+        store.0.dispatch(action);
+    }
+}
+
+/// Returns the current state.
+///
+/// # Safety
+///
+/// `store` must have been returned by [`redux_store_new`](fn.redux_store_new.html) and not been freed yet.
+/// A null pointer returns 0.
+#[no_mangle]
+pub unsafe extern "C" fn redux_store_get_state(store: *const ReduxStore) -> i64 {
+    store.as_ref().map_or(0, |store| *store.0.state())
+}
+
+/// Subscribes a callback, called with the state and `user_data` whenever an action is dispatched.
+///
+/// # Safety
+///
+/// `store` must have been returned by [`redux_store_new`](fn.redux_store_new.html) and not been freed yet.
+/// `user_data` is passed as is, so it must stay valid for as long as the store lives.
+/// Null store pointers are ignored.
+#[no_mangle]
+pub unsafe extern "C" fn redux_store_subscribe(
+    store: *mut ReduxStore,
+    callback: ReduxSubscription,
+    user_data: *mut c_void,
+) {
+    if let Some(store) = store.as_mut() {
+        store
+            .0
+            .subscribe(move |state: &i64| callback(*state, user_data));
+    }
+}
+
+/// Frees a store.
+///
+/// # Safety
+///
+/// `store` must have been returned by [`redux_store_new`](fn.redux_store_new.html) and not been freed yet.
+/// Null pointers are ignored.
+#[no_mangle]
+pub unsafe extern "C" fn redux_store_free(store: *mut ReduxStore) {
+    if !store.is_null() {
+        drop(Box::from_raw(store));
+    }
+}
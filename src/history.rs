@@ -0,0 +1,229 @@
+use crate::{Box, Clock, Store, Vec, VecDeque};
+use std::time::{Duration, Instant};
+
+/// A store keeping snapshots of previous states, providing undo and redo.
+///
+/// Every dispatch records the state before it as an undo step, except for dispatches happening within the coalescing window
+/// of the previous one: rapid edits (e.g. typing) get merged into a single undo step.
+/// Use [`mark_undo_boundary`](#method.mark_undo_boundary) to force the next dispatch to start a new step.
+///
+/// Snapshots are full clones of the state, so the number of undo steps kept can be limited using [`set_limit`](#method.set_limit).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{HistoryStore, Store};
+/// #
+/// # fn reducer(state: &String, character: char) -> String {
+/// #     let mut text = state.clone();
+/// #     text.push(character);
+/// #     text
+/// # }
+/// #
+/// let mut history = HistoryStore::new(Store::new(reducer, String::new()));
+///
+/// history.dispatch('a');
+/// history.mark_undo_boundary();
+/// history.dispatch('b');
+/// assert_eq!(history.state(), "ab");
+///
+/// history.undo();
+/// assert_eq!(history.state(), "a");
+///
+/// history.redo();
+/// assert_eq!(history.state(), "ab");
+/// ```
+pub struct HistoryStore<State, Action> {
+    store: Store<State, Action>,
+    undo: VecDeque<State>,
+    redo: Vec<State>,
+    limit: Option<usize>,
+    window: Option<Duration>,
+    clock: Box<dyn Clock>,
+    last_edit: Option<Instant>,
+    actions: Vec<Action>,
+    clone_action: Option<fn(&Action) -> Action>,
+}
+
+impl<State: Clone, Action> HistoryStore<State, Action> {
+    /// Creates a history around a store.
+    ///
+    /// By default, the history is unlimited and edits are never coalesced.
+    pub fn new(store: Store<State, Action>) -> Self {
+        Self {
+            store,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit: None,
+            window: None,
+            clock: Box::new(Instant::now),
+            last_edit: None,
+            actions: Vec::new(),
+            clone_action: None,
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &Store<State, Action> {
+        &self.store
+    }
+
+    /// Returns the underlying store mutably, e.g. to subscribe to it.
+    ///
+    /// Actions dispatched directly on the underlying store are not recorded.
+    pub fn store_mut(&mut self) -> &mut Store<State, Action> {
+        &mut self.store
+    }
+
+    /// Sets the maximum number of undo steps kept, dropping the oldest ones.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = Some(limit);
+        self.trim();
+    }
+
+    /// Sets the time window within which consecutive dispatches are merged into a single undo step.
+    pub fn set_coalescing_window(&mut self, window: Duration) {
+        self.window = Some(window);
+    }
+
+    /// Sets the clock used to measure the time between dispatches.
+    pub fn set_clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.clock = Box::new(clock);
+    }
+
+    /// Starts recording every action dispatched from now on into an action log.
+    ///
+    /// The log is independent of undo and redo: it keeps every dispatched action reaching the reducer, in order.
+    ///
+    /// Warning: this requires `Action` to be `Clone`, as the store consumes the action.
+    pub fn record_actions(&mut self)
+    where
+        Action: Clone,
+    {
+        self.clone_action = Some(Action::clone);
+    }
+
+    /// Returns the recorded actions, see [`record_actions`](#method.record_actions).
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Folds over the recorded actions, e.g. to compute totals independently of the state.
+    ///
+    /// See [`record_actions`](#method.record_actions).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{HistoryStore, Store};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut history = HistoryStore::new(Store::new(reducer, 0));
+    /// history.record_actions();
+    ///
+    /// history.dispatch(1);
+    /// history.dispatch(5);
+    /// history.undo();
+    ///
+    /// let largest = history.aggregate(0, |largest, increment| largest.max(*increment));
+    /// assert_eq!(largest, 5);
+    /// ```
+    pub fn aggregate<Acc, F: Fn(Acc, &Action) -> Acc>(&self, init: Acc, f: F) -> Acc {
+        self.actions.iter().fold(init, f)
+    }
+
+    /// Dispatches an action, recording an undo step unless it gets coalesced into the previous one.
+    ///
+    /// Nothing is recorded unless the reducer runs, e.g. if middleware halts the action or dispatching is paused.
+    pub fn dispatch(&mut self, action: Action) {
+        let now = self.clock.now();
+        let coalesce = match (self.last_edit, self.window) {
+            (Some(last_edit), Some(window)) => now.duration_since(last_edit) <= window,
+            _ => false,
+        };
+
+        let before = if coalesce {
+            None
+        } else {
+            Some(self.store.state().clone())
+        };
+        let recorded = self.clone_action.map(|clone_action| clone_action(&action));
+        let reductions = self.store.reductions();
+        self.store.dispatch(action);
+        if self.store.reductions() == reductions {
+            return;
+        }
+
+        if let Some(before) = before {
+            self.undo.push_back(before);
+            self.trim();
+        }
+        self.redo.clear();
+        self.last_edit = Some(now);
+        self.actions.extend(recorded);
+    }
+
+    /// Forces the next dispatch to start a new undo step.
+    pub fn mark_undo_boundary(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Returns the number of undo steps available.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Returns the number of redo steps available.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Restores the state before the last undo step, returning whether there was one.
+    ///
+    /// Subscriptions of the underlying store get notified.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop_back() {
+            Some(state) => {
+                let current = self.store.replace_state(state);
+                self.redo.push(current);
+                self.mark_undo_boundary();
+                self.store.notify();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last undone step, returning whether there was one.
+    ///
+    /// Subscriptions of the underlying store get notified.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(state) => {
+                let current = self.store.replace_state(state);
+                self.undo.push_back(current);
+                self.mark_undo_boundary();
+                self.store.notify();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drops the oldest undo steps exceeding the limit.
+    fn trim(&mut self) {
+        if let Some(limit) = self.limit {
+            while self.undo.len() > limit {
+                self.undo.pop_front();
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+/// A focus on a part of a larger value, used to update nested state without rebuilding every layer by hand.
+///
+/// Lenses for (nested) fields are most easily built with the [`lens!`](macro.lens.html) macro.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{update_at, Lens};
+/// #
+/// #[derive(Clone)]
+/// struct State {
+///     counter: u8
+/// }
+///
+/// let counter = Lens::new(|state: &State| &state.counter, |state: &mut State| &mut state.counter);
+///
+/// let state = update_at(&State { counter: 1 }, &counter, |counter| counter + 1);
+/// assert_eq!(*counter.get(&state), 2);
+/// ```
+pub struct Lens<Whole, Part> {
+    get: fn(&Whole) -> &Part,
+    get_mut: fn(&mut Whole) -> &mut Part,
+}
+
+impl<Whole, Part> Lens<Whole, Part> {
+    /// Creates a lens from a shared and a mutable accessor to the same part.
+    pub fn new(get: fn(&Whole) -> &Part, get_mut: fn(&mut Whole) -> &mut Part) -> Self {
+        Self { get, get_mut }
+    }
+
+    /// Returns the focused part.
+    pub fn get<'a>(&self, whole: &'a Whole) -> &'a Part {
+        (self.get)(whole)
+    }
+
+    /// Returns the focused part mutably.
+    pub fn get_mut<'a>(&self, whole: &'a mut Whole) -> &'a mut Part {
+        (self.get_mut)(whole)
+    }
+}
+
+impl<Whole, Part> Clone for Lens<Whole, Part> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Whole, Part> Copy for Lens<Whole, Part> {}
+
+/// Returns a copy of `state` where only the part focused by `lens` is replaced by `f` applied to it.
+///
+/// `state` itself is left untouched.
+///
+/// See [`Lens`](struct.Lens.html).
+pub fn update_at<Whole, Part, F>(state: &Whole, lens: &Lens<Whole, Part>, f: F) -> Whole
+where
+    Whole: Clone,
+    F: FnOnce(&Part) -> Part,
+{
+    let part = f(lens.get(state));
+    let mut state = state.clone();
+    *lens.get_mut(&mut state) = part;
+    state
+}
+
+#[macro_export]
+/// Builds a [`Lens`](struct.Lens.html) focusing on a (nested) field.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::lens;
+/// #
+/// # struct Inner {
+/// #     field: u8
+/// # }
+/// #
+/// # struct State {
+/// #     inner: Inner
+/// # }
+/// #
+/// let lens = lens!(State, inner.field);
+/// ```
+/// (`State` being the actual type.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{lens, update_at};
+/// #
+/// #[derive(Clone)]
+/// struct Settings {
+///     volume: u8
+/// }
+///
+/// #[derive(Clone)]
+/// struct State {
+///     settings: Settings
+/// }
+///
+/// fn reducer(state: &State, volume: u8) -> State {
+///     update_at(state, &lens!(State, settings.volume), |_| volume)
+/// }
+/// ```
+macro_rules! lens {
+    ($whole: ty, $($field: tt).+) => (
+        $crate::Lens::new(
+            |whole: &$whole| &whole.$($field).+,
+            |whole: &mut $whole| &mut whole.$($field).+,
+        )
+    )
+}
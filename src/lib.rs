@@ -139,17 +139,125 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, boxed::Box, collections::VecDeque, rc::Rc, vec::Vec};
 #[cfg(feature = "std")]
-use std::vec::Vec;
+use std::{borrow::Cow, boxed::Box, collections::VecDeque, rc::Rc, vec::Vec};
 
+#[cfg(feature = "std")]
+mod actor;
+#[cfg(feature = "async")]
+mod async_middleware;
+mod backend;
+#[cfg(feature = "causality")]
+mod causality;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "std")]
+mod coalesce;
+mod context;
+mod counter;
+#[cfg(feature = "std")]
+mod counting;
+#[cfg(feature = "std")]
+mod crash;
+#[cfg(feature = "log")]
+mod diagnostics;
+mod diff;
+mod dispatcher;
+mod effects;
+#[cfg(feature = "std")]
+mod envelope;
+mod extensions;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod history;
+mod lens;
+#[cfg(feature = "std")]
+mod metrics;
 mod middleware;
+mod notification;
+mod observable;
+#[cfg(feature = "persist")]
+mod persist;
+#[cfg(feature = "std")]
+mod rate_limit;
 mod reducer;
+mod registry;
+#[cfg(feature = "rpc")]
+mod rpc;
+mod scope;
 mod store;
 mod subscription;
+#[cfg(feature = "test-util")]
+pub mod test;
+#[cfg(feature = "std")]
+mod throttle;
+#[cfg(feature = "async")]
+mod wait;
+mod zip;
 
-pub use middleware::Middleware;
-pub use reducer::Reducible;
-#[cfg(not(feature = "devtools"))]
-pub use store::Store;
-pub use subscription::Subscription;
+#[cfg(feature = "std")]
+pub use actor::ActorStore;
+#[cfg(feature = "async")]
+pub use async_middleware::{
+    ActionFuture, AsyncMiddleware, DispatchTimeout, SleepFuture, Stream, TaskFuture, Timer,
+};
+pub use backend::{InMemoryBackend, StateBackend};
+#[cfg(feature = "causality")]
+pub use causality::{ActionGraph, ActionId};
+#[cfg(feature = "std")]
+pub use clock::{Clock, ManualClock};
+#[cfg(feature = "std")]
+pub use coalesce::CoalesceMiddleware;
+pub use context::ContextReducible;
+pub use counter::{counting_reducer, CounterAction, CounterValue, OverflowPolicy};
+#[cfg(feature = "std")]
+pub use counting::CountingMiddleware;
+#[cfg(feature = "std")]
+pub use crash::{CrashReport, CrashReporterMiddleware};
+#[cfg(feature = "log")]
+pub use diagnostics::{DiagnosticsLogger, LogLevel};
+pub use diff::Diff;
+pub use dispatcher::Dispatcher;
+#[cfg(feature = "std")]
+pub use envelope::{enveloped, ActionEnvelope};
+pub use extensions::Extensions;
+#[cfg(feature = "std")]
+pub use history::HistoryStore;
+pub use lens::{update_at, Lens};
+#[cfg(feature = "std")]
+pub use metrics::{DispatchMetrics, MetricsCollector};
+pub use middleware::{
+    AroundMiddleware, Feature, MapActionMiddleware, Middleware, MiddlewareId, MiddlewareOrderError,
+    MiddlewarePhase, MiddlewareTag, Next,
+};
+pub use notification::NotifyingReducible;
+pub use observable::{IntoObservable, Observable};
+#[cfg(feature = "persist")]
+pub use persist::{PersistSubscription, StateStore};
+#[cfg(feature = "std")]
+pub use rate_limit::RateLimitMiddleware;
+pub use reducer::{
+    assert_pure, checked_reduce, combine, combine_bounded, combine_parallel, namespaced_reducer,
+    try_combine, CombinedReducer, CowReducible, DepthError, Reducible, TryCombinedReducer,
+    TryReducible,
+};
+#[cfg(feature = "std")]
+pub use reducer::{guard_reducer, memoized_reducer, with_delta_logging, with_delta_logging_to};
+pub use registry::ReducerRegistry;
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub use registry::{__Registration, __boxed_reducer, __register_reducer};
+#[cfg(feature = "rpc")]
+pub use rpc::{RpcCodec, StoreRpcHandler};
+pub use scope::ScopedStore;
+pub use store::{CapacityError, QueueOverflowPolicy, Store};
+#[cfg(feature = "std")]
+pub use store::{ConfigError, StoreError, SubscriptionPanicPolicy};
+pub use subscription::{CloneableSubscription, Subscription, SubscriptionControl, SubscriptionId};
+#[cfg(feature = "std")]
+pub use throttle::ThrottledSubscription;
+#[cfg(feature = "async")]
+pub use wait::WaitFor;
+pub use zip::{combine_stores, Either};
@@ -0,0 +1,49 @@
+/// Timing information about a single dispatch, in nanoseconds.
+///
+/// If a middleware halted the action, `reducer_ns` and `subscriptions_ns` are zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DispatchMetrics {
+    /// Time spent running the middleware chain.
+    pub middleware_ns: u128,
+    /// Time spent running the reducer.
+    pub reducer_ns: u128,
+    /// Time spent running the subscriptions.
+    pub subscriptions_ns: u128,
+    /// Time spent on the whole dispatch.
+    pub total_ns: u128,
+}
+
+pub trait MetricsCollector {
+    fn collect(&self, metrics: DispatchMetrics);
+}
+
+/// Function signature for a metrics collector.
+///
+/// A metrics collector gets called after every dispatch with the time spent in each phase of it.
+/// Collecting metrics is opt-in and requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{DispatchMetrics, Store};
+/// #
+/// # fn reducer(state: &u8, _: ()) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.set_metrics_collector(|metrics: DispatchMetrics| {
+///     println!("Reducer took {}ns", metrics.reducer_ns);
+/// });
+///
+/// store.dispatch(());
+/// ```
+impl<Function> MetricsCollector for Function
+where
+    Function: Fn(DispatchMetrics),
+{
+    fn collect(&self, metrics: DispatchMetrics) {
+        self(metrics)
+    }
+}
@@ -1,4 +1,6 @@
-use crate::Store;
+use crate::{Box, Store, Vec};
+use core::fmt;
+use core::mem::{self, Discriminant};
 
 pub trait Middleware<State, Action> {
     fn next(&self, store: &Store<State, Action>, action: Action) -> Option<Action>;
@@ -51,3 +53,334 @@ where
         self(store, action)
     }
 }
+
+#[macro_export]
+/// Combines multiple middleware into a single one, running them in order.
+///
+/// The action returned by each middleware is passed on to the next one.
+/// The chain stops at the first middleware returning `None`, which is then returned by the combined middleware.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::{combine_middleware, Store};
+/// #
+/// # type State = u8;
+/// #
+/// # type Action = bool;
+/// #
+/// # fn first_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+/// #     Some(action)
+/// # }
+/// #
+/// # fn second_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+/// #     Some(action)
+/// # }
+/// #
+/// let middleware = combine_middleware!(State, Action, first_middleware, second_middleware);
+/// ```
+/// (`State` and `Action` being the actual types.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine_middleware, Middleware, Store};
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// fn logger_middleware(_: &Store<u8, Action>, action: Action) -> Option<Action> {
+///     println!("Dispatching an action");
+///     Some(action)
+/// }
+///
+/// fn no_decrement_middleware(_: &Store<u8, Action>, action: Action) -> Option<Action> {
+///     match action {
+///         Action::Increment => Some(action),
+///         Action::Decrement => None
+///     }
+/// }
+///
+/// fn middleware() -> impl Middleware<u8, Action> {
+///     combine_middleware!(u8, Action, logger_middleware, no_decrement_middleware)
+/// }
+/// ```
+macro_rules! combine_middleware {
+    ($state: ty, $action: ty, $middleware: expr) => ($middleware);
+    ($state: ty, $action: ty, $first: expr, $($rest: expr),+) => ({
+        let first = $first;
+        let rest = combine_middleware!($state, $action, $($rest),+);
+        move |store: &$crate::Store<$state, $action>, action: $action| -> Option<$action> {
+            let action = $crate::Middleware::next(&first, store, action)?;
+            $crate::Middleware::next(&rest, store, action)
+        }
+    })
+}
+
+pub trait AroundMiddleware<State, Action> {
+    fn around(&self, action: Action, next: Next<'_, State, Action>);
+}
+
+/// Function signature for an around middleware.
+///
+/// Around middleware wraps the reducer: it receives the action and a [`Next`](struct.Next.html) continuation.
+/// Calling [`Next::run`](struct.Next.html#method.run) runs the remaining around middleware and the reducer, returning the resulting state.
+/// This allows logic before and after the reducer in a single place.
+/// Not calling it halts the chain, including the reducer and subscriptions.
+///
+/// # Example
+///
+/// The following logs every state transition.
+///
+/// ```
+/// # use redux_rs::{Next, Store};
+/// #
+/// fn reducer(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_around_middleware(|increment: u8, next: Next<u8, u8>| {
+///     let before = *next.state();
+///     let after = next.run(increment);
+///     println!("{} -> {}", before, after);
+/// });
+/// ```
+impl<State, Action, Function> AroundMiddleware<State, Action> for Function
+where
+    Function: Fn(Action, Next<'_, State, Action>),
+{
+    fn around(&self, action: Action, next: Next<'_, State, Action>) {
+        self(action, next)
+    }
+}
+
+/// The continuation passed to an around middleware, running the rest of the chain.
+///
+/// See [`AroundMiddleware`](trait.AroundMiddleware.html).
+pub struct Next<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    middleware: &'a [Box<dyn AroundMiddleware<State, Action>>],
+    notify: &'a mut bool,
+}
+
+impl<'a, State, Action> Next<'a, State, Action> {
+    pub(crate) fn new(
+        store: &'a mut Store<State, Action>,
+        middleware: &'a [Box<dyn AroundMiddleware<State, Action>>],
+        notify: &'a mut bool,
+    ) -> Self {
+        Self {
+            store,
+            middleware,
+            notify,
+        }
+    }
+
+    /// Returns the current state, before running the rest of the chain.
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    /// Runs the remaining around middleware and the reducer, returning the resulting state.
+    pub fn run(self, action: Action) -> &'a State {
+        let store = self.store;
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => {
+                middleware.around(action, Next::new(store, rest, self.notify))
+            }
+            None => {
+                *self.notify = store.reduce(action);
+            }
+        }
+        store.state()
+    }
+}
+
+/// A middleware unconditionally transforming every action before passing it on.
+///
+/// This is a pure transform: it never halts the chain, so it behaves like a middleware always returning `Some(map(action))`.
+///
+/// # Example
+///
+/// The following migrates legacy actions into their new representation before they reach the reducer.
+///
+/// ```
+/// # use redux_rs::{MapActionMiddleware, Store};
+/// #
+/// enum Action {
+///     LegacyIncrement,
+///     Increment
+/// }
+///
+/// fn reducer(state: &u8, action: Action) -> u8 {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::LegacyIncrement => unreachable!()
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(MapActionMiddleware::new(|action| match action {
+///     Action::LegacyIncrement => Action::Increment,
+///     action => action
+/// }));
+///
+/// store.dispatch(Action::LegacyIncrement);
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub struct MapActionMiddleware<Map> {
+    map: Map,
+}
+
+impl<Map> MapActionMiddleware<Map> {
+    /// Creates a middleware transforming every action using `map`.
+    pub fn new(map: Map) -> Self {
+        Self { map }
+    }
+}
+
+impl<State, Action, Map> Middleware<State, Action> for MapActionMiddleware<Map>
+where
+    Map: Fn(Action) -> Action,
+{
+    fn next(&self, _: &Store<State, Action>, action: Action) -> Option<Action> {
+        Some((self.map)(action))
+    }
+}
+
+pub trait Feature<State, Action> {
+    fn middleware(&self) -> Vec<Box<dyn Middleware<State, Action>>>;
+}
+
+/// Function signature for a feature, contributing its standard middleware to a store.
+///
+/// Modular apps can have every feature module expose such a function, and wire them all up the same way
+/// using [`Store::add_feature_middleware`](struct.Store.html#method.add_feature_middleware).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Middleware, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// mod analytics {
+///     # use redux_rs::{Middleware, Store};
+///     #
+///     pub fn middleware() -> Vec<Box<dyn Middleware<u8, u8>>> {
+///         vec![Box::new(|_: &Store<u8, u8>, increment: u8| {
+///             println!("Tracking an increment by {}", increment);
+///             Some(increment)
+///         })]
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.add_feature_middleware(analytics::middleware);
+/// ```
+impl<State, Action, Function> Feature<State, Action> for Function
+where
+    Function: Fn() -> Vec<Box<dyn Middleware<State, Action>>>,
+{
+    fn middleware(&self) -> Vec<Box<dyn Middleware<State, Action>>> {
+        self()
+    }
+}
+
+/// A label identifying a middleware, used to declare ordering constraints between middleware.
+///
+/// See [`Store::add_tagged_middleware`](struct.Store.html#method.add_tagged_middleware).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MiddlewareTag(pub &'static str);
+
+impl fmt::Display for MiddlewareTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Error returned when middleware violate a declared ordering constraint.
+///
+/// See [`Store::validate_middleware_order`](struct.Store.html#method.validate_middleware_order).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MiddlewareOrderError {
+    /// The tag required to run first.
+    pub before: MiddlewareTag,
+    /// The tag required to run afterwards.
+    pub after: MiddlewareTag,
+}
+
+impl fmt::Display for MiddlewareOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "middleware `{}` must run before `{}`",
+            self.before, self.after
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MiddlewareOrderError {}
+
+/// An opaque handle to a middleware registered in a store.
+///
+/// Returned when adding middleware, e.g. by [`Store::add_middleware`](struct.Store.html#method.add_middleware).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MiddlewareId(pub(crate) usize);
+
+/// A coarse stage of the middleware chain.
+///
+/// Middleware runs phase by phase, and in insertion order within a phase.
+///
+/// See [`Store::add_middleware_in_phase`](struct.Store.html#method.add_middleware_in_phase).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MiddlewarePhase {
+    /// Runs before all other middleware, e.g. for validation.
+    Pre,
+    /// The phase of middleware added without one.
+    Normal,
+    /// Runs after all other middleware, e.g. for logging what reaches the reducer.
+    Post,
+}
+
+/// A middleware registered in a store, along with its metadata.
+pub(crate) struct MiddlewareEntry<State, Action> {
+    pub(crate) middleware: Box<dyn Middleware<State, Action>>,
+    pub(crate) id: MiddlewareId,
+    pub(crate) enabled: bool,
+    pub(crate) tag: Option<MiddlewareTag>,
+    pub(crate) route: Option<Discriminant<Action>>,
+    pub(crate) phase: MiddlewarePhase,
+}
+
+impl<State, Action> MiddlewareEntry<State, Action> {
+    pub(crate) fn new<M: Middleware<State, Action> + 'static>(middleware: M) -> Self {
+        Self::boxed(Box::new(middleware))
+    }
+
+    pub(crate) fn boxed(middleware: Box<dyn Middleware<State, Action>>) -> Self {
+        Self {
+            middleware,
+            id: MiddlewareId(0),
+            enabled: true,
+            tag: None,
+            route: None,
+            phase: MiddlewarePhase::Normal,
+        }
+    }
+
+    /// Returns whether the middleware wants to see the action.
+    pub(crate) fn handles(&self, action: &Action) -> bool {
+        self.enabled
+            && match self.route {
+                Some(route) => route == mem::discriminant(action),
+                None => true,
+            }
+    }
+}
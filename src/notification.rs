@@ -0,0 +1,92 @@
+use crate::reducer::StoreReducer;
+use crate::{Box, Rc, Vec};
+use core::cell::RefCell;
+
+pub trait NotifyingReducible<State, Action, Notification> {
+    fn reduce(&self, state: &State, action: Action) -> (State, Vec<Notification>);
+}
+
+/// Function signature for a reducer signaling outcomes besides the new state.
+///
+/// The notifications, e.g. the id assigned to a new item, get delivered to the handlers subscribed
+/// through [`Store::subscribe_notifications`](struct.Store.html#method.subscribe_notifications) right after the reducer ran.
+///
+/// See [`Store::new_with_notifications`](struct.Store.html#method.new_with_notifications).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::NotifyingReducible;
+/// #
+/// fn reducer(state: &Vec<&'static str>, todo: &'static str) -> (Vec<&'static str>, Vec<usize>) {
+///     let mut todos = state.clone();
+///     todos.push(todo);
+///     (todos, vec![state.len()])
+/// }
+///
+/// assert_eq!(reducer.reduce(&vec![], "Wash the car"), (vec!["Wash the car"], vec![0]));
+/// ```
+impl<State, Action, Notification, Function> NotifyingReducible<State, Action, Notification>
+    for Function
+where
+    Function: Fn(&State, Action) -> (State, Vec<Notification>),
+{
+    fn reduce(&self, state: &State, action: Action) -> (State, Vec<Notification>) {
+        self(state, action)
+    }
+}
+
+/// Adapts a reducer signaling outcomes, queueing its notifications on the channel of the store.
+///
+/// A plain reducer signals nothing, so it can't stand in for this one.
+pub(crate) struct NotifyingReducer<R, Notification>(
+    pub(crate) R,
+    pub(crate) Rc<NotificationChannel<Notification>>,
+);
+
+impl<State, Action, Notification, R> StoreReducer<State, Action>
+    for NotifyingReducer<R, Notification>
+where
+    R: NotifyingReducible<State, Action, Notification>,
+{
+    fn reduce_in_place(&self, state: &mut State, action: Action) -> bool {
+        let (new_state, notifications) = self.0.reduce(state, action);
+        *state = new_state;
+        self.1.push(notifications);
+        true
+    }
+}
+
+type NotificationHandler<Notification> = Box<dyn Fn(&Notification)>;
+
+/// The notifications of a store, kept aside so handlers can be subscribed.
+pub(crate) struct NotificationChannel<Notification> {
+    pending: RefCell<Vec<Notification>>,
+    handlers: RefCell<Vec<NotificationHandler<Notification>>>,
+}
+
+impl<Notification> NotificationChannel<Notification> {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: RefCell::new(Vec::new()),
+            handlers: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn push(&self, notifications: Vec<Notification>) {
+        self.pending.borrow_mut().extend(notifications);
+    }
+
+    pub(crate) fn subscribe<F: Fn(&Notification) + 'static>(&self, handler: F) {
+        self.handlers.borrow_mut().push(Box::new(handler));
+    }
+
+    /// Hands all pending notifications to every handler, in order.
+    pub(crate) fn deliver(&self) {
+        let pending = self.pending.replace(Vec::new());
+        let handlers = self.handlers.borrow();
+        for notification in &pending {
+            handlers.iter().for_each(|handler| handler(notification));
+        }
+    }
+}
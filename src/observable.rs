@@ -0,0 +1,169 @@
+use crate::{Box, Rc, Store, SubscriptionControl, Vec};
+use core::cell::RefCell;
+
+pub trait IntoObservable<T> {
+    fn into_observable(self) -> Observable<T>;
+}
+
+/// Conversion of a store into an observable of its state, see [`Store::observe`](struct.Store.html#method.observe).
+///
+/// This lets functions accept anything observable, be it a store or an observable derived from one.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{IntoObservable, Observable, Store};
+/// #
+/// # fn reducer(state: &u8, _: ()) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// fn log_values<O: IntoObservable<u8>>(source: O) -> Observable<u8> {
+///     let observable = source.into_observable();
+///     observable.subscribe(|value: &u8| println!("Value: {}", value));
+///     observable
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// let _logged = log_values(&mut store);
+///
+/// store.dispatch(()); // Prints "Value: 1".
+/// ```
+impl<State: 'static, Action> IntoObservable<State> for &mut Store<State, Action> {
+    fn into_observable(self) -> Observable<State> {
+        self.observe()
+    }
+}
+
+/// An observable converts into itself.
+impl<T> IntoObservable<T> for Observable<T> {
+    fn into_observable(self) -> Observable<T> {
+        self
+    }
+}
+
+type Observer<T> = Box<dyn Fn(&T)>;
+
+/// A stream of values derived from the state changes of a store.
+///
+/// Observables are created by [`Store::observe`](struct.Store.html#method.observe) and can be transformed
+/// using combinators such as [`map`](#method.map), [`filter`](#method.filter) and
+/// [`distinct_until_changed`](#method.distinct_until_changed).
+/// The store keeps feeding an observable and the ones derived from it until all of them got dropped, then unsubscribes.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// #
+/// # fn reducer(state: &u8, _: ()) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// let even = store
+///     .observe()
+///     .map(|state: &u8| state % 2 == 0)
+///     .distinct_until_changed();
+/// even.subscribe(|even: &bool| println!("Even: {}", even));
+///
+/// store.dispatch(()); // Prints "Even: false".
+/// drop(even);
+/// store.dispatch(()); // Prints nothing.
+/// ```
+pub struct Observable<T> {
+    observers: Rc<RefCell<Vec<Observer<T>>>>,
+    subscription: Rc<ObservedSubscription>,
+}
+
+/// The store subscription feeding a chain of observables, unsubscribed once they all got dropped.
+struct ObservedSubscription(SubscriptionControl);
+
+impl Drop for ObservedSubscription {
+    fn drop(&mut self) {
+        self.0.unsubscribe();
+    }
+}
+
+impl<T: 'static> Observable<T> {
+    /// Creates an observable fed by the store subscription controlled by `control`.
+    pub(crate) fn new(control: SubscriptionControl) -> Self {
+        Self {
+            observers: Rc::new(RefCell::new(Vec::new())),
+            subscription: Rc::new(ObservedSubscription(control)),
+        }
+    }
+
+    /// Creates an observable fed by the same store subscription as this one.
+    fn derive<U>(&self) -> Observable<U> {
+        Observable {
+            observers: Rc::new(RefCell::new(Vec::new())),
+            subscription: self.subscription.clone(),
+        }
+    }
+
+    /// Returns a callback sending a value to all observers.
+    ///
+    /// It does not keep the store subscription alive, so the store can hold on to it.
+    pub(crate) fn emitter(&self) -> impl Fn(&T) {
+        let observers = self.observers.clone();
+        move |value| {
+            observers
+                .borrow()
+                .iter()
+                .for_each(|observer| observer(value))
+        }
+    }
+
+    /// Registers a callback receiving every value of this observable.
+    pub fn subscribe<F: Fn(&T) + 'static>(&self, observer: F) {
+        self.observers.borrow_mut().push(Box::new(observer));
+    }
+
+    /// Creates an observable emitting the values of this one, transformed by `f`.
+    pub fn map<U: 'static, F: Fn(&T) -> U + 'static>(&self, f: F) -> Observable<U> {
+        let mapped = self.derive();
+        let emit = mapped.emitter();
+        self.subscribe(move |value| emit(&f(value)));
+        mapped
+    }
+
+    /// Creates an observable emitting only the values of this one satisfying `predicate`.
+    pub fn filter<F: Fn(&T) -> bool + 'static>(&self, predicate: F) -> Observable<T> {
+        let filtered = self.derive();
+        let emit = filtered.emitter();
+        self.subscribe(move |value| {
+            if predicate(value) {
+                emit(value);
+            }
+        });
+        filtered
+    }
+
+    /// Creates an observable skipping values equal to the previously emitted one.
+    pub fn distinct_until_changed(&self) -> Observable<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let distinct = self.derive();
+        let emit = distinct.emitter();
+        let last = RefCell::new(None);
+        self.subscribe(move |value: &T| {
+            if last.borrow().as_ref() != Some(value) {
+                last.replace(Some(value.clone()));
+                emit(value);
+            }
+        });
+        distinct
+    }
+}
+
+impl<T> Clone for Observable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            observers: self.observers.clone(),
+            subscription: self.subscription.clone(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Box, Clock, Subscription};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+pub trait StateStore<State> {
+    fn save(&self, state: &State);
+}
+
+/// Function signature for a state store, a backend persisting the state.
+///
+/// The backend is responsible for serializing the state, e.g. to a file, the browser's local storage or a database.
+/// As the crate has no dependencies, the trait is not bound on serde traits: call e.g. `serde_json::to_string` from the backend instead.
+///
+/// # Example
+///
+/// ```no_run
+/// # use redux_rs::{PersistSubscription, Store};
+/// use std::fs;
+///
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.subscribe(PersistSubscription::new(|state: &u8| {
+///     fs::write("state.txt", state.to_string()).unwrap()
+/// }));
+/// ```
+impl<State, Function> StateStore<State> for Function
+where
+    Function: Fn(&State),
+{
+    fn save(&self, state: &State) {
+        self(state)
+    }
+}
+
+/// A subscription saving the state to a [`StateStore`](trait.StateStore.html) on every change.
+///
+/// Saves can be debounced using [`with_debounce`](#method.with_debounce).
+///
+/// Requires the `persist` feature.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{PersistSubscription, Store};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use std::time::Duration;
+///
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let saved = Rc::new(Cell::new(0));
+/// let backend = saved.clone();
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.subscribe(PersistSubscription::new(move |state: &u8| backend.set(*state)));
+///
+/// store.dispatch(3);
+/// assert_eq!(saved.get(), 3);
+/// ```
+pub struct PersistSubscription<Backend> {
+    backend: Backend,
+    debounce: Option<Duration>,
+    clock: Box<dyn Clock>,
+    last_save: Cell<Option<Instant>>,
+}
+
+impl<Backend> PersistSubscription<Backend> {
+    /// Creates a subscription saving every state to `backend`.
+    pub fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            debounce: None,
+            clock: Box::new(Instant::now),
+            last_save: Cell::new(None),
+        }
+    }
+
+    /// Saves at most once per `interval`, skipping states produced within `interval` of the last save.
+    ///
+    /// Skipped states are not saved later on by themselves: save the final state explicitly (e.g. on shutdown) to make sure it is persisted.
+    pub fn with_debounce(mut self, interval: Duration) -> Self {
+        self.debounce = Some(interval);
+        self
+    }
+
+    /// Uses the given clock to measure the time between saves.
+    ///
+    /// See [`Clock`](trait.Clock.html).
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+}
+
+impl<State, Backend> Subscription<State> for PersistSubscription<Backend>
+where
+    Backend: StateStore<State>,
+{
+    fn update(&self, state: &State) {
+        let now = self.clock.now();
+        let skip = match (self.debounce, self.last_save.get()) {
+            (Some(interval), Some(last_save)) => now.duration_since(last_save) < interval,
+            _ => false,
+        };
+
+        if !skip {
+            self.backend.save(state);
+            self.last_save.set(Some(now));
+        }
+    }
+}
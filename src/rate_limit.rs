@@ -0,0 +1,83 @@
+use crate::{Box, Clock, Middleware, Store};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A middleware capping the number of dispatches within a time window.
+///
+/// Once more than `max` actions were dispatched within the current window, further actions are halted
+/// (and a warning is printed to standard error) until the window is over.
+/// This protects against runaway dispatch loops, e.g. a buggy middleware re-dispatching itself.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{RateLimitMiddleware, Store};
+/// use std::time::Duration;
+///
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+/// store.add_middleware(RateLimitMiddleware::new(100, Duration::from_secs(1)));
+/// ```
+pub struct RateLimitMiddleware {
+    max: usize,
+    window: Duration,
+    clock: Box<dyn Clock>,
+    window_start: Cell<Option<Instant>>,
+    count: Cell<usize>,
+}
+
+impl RateLimitMiddleware {
+    /// Creates a middleware letting at most `max` actions through per `window`.
+    pub fn new(max: usize, window: Duration) -> Self {
+        Self {
+            max,
+            window,
+            clock: Box::new(Instant::now),
+            window_start: Cell::new(None),
+            count: Cell::new(0),
+        }
+    }
+
+    /// Uses the given clock to measure time windows.
+    ///
+    /// See [`Clock`](trait.Clock.html).
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Counts a dispatch, returning whether it stays within the cap.
+    fn allow(&self) -> bool {
+        let now = self.clock.now();
+        let expired = match self.window_start.get() {
+            Some(start) => now.duration_since(start) >= self.window,
+            None => true,
+        };
+        if expired {
+            self.window_start.set(Some(now));
+            self.count.set(0);
+        }
+
+        self.count.set(self.count.get() + 1);
+        if self.count.get() == self.max + 1 {
+            eprintln!(
+                "more than {} actions dispatched within {:?}, halting further actions",
+                self.max, self.window
+            );
+        }
+        self.count.get() <= self.max
+    }
+}
+
+impl<State, Action> Middleware<State, Action> for RateLimitMiddleware {
+    fn next(&self, _: &Store<State, Action>, action: Action) -> Option<Action> {
+        if self.allow() {
+            Some(action)
+        } else {
+            None
+        }
+    }
+}
@@ -1,6 +1,21 @@
+use crate::{Box, Cow, Rc, Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::{
+    cell::RefCell,
+    hash::{Hash, Hasher},
+};
+#[cfg(feature = "std")]
+use std::collections::{hash_map::DefaultHasher, HashMap};
+
 // Idea from <https://www.reddit.com/r/rust/comments/5bn5pn/would_love_feedback_on_my_new_library_reduxrs_a/d9pyafm?utm_source=share&utm_medium=web2x&context=3>.
 pub trait Reducible<State, Action> {
     fn reduce(&self, state: &State, action: Action) -> State;
+
+    /// Returns how deeply combined reducers are nested within this one, a plain reducer having a depth of 1.
+    fn depth(&self) -> usize {
+        1
+    }
 }
 
 /// Function signature for a reducer.
@@ -33,10 +48,806 @@ where
     }
 }
 
+pub trait CowReducible<State: Clone, Action> {
+    fn reduce_cow<'a>(&self, state: &'a State, action: Action) -> Cow<'a, State>;
+}
+
+/// Function signature for a copy-on-write reducer.
+///
+/// A copy-on-write reducer returns `Cow::Borrowed` when it leaves the state unchanged and `Cow::Owned` with the new state otherwise.
+/// The store only replaces its state in the latter case, so unhandled actions never clone the state.
+///
+/// See [`Store::new_cow`](struct.Store.html#method.new_cow).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::CowReducible;
+/// use std::borrow::Cow;
+///
+/// enum Action {
+///     Insert(&'static str),
+///     Unhandled
+/// }
+///
+/// fn reducer<'a>(state: &'a Vec<&'static str>, action: Action) -> Cow<'a, Vec<&'static str>> {
+///     match action {
+///         Action::Insert(todo) => {
+///             let mut todos = state.clone();
+///             todos.push(todo);
+///             Cow::Owned(todos)
+///         }
+///         Action::Unhandled => Cow::Borrowed(state)
+///     }
+/// }
+///
+/// assert_eq!(reducer.reduce_cow(&vec![], Action::Insert("Sleep")).into_owned(), vec!["Sleep"]);
+/// ```
+impl<State, Action, Function> CowReducible<State, Action> for Function
+where
+    State: Clone,
+    Function: for<'a> Fn(&'a State, Action) -> Cow<'a, State>,
+{
+    fn reduce_cow<'a>(&self, state: &'a State, action: Action) -> Cow<'a, State> {
+        self(state, action)
+    }
+}
+
+/// A fallible reducer, producing a new state from the current one or failing with an error.
+pub trait TryReducible<State, Action> {
+    type Error;
+
+    fn try_reduce(&self, state: &State, action: Action) -> Result<State, Self::Error>;
+}
+
+/// Function signature for a fallible reducer.
+///
+/// A fallible reducer returns `Err` when the action cannot be applied to the state, which is then left as is.
+/// Fallible reducers can be chained using [`try_combine`](fn.try_combine.html).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::TryReducible;
+/// #
+/// fn withdraw(balance: &u8, amount: u8) -> Result<u8, &'static str> {
+///     balance.checked_sub(amount).ok_or("insufficient funds")
+/// }
+///
+/// assert_eq!(withdraw.try_reduce(&5, 2), Ok(3));
+/// assert_eq!(withdraw.try_reduce(&5, 10), Err("insufficient funds"));
+/// ```
+impl<State, Action, Error, Function> TryReducible<State, Action> for Function
+where
+    Function: Fn(&State, Action) -> Result<State, Error>,
+{
+    type Error = Error;
+
+    fn try_reduce(&self, state: &State, action: Action) -> Result<State, Error> {
+        self(state, action)
+    }
+}
+
+/// A fallible reducer chaining two fallible reducers, as created by [`try_combine`](fn.try_combine.html).
+pub struct TryCombinedReducer<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<State, Action, First, Second> TryReducible<State, Action> for TryCombinedReducer<First, Second>
+where
+    Action: Clone,
+    First: TryReducible<State, Action>,
+    Second: TryReducible<State, Action, Error = First::Error>,
+{
+    type Error = First::Error;
+
+    fn try_reduce(&self, state: &State, action: Action) -> Result<State, Self::Error> {
+        let state = self.first.try_reduce(state, action.clone())?;
+        self.second.try_reduce(&state, action)
+    }
+}
+
+/// Chains two fallible reducers sharing the same error type, stopping at the first error.
+///
+/// The first reducer gets called first, chained into the second one.
+/// Nest calls to chain more reducers.
+/// This is the fallible analog of [`combine_reducers!`](macro.combine_reducers.html).
+///
+/// Warning: this requires `Action` to be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{try_combine, TryReducible};
+/// #
+/// fn withdraw(balance: &u8, amount: u8) -> Result<u8, &'static str> {
+///     balance.checked_sub(amount).ok_or("insufficient funds")
+/// }
+///
+/// fn pay_fee(balance: &u8, _: u8) -> Result<u8, &'static str> {
+///     balance.checked_sub(1).ok_or("cannot pay fee")
+/// }
+///
+/// let reducer = try_combine(withdraw, pay_fee);
+///
+/// assert_eq!(reducer.try_reduce(&5, 2), Ok(2));
+/// assert_eq!(reducer.try_reduce(&5, 5), Err("cannot pay fee"));
+/// ```
+pub fn try_combine<First, Second>(
+    first: First,
+    second: Second,
+) -> TryCombinedReducer<First, Second> {
+    TryCombinedReducer { first, second }
+}
+
+pub(crate) trait StoreReducer<State, Action> {
+    /// Reduces the state in place, returning whether the state changed.
+    fn reduce_in_place(&self, state: &mut State, action: Action) -> bool;
+
+    /// Adapts `reducer` the same way as this one, or returns `None` if a plain reducer can't stand in for it.
+    fn adapt(
+        &self,
+        _reducer: Box<dyn Reducible<State, Action>>,
+    ) -> Option<Rc<dyn StoreReducer<State, Action>>>
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        None
+    }
+}
+
+/// A boxed reducer, as swapped in by [`Store::replace_reducer`](struct.Store.html#method.replace_reducer).
+pub(crate) struct BoxedReducer<State, Action>(Box<dyn Reducible<State, Action>>);
+
+impl<State, Action> Reducible<State, Action> for BoxedReducer<State, Action> {
+    fn reduce(&self, state: &State, action: Action) -> State {
+        self.0.reduce(state, action)
+    }
+}
+
+/// Adapts a reducer, always replacing the state with the one it produced.
+///
+/// As there is no way to tell, every action counts as changing the state.
+pub(crate) struct PlainReducer<R>(pub(crate) R);
+
+impl<State, Action, R> StoreReducer<State, Action> for PlainReducer<R>
+where
+    R: Reducible<State, Action>,
+{
+    fn reduce_in_place(&self, state: &mut State, action: Action) -> bool {
+        *state = self.0.reduce(state, action);
+        true
+    }
+
+    fn adapt(
+        &self,
+        reducer: Box<dyn Reducible<State, Action>>,
+    ) -> Option<Rc<dyn StoreReducer<State, Action>>>
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        Some(Rc::new(PlainReducer(BoxedReducer(reducer))))
+    }
+}
+
+/// Adapts a copy-on-write reducer, only replacing the state when a new one got produced.
+///
+/// Actions for which the reducer borrowed the state count as leaving it unchanged.
+/// A plain reducer can't tell that, so it can't stand in for a copy-on-write one.
+pub(crate) struct CowReducer<R>(pub(crate) R);
+
+impl<State, Action, R> StoreReducer<State, Action> for CowReducer<R>
+where
+    State: Clone,
+    R: CowReducible<State, Action>,
+{
+    fn reduce_in_place(&self, state: &mut State, action: Action) -> bool {
+        match self.0.reduce_cow(state, action) {
+            Cow::Owned(new_state) => {
+                *state = new_state;
+                true
+            }
+            Cow::Borrowed(_) => false,
+        }
+    }
+}
+
+/// Decides whether two states are equal, for change detection.
+pub(crate) trait StateEq<State> {
+    fn eq(&self, a: &State, b: &State) -> bool;
+}
+
+impl<State, Function> StateEq<State> for Function
+where
+    Function: Fn(&State, &State) -> bool,
+{
+    fn eq(&self, a: &State, b: &State) -> bool {
+        self(a, b)
+    }
+}
+
+/// Compares states using `PartialEq`.
+pub(crate) struct PartialEqual;
+
+impl<State: PartialEq> StateEq<State> for PartialEqual {
+    fn eq(&self, a: &State, b: &State) -> bool {
+        a == b
+    }
+}
+
+/// Compares states by their hashes.
+#[cfg(feature = "std")]
+pub(crate) struct HashEqual;
+
+#[cfg(feature = "std")]
+impl<State: Hash> StateEq<State> for HashEqual {
+    fn eq(&self, a: &State, b: &State) -> bool {
+        fn hash<State: Hash>(state: &State) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        hash(a) == hash(b)
+    }
+}
+
+/// Adapts a reducer, discarding a new state equal to the previous one according to a comparator.
+pub(crate) struct ComparingReducer<R, E>(pub(crate) R, pub(crate) Rc<E>);
+
+impl<State, Action, R, E> StoreReducer<State, Action> for ComparingReducer<R, E>
+where
+    R: Reducible<State, Action>,
+    E: StateEq<State> + 'static,
+{
+    fn reduce_in_place(&self, state: &mut State, action: Action) -> bool {
+        let new_state = self.0.reduce(state, action);
+        if self.1.eq(state, &new_state) {
+            return false;
+        }
+
+        *state = new_state;
+        true
+    }
+
+    fn adapt(
+        &self,
+        reducer: Box<dyn Reducible<State, Action>>,
+    ) -> Option<Rc<dyn StoreReducer<State, Action>>>
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        Some(Rc::new(ComparingReducer(
+            BoxedReducer(reducer),
+            self.1.clone(),
+        )))
+    }
+}
+
+/// A reducer chaining a list of reducers, as created by [`combine`](fn.combine.html).
+pub struct CombinedReducer<State, Action> {
+    reducers: Vec<Box<dyn Reducible<State, Action>>>,
+}
+
+impl<State: Clone, Action: Clone> Reducible<State, Action> for CombinedReducer<State, Action> {
+    fn reduce(&self, state: &State, action: Action) -> State {
+        let (first, rest) = match self.reducers.split_first() {
+            Some(split) => split,
+            None => return state.clone(),
+        };
+        rest.iter()
+            .fold(first.reduce(state, action.clone()), |state, reducer| {
+                reducer.reduce(&state, action.clone())
+            })
+    }
+
+    fn depth(&self) -> usize {
+        1 + self
+            .reducers
+            .iter()
+            .map(|reducer| reducer.depth())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Combines a list of reducers into a single one.
+///
+/// This is the function-based counterpart of [`combine_reducers!`](macro.combine_reducers.html), useful when the list of reducers
+/// is only known at runtime: the first one gets called first, chained into the second one and so on...
+///
+/// An empty list combines into a reducer leaving the state unchanged, which is handy when building the list dynamically.
+///
+/// Warning: this requires `State` and `Action` to be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine, Reducible};
+/// #
+/// fn add_one(state: &u8, _: ()) -> u8 {
+///     state + 1
+/// }
+///
+/// fn double(state: &u8, _: ()) -> u8 {
+///     state * 2
+/// }
+///
+/// let reducer = combine(vec![Box::new(add_one), Box::new(double)]);
+/// assert_eq!(reducer.reduce(&1, ()), 4);
+/// ```
+pub fn combine<State: Clone, Action: Clone>(
+    reducers: Vec<Box<dyn Reducible<State, Action>>>,
+) -> CombinedReducer<State, Action> {
+    CombinedReducer { reducers }
+}
+
+/// Combines a list of reducers running side by side, merging the states they produce.
+///
+/// Unlike [`combine`](fn.combine.html), which chains the reducers, every reducer receives the same input state and produces a candidate.
+/// The candidates are then folded using `merge`, starting from the state `init` derives from the input state.
+/// This suits reducers updating independent parts of the state, merged e.g. field-wise.
+///
+/// Warning: this requires `Action` to be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine_parallel, Reducible};
+/// #
+/// fn bump_low(state: &(u8, u8), _: ()) -> (u8, u8) {
+///     (state.0 + 1, state.1)
+/// }
+///
+/// fn bump_high(state: &(u8, u8), _: ()) -> (u8, u8) {
+///     (state.0, state.1 + 10)
+/// }
+///
+/// let reducer = combine_parallel(
+///     vec![Box::new(bump_low), Box::new(bump_high)],
+///     |merged: (u8, u8), candidate: (u8, u8)| (merged.0.max(candidate.0), merged.1.max(candidate.1)),
+///     |state: &(u8, u8)| *state
+/// );
+///
+/// assert_eq!(reducer.reduce(&(1, 1), ()), (2, 11));
+/// ```
+pub fn combine_parallel<State, Action, M, I>(
+    reducers: Vec<Box<dyn Reducible<State, Action>>>,
+    merge: M,
+    init: I,
+) -> impl Fn(&State, Action) -> State
+where
+    Action: Clone,
+    M: Fn(State, State) -> State,
+    I: Fn(&State) -> State,
+{
+    move |state: &State, action: Action| {
+        reducers.iter().fold(init(state), |merged, reducer| {
+            merge(merged, reducer.reduce(state, action.clone()))
+        })
+    }
+}
+
+/// Combines a list of reducers like [`combine`](fn.combine.html), rejecting compositions nested more than `max_depth` levels deep.
+///
+/// Combined reducers may themselves contain combined reducers: when assembling them dynamically, this guards against
+/// pathological compositions recursing deeply at dispatch time. See [`Reducible::depth`](trait.Reducible.html#method.depth).
+///
+/// Warning: this requires `State` and `Action` to be `Clone`.
+///
+/// # Errors
+///
+/// Fails with a [`DepthError`](struct.DepthError.html) if the combined reducer would be nested more than `max_depth` levels deep.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine, combine_bounded, Reducible};
+/// #
+/// fn add_one(state: &u8, _: ()) -> u8 {
+///     state + 1
+/// }
+///
+/// let inner = combine(vec![Box::new(add_one), Box::new(add_one)]);
+/// assert_eq!(inner.depth(), 2);
+///
+/// assert!(combine_bounded(vec![Box::new(inner)], 2).is_err());
+/// ```
+pub fn combine_bounded<State: Clone, Action: Clone>(
+    reducers: Vec<Box<dyn Reducible<State, Action>>>,
+    max_depth: usize,
+) -> Result<CombinedReducer<State, Action>, DepthError> {
+    let reducer = combine(reducers);
+    let depth = reducer.depth();
+    if depth > max_depth {
+        return Err(DepthError { depth, max_depth });
+    }
+    Ok(reducer)
+}
+
+/// Error returned when combined reducers are nested too deeply.
+///
+/// See [`combine_bounded`](fn.combine_bounded.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepthError {
+    /// The depth of the rejected composition.
+    pub depth: usize,
+    /// The maximum depth allowed.
+    pub max_depth: usize,
+}
+
+impl fmt::Display for DepthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reducers nested {} levels deep, exceeding the maximum of {}",
+            self.depth, self.max_depth
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DepthError {}
+
+/// Turns a reducer returning `Option<State>` into a regular reducer, leaving the state unchanged on `None`.
+///
+/// This is meant for reducers using checked arithmetic: rather than overflowing (which panics in debug builds),
+/// an action which cannot be applied is simply ignored.
+/// For clamping behavior, prefer saturating arithmetic (e.g. `saturating_add`) in a regular reducer.
+///
+/// Warning: this requires `State` to be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{checked_reduce, Store};
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// fn counter_reducer(state: &i8, action: Action) -> Option<i8> {
+///     match action {
+///         Action::Increment => state.checked_add(1),
+///         Action::Decrement => state.checked_sub(1)
+///     }
+/// }
+///
+/// let mut store = Store::new(checked_reduce(counter_reducer), i8::MAX);
+///
+/// store.dispatch(Action::Increment);
+/// assert_eq!(*store.state(), i8::MAX);
+/// ```
+pub fn checked_reduce<State, Action, F>(reducer: F) -> impl Fn(&State, Action) -> State
+where
+    State: Clone,
+    F: Fn(&State, Action) -> Option<State>,
+{
+    move |state: &State, action: Action| reducer(state, action).unwrap_or_else(|| state.clone())
+}
+
+/// Wraps a reducer of namespaced actions into a reducer of the parent action type.
+///
+/// `unwrap` returns the namespaced action if the parent action belongs to the namespace, usually by matching its variant.
+/// The sub-reducer only runs for those actions, while all other actions leave the state unchanged.
+/// This allows composing modules with their own action enums, e.g. using [`combine_reducers!`](macro.combine_reducers.html).
+///
+/// Warning: this requires `State` and `SubAction` to be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{namespaced_reducer, Store};
+/// #
+/// #[derive(Clone)]
+/// enum CounterAction {
+///     Increment
+/// }
+///
+/// enum Action {
+///     Counter(CounterAction),
+///     Logout
+/// }
+///
+/// fn counter_reducer(state: &u8, action: CounterAction) -> u8 {
+///     match action {
+///         CounterAction::Increment => state + 1
+///     }
+/// }
+///
+/// let reducer = namespaced_reducer(
+///     |action: &Action| match action {
+///         Action::Counter(action) => Some(action),
+///         _ => None
+///     },
+///     counter_reducer
+/// );
+///
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.dispatch(Action::Counter(CounterAction::Increment));
+/// store.dispatch(Action::Logout);
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub fn namespaced_reducer<State, Action, SubAction, U, R>(
+    unwrap: U,
+    sub_reducer: R,
+) -> impl Fn(&State, Action) -> State
+where
+    State: Clone,
+    SubAction: Clone,
+    U: Fn(&Action) -> Option<&SubAction>,
+    R: Reducible<State, SubAction>,
+{
+    move |state: &State, action: Action| match unwrap(&action) {
+        Some(sub_action) => sub_reducer.reduce(state, sub_action.clone()),
+        None => state.clone(),
+    }
+}
+
+/// Wraps a reducer, checking an invariant on every state it produces.
+///
+/// When the invariant does not hold for the state resulting from an action,
+/// the wrapped reducer panics in debug builds and prints the offending action to standard error in release builds.
+///
+/// Warning: this requires `Action` to be `Clone` and `Debug`.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use redux_rs::{guard_reducer, Store};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Withdraw(u8)
+/// }
+///
+/// fn reducer(balance: &i16, action: Action) -> i16 {
+///     match action {
+///         Action::Withdraw(amount) => balance - amount as i16
+///     }
+/// }
+///
+/// let mut store = Store::new(guard_reducer(reducer, |balance: &i16| *balance >= 0), 10);
+///
+/// store.dispatch(Action::Withdraw(5));
+/// store.dispatch(Action::Withdraw(10)); // Panics in debug builds.
+/// ```
+#[cfg(feature = "std")]
+pub fn guard_reducer<State, Action, R, I>(
+    reducer: R,
+    invariant: I,
+) -> impl Fn(&State, Action) -> State
+where
+    Action: Clone + core::fmt::Debug,
+    R: Reducible<State, Action>,
+    I: Fn(&State) -> bool,
+{
+    move |state: &State, action: Action| {
+        let new_state = reducer.reduce(state, action.clone());
+        if !invariant(&new_state) {
+            if cfg!(debug_assertions) {
+                panic!("invariant violated after reducing {:?}", action);
+            } else {
+                eprintln!("invariant violated after reducing {:?}", action);
+            }
+        }
+        new_state
+    }
+}
+
+/// Wraps a reducer, checking that it is pure by running it twice on every `(state, action)` pair.
+///
+/// In debug builds, the wrapped reducer panics when both runs produce different states,
+/// catching accidental nondeterminism such as reading the time or a random number generator.
+/// In release builds, the check is compiled out and the reducer only runs once.
+///
+/// Warning: this requires `State` to be `PartialEq`, and `Action` to be `Clone` and `Debug`.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use redux_rs::{assert_pure, Store};
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let reducer = move |state: &u8, increment: u8| {
+///     calls.set(calls.get() + 1);
+///     state + increment + calls.get()
+/// };
+///
+/// let mut store = Store::new(assert_pure(reducer), 0);
+/// store.dispatch(1); // Panics in debug builds.
+/// ```
+pub fn assert_pure<State, Action, R>(reducer: R) -> impl Fn(&State, Action) -> State
+where
+    State: PartialEq,
+    Action: Clone + core::fmt::Debug,
+    R: Reducible<State, Action>,
+{
+    move |state: &State, action: Action| {
+        if cfg!(debug_assertions) {
+            let first = reducer.reduce(state, action.clone());
+            let second = reducer.reduce(state, action.clone());
+            assert!(
+                first == second,
+                "reducer is not pure: reducing {:?} twice produced different states",
+                action
+            );
+            second
+        } else {
+            reducer.reduce(state, action)
+        }
+    }
+}
+
+/// Wraps a deterministic reducer, caching the states it produces for the most recently seen `(state, action)` pairs.
+///
+/// When a pair was already reduced, the cached state is returned without running the reducer.
+/// At most `capacity` entries are kept, evicting the least recently used one when full.
+///
+/// Every entry holds a copy of the state and of the action, along with the resulting state.
+/// With large states, a small capacity keeps memory in check; caching only pays off when identical pairs actually repeat.
+/// The reducer has to be pure, as side effects of cached transitions are skipped.
+///
+/// Warning: this requires `State` and `Action` to be `Hash`, `Eq` and `Clone`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{memoized_reducer, Store};
+/// #
+/// fn expensive_reducer(state: &u64, exponent: u32) -> u64 {
+///     (0..exponent).fold(1, |power, _| power * state)
+/// }
+///
+/// let mut store = Store::new(memoized_reducer(expensive_reducer, 16), 2);
+///
+/// store.dispatch(3);
+/// assert_eq!(*store.state(), 8);
+/// ```
+#[cfg(feature = "std")]
+pub fn memoized_reducer<State, Action, R>(
+    reducer: R,
+    capacity: usize,
+) -> impl Fn(&State, Action) -> State
+where
+    State: Hash + Eq + Clone,
+    Action: Hash + Eq + Clone,
+    R: Reducible<State, Action>,
+{
+    let cache = RefCell::new(MemoCache {
+        entries: HashMap::new(),
+        tick: 0,
+    });
+    move |state: &State, action: Action| {
+        let key = (state.clone(), action);
+        let mut cache = cache.borrow_mut();
+        cache.tick += 1;
+        let tick = cache.tick;
+        if let Some((cached, last_used)) = cache.entries.get_mut(&key) {
+            *last_used = tick;
+            return cached.clone();
+        }
+
+        let new_state = reducer.reduce(state, key.1.clone());
+        if capacity > 0 {
+            if cache.entries.len() >= capacity {
+                let oldest = cache
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (_, last_used))| *last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    cache.entries.remove(&oldest);
+                }
+            }
+            cache.entries.insert(key, (new_state.clone(), tick));
+        }
+        new_state
+    }
+}
+
+/// The cache of a memoized reducer, along with the time each entry was last used.
+#[cfg(feature = "std")]
+struct MemoCache<State, Action> {
+    entries: HashMap<(State, Action), (State, u64)>,
+    tick: u64,
+}
+
+/// Wraps a reducer, printing a delta to standard error whenever an action changes the state.
+///
+/// Each delta reads `<action>: <old state> -> <new state>`.
+/// Actions leaving the state unchanged are not logged.
+/// Use [`with_delta_logging_to`](fn.with_delta_logging_to.html) to send the deltas elsewhere.
+///
+/// Warning: this requires `State` to be `PartialEq` and `Debug`, and `Action` to be `Clone` and `Debug`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{with_delta_logging, Store};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Increment,
+///     Nothing
+/// }
+///
+/// fn reducer(state: &u8, action: Action) -> u8 {
+///     match action {
+///         Action::Increment => state + 1,
+///         Action::Nothing => *state
+///     }
+/// }
+///
+/// let mut store = Store::new(with_delta_logging(reducer), 0);
+///
+/// store.dispatch(Action::Increment); // Prints "Increment: 0 -> 1".
+/// store.dispatch(Action::Nothing); // Prints nothing.
+/// ```
+#[cfg(feature = "std")]
+pub fn with_delta_logging<State, Action, R>(reducer: R) -> impl Fn(&State, Action) -> State
+where
+    State: PartialEq + core::fmt::Debug,
+    Action: Clone + core::fmt::Debug,
+    R: Reducible<State, Action>,
+{
+    with_delta_logging_to(reducer, |delta: &str| eprintln!("{}", delta))
+}
+
+/// Wraps a reducer, passing a delta to `log` whenever an action changes the state.
+///
+/// See [`with_delta_logging`](fn.with_delta_logging.html).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{with_delta_logging_to, Store};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let reducer = with_delta_logging_to(reducer, |delta: &str| println!("[store] {}", delta));
+/// let mut store = Store::new(reducer, 0);
+///
+/// store.dispatch(2); // Prints "[store] 2: 0 -> 2".
+/// ```
+#[cfg(feature = "std")]
+pub fn with_delta_logging_to<State, Action, R, L>(
+    reducer: R,
+    log: L,
+) -> impl Fn(&State, Action) -> State
+where
+    State: PartialEq + core::fmt::Debug,
+    Action: Clone + core::fmt::Debug,
+    R: Reducible<State, Action>,
+    L: Fn(&str),
+{
+    move |state: &State, action: Action| {
+        let new_state = reducer.reduce(state, action.clone());
+        if new_state != *state {
+            log(&format!("{:?}: {:?} -> {:?}", action, state, new_state));
+        }
+        new_state
+    }
+}
+
 #[macro_export]
 /// Combines multiple reducers into a single one.
 ///
 /// The first one gets called first, chained into the second one and so on...
+/// Every reducer receives the whole state as produced by the previous one, along with the original action.
+///
+/// This is not Redux's `combineReducers`, which gives each reducer its own slice of the state:
+/// for that, see [`combine_slices!`](macro.combine_slices.html).
+/// To stop at the first reducer handling the action, see [`combine_reducers_first!`](macro.combine_reducers_first.html).
 ///
 /// Warning: this requires `Action` to be `Clone`.
 ///
@@ -100,3 +911,261 @@ macro_rules! combine_reducers {
         }
     )
 }
+
+#[macro_export]
+/// Combines multiple optional reducers into a single one, stopping at the first one that handles the action.
+///
+/// Every reducer returns an `Option<State>`: `Some(State)` means the action was handled and the chain stops there,
+/// `None` passes the action on to the next reducer. If no reducer handles the action, the state is left unchanged.
+/// Every reducer receives the original state, as the ones before it did not produce any.
+///
+/// Warning: this requires `State` and `Action` to be `Clone`.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::combine_reducers_first;
+/// #
+/// # type State = u8;
+/// #
+/// # type Action = bool;
+/// #
+/// # fn first_reducer(_: &State, _: Action) -> Option<State> {
+/// #     None
+/// # }
+/// #
+/// # fn second_reducer(_: &State, _: Action) -> Option<State> {
+/// #     None
+/// # }
+/// #
+/// let reducer = combine_reducers_first!(State, Action, first_reducer, second_reducer);
+/// ```
+/// (`State` and `Action` being the actual types.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine_reducers_first, Store};
+/// #
+/// #[derive(Clone)]
+/// enum Action {
+///     Increment,
+///     Reset
+/// }
+///
+/// fn increment_reducer(state: &u8, action: Action) -> Option<u8> {
+///     match action {
+///         Action::Increment => Some(state + 1),
+///         _ => None
+///     }
+/// }
+///
+/// fn reset_reducer(_: &u8, action: Action) -> Option<u8> {
+///     match action {
+///         Action::Reset => Some(0),
+///         _ => None
+///     }
+/// }
+///
+/// let mut store = Store::new(combine_reducers_first!(u8, Action, increment_reducer, reset_reducer), 0);
+///
+/// store.dispatch(Action::Increment);
+/// assert_eq!(*store.state(), 1);
+///
+/// store.dispatch(Action::Reset);
+/// assert_eq!(*store.state(), 0);
+/// ```
+macro_rules! combine_reducers_first {
+    ($state: ty, $action: ty, $($reducer: ident),+) => (
+        |state: &$state, action: $action| -> $state {
+            $(
+                if let Some(state) = $reducer(state, action.clone()) {
+                    return state;
+                }
+            )+
+            state.clone()
+        }
+    )
+}
+
+#[macro_export]
+/// Combines reducers each owning a field of the state into a reducer for the whole state, like Redux's `combineReducers`.
+///
+/// Every reducer only sees its own field, and receives the original action.
+/// Reducers are independent: none sees what another one produced.
+/// Every field of the state has to be listed.
+///
+/// Warning: this requires `Action` to be `Clone`.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::combine_slices;
+/// #
+/// # struct State {
+/// #     first: u8,
+/// #     second: u8
+/// # }
+/// #
+/// # type Action = bool;
+/// #
+/// # fn first_reducer(_: &u8, _: Action) -> u8 {
+/// #     0
+/// # }
+/// #
+/// # fn second_reducer(_: &u8, _: Action) -> u8 {
+/// #     0
+/// # }
+/// #
+/// let reducer = combine_slices!(State, Action, first: first_reducer, second: second_reducer);
+/// ```
+/// (`State` and `Action` being the actual types, and `first` and `second` fields of the state.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine_slices, Store};
+/// #
+/// struct State {
+///     counter: u8,
+///     history: Vec<&'static str>
+/// }
+///
+/// #[derive(Clone)]
+/// enum Action {
+///     Increment,
+///     Reset
+/// }
+///
+/// fn counter_reducer(counter: &u8, action: Action) -> u8 {
+///     match action {
+///         Action::Increment => counter + 1,
+///         Action::Reset => 0
+///     }
+/// }
+///
+/// fn history_reducer(history: &Vec<&'static str>, action: Action) -> Vec<&'static str> {
+///     let mut history = history.clone();
+///     history.push(match action {
+///         Action::Increment => "increment",
+///         Action::Reset => "reset"
+///     });
+///     history
+/// }
+///
+/// let reducer = combine_slices!(State, Action, counter: counter_reducer, history: history_reducer);
+/// let mut store = Store::new(reducer, State { counter: 0, history: Vec::new() });
+///
+/// store.dispatch(Action::Increment);
+/// store.dispatch(Action::Increment);
+/// assert_eq!(store.state().counter, 2);
+/// assert_eq!(store.state().history, vec!["increment", "increment"]);
+/// ```
+macro_rules! combine_slices {
+    ($state: path, $action: ty, $($field: ident: $reducer: expr),+ $(,)?) => (
+        |state: &$state, action: $action| -> $state {
+            $state {
+                $($field: $reducer(&state.$field, action.clone()),)+
+            }
+        }
+    )
+}
+
+#[macro_export]
+/// Defines a reducer matching on every variant of the action enum explicitly.
+///
+/// Arms have to name a variant (`Action::Variant`, optionally followed by its fields), so catch-all arms such as `_` or a binding are rejected.
+/// Thanks to the compiler's exhaustiveness checking, adding a variant to the action enum fails the build until the reducer handles it.
+/// Every arm has to end with a comma.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::exhaustive_reducer;
+/// #
+/// # type State = u8;
+/// #
+/// # enum Action {
+/// #     Increment,
+/// #     Add(u8),
+/// #     Set { value: u8 }
+/// # }
+/// #
+/// exhaustive_reducer! {
+///     fn reducer(state: &State, action: Action) -> State {
+///         Action::Increment => state + 1,
+///         Action::Add(value) => state + value,
+///         Action::Set { value } => value,
+///     }
+/// }
+/// ```
+/// (`State` and `Action` being the actual types.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{exhaustive_reducer, Store};
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// exhaustive_reducer! {
+///     fn counter_reducer(state: &i8, action: Action) -> i8 {
+///         Action::Increment => state + 1,
+///         Action::Decrement => state - 1,
+///     }
+/// }
+///
+/// let mut store = Store::new(counter_reducer, 0);
+/// store.dispatch(Action::Increment);
+/// ```
+///
+/// Forgetting a variant does not compile:
+///
+/// ```compile_fail
+/// # use redux_rs::exhaustive_reducer;
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// exhaustive_reducer! {
+///     fn counter_reducer(state: &i8, action: Action) -> i8 {
+///         Action::Increment => state + 1,
+///     }
+/// }
+/// ```
+///
+/// Neither does a catch-all arm:
+///
+/// ```compile_fail
+/// # use redux_rs::exhaustive_reducer;
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// exhaustive_reducer! {
+///     fn counter_reducer(state: &i8, action: Action) -> i8 {
+///         Action::Increment => state + 1,
+///         _ => *state,
+///     }
+/// }
+/// ```
+macro_rules! exhaustive_reducer {
+    (
+        $vis: vis fn $name: ident($state: ident: &$state_type: ty, $action: ident: $action_type: ty) -> $return_type: ty {
+            $($enum: ident :: $variant: ident $(($($tuple: tt)*))? $({$($fields: tt)*})? => $body: expr,)+
+        }
+    ) => (
+        $vis fn $name($state: &$state_type, $action: $action_type) -> $return_type {
+            match $action {
+                $($enum::$variant $(($($tuple)*))? $({$($fields)*})? => $body,)+
+            }
+        }
+    )
+}
@@ -0,0 +1,243 @@
+use crate::{combine, Box, CombinedReducer, Reducible, Vec};
+#[cfg(feature = "inventory")]
+use std::{
+    any::Any,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+#[cfg(all(
+    feature = "inventory",
+    not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+        target_os = "illumos",
+        target_vendor = "apple",
+        windows
+    ))
+))]
+compile_error!(
+    "the `inventory` feature needs static constructors, which this target does not support"
+);
+
+/// A collection of reducers contributed by independent modules.
+///
+/// Instead of listing every reducer in one place, each module registers its own ones,
+/// and the store is created from all of them using [`Store::new_with_registered`](struct.Store.html#method.new_with_registered).
+/// Reducers run in registration order, each one receiving the state produced by the previous one.
+///
+/// With the `inventory` feature, modules can also register reducers at link time using [`register_reducer!`](macro.register_reducer.html),
+/// without any central list, see [`collected`](#method.collected).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{ReducerRegistry, Store};
+/// #
+/// mod counter {
+///     # use redux_rs::ReducerRegistry;
+///     #
+///     fn reducer(state: &u8, _: ()) -> u8 {
+///         state + 1
+///     }
+///
+///     pub fn register(registry: &mut ReducerRegistry<u8, ()>) {
+///         registry.register(reducer);
+///     }
+/// }
+///
+/// let mut registry = ReducerRegistry::new();
+/// counter::register(&mut registry);
+///
+/// let mut store = Store::new_with_registered(registry, 0);
+/// store.dispatch(());
+/// assert_eq!(*store.state(), 1);
+/// ```
+pub struct ReducerRegistry<State, Action> {
+    reducers: Vec<Box<dyn Reducible<State, Action>>>,
+}
+
+impl<State, Action> ReducerRegistry<State, Action> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            reducers: Vec::new(),
+        }
+    }
+
+    /// Creates a registry holding every reducer registered at link time for these state and action types.
+    ///
+    /// See [`register_reducer!`](macro.register_reducer.html).
+    /// Requires the `inventory` feature.
+    #[cfg(feature = "inventory")]
+    pub fn collected() -> Self
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        let mut reducers = Vec::new();
+        let mut registration = REGISTERED.load(Ordering::Acquire);
+        // Registrations are only ever linked from `'static` references, see `__register_reducer`.
+        while let Some(current) = unsafe { registration.as_ref() } {
+            if let Ok(reducer) = (current.reducer)().downcast::<fn(&State, Action) -> State>() {
+                reducers.push(*reducer);
+            }
+            registration = current.next.load(Ordering::Acquire);
+        }
+
+        let mut registry = Self::new();
+        for reducer in reducers.into_iter().rev() {
+            registry.register(reducer);
+        }
+        registry
+    }
+
+    /// Registers a reducer.
+    pub fn register<R: Reducible<State, Action> + 'static>(&mut self, reducer: R) {
+        self.reducers.push(Box::new(reducer));
+    }
+
+    /// Returns the number of registered reducers.
+    pub fn len(&self) -> usize {
+        self.reducers.len()
+    }
+
+    /// Returns whether no reducer got registered.
+    pub fn is_empty(&self) -> bool {
+        self.reducers.is_empty()
+    }
+
+    /// Combines all registered reducers into a single one.
+    pub fn into_reducer(self) -> CombinedReducer<State, Action>
+    where
+        State: Clone,
+        Action: Clone,
+    {
+        combine(self.reducers)
+    }
+}
+
+impl<State, Action> Default for ReducerRegistry<State, Action> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reducers registered at link time, most recent first.
+#[cfg(feature = "inventory")]
+static REGISTERED: AtomicPtr<__Registration> = AtomicPtr::new(ptr::null_mut());
+
+/// A reducer registered at link time, linked to the ones registered before it.
+///
+/// Registrations are statics, so registering neither allocates nor locks before `main`.
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub struct __Registration {
+    reducer: fn() -> Box<dyn Any>,
+    next: AtomicPtr<__Registration>,
+}
+
+#[cfg(feature = "inventory")]
+impl __Registration {
+    /// Creates a registration for the reducer boxed by `reducer`, see [`__boxed_reducer`].
+    pub const fn new(reducer: fn() -> Box<dyn Any>) -> Self {
+        Self {
+            reducer,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+}
+
+/// Boxes a reducer function, for the registry to find it by type.
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub fn __boxed_reducer<State: 'static, Action: 'static>(
+    reducer: fn(&State, Action) -> State,
+) -> Box<dyn Any> {
+    Box::new(reducer)
+}
+
+/// Registers a reducer at link time, see [`register_reducer!`](macro.register_reducer.html).
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub fn __register_reducer(registration: &'static __Registration) {
+    let mut head = REGISTERED.load(Ordering::Acquire);
+    loop {
+        registration.next.store(head, Ordering::Relaxed);
+        let new_head = registration as *const __Registration as *mut __Registration;
+        match REGISTERED.compare_exchange_weak(head, new_head, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => return,
+            Err(current) => head = current,
+        }
+    }
+}
+
+/// Registers a reducer function at link time, to be collected by [`ReducerRegistry::collected`](struct.ReducerRegistry.html#method.collected).
+///
+/// This allows feature modules to contribute reducers without any central list, like the `inventory` crate does.
+/// Requires the `inventory` feature.
+///
+/// Caveats:
+///
+/// - registration runs before `main` through the platform's static constructors (ELF `.init_array`, Mach-O `__mod_init_func`, or the MSVC CRT initializers),
+///   so enabling the feature fails to compile for other targets, such as WebAssembly;
+/// - like with `inventory`, the linker may discard a module whose object file is otherwise unreferenced, e.g. in a static library,
+///   dropping its reducers along with it;
+/// - reducers of different modules run in an unspecified order, so they should not depend on each other.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{register_reducer, ReducerRegistry, Store};
+/// #
+/// mod counter {
+///     pub fn reducer(state: &u8, _: ()) -> u8 {
+///         state + 1
+///     }
+///
+///     redux_rs::register_reducer!(reducer);
+/// }
+///
+/// let mut store = Store::new_with_registered(ReducerRegistry::collected(), 0u8);
+/// store.dispatch(());
+/// assert_eq!(*store.state(), 1);
+/// ```
+#[cfg(feature = "inventory")]
+#[macro_export]
+macro_rules! register_reducer {
+    ($reducer:path) => {
+        const _: () = {
+            fn boxed() -> ::std::boxed::Box<dyn ::std::any::Any> {
+                $crate::__boxed_reducer($reducer)
+            }
+
+            static REGISTRATION: $crate::__Registration = $crate::__Registration::new(boxed);
+
+            extern "C" fn register() {
+                $crate::__register_reducer(&REGISTRATION);
+            }
+
+            #[used]
+            #[cfg_attr(
+                any(
+                    target_os = "linux",
+                    target_os = "android",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd",
+                    target_os = "dragonfly",
+                    target_os = "illumos"
+                ),
+                link_section = ".init_array"
+            )]
+            #[cfg_attr(target_vendor = "apple", link_section = "__DATA,__mod_init_func")]
+            #[cfg_attr(windows, link_section = ".CRT$XCU")]
+            static REGISTER: extern "C" fn() = register;
+        };
+    };
+}
@@ -0,0 +1,326 @@
+use crate::Store;
+use std::fmt::Write;
+
+/// Converts between JSON text and the actions and states of a store, for a [`StoreRpcHandler`](struct.StoreRpcHandler.html).
+pub trait RpcCodec<State, Action> {
+    fn decode_action(&self, params: &str) -> Result<Action, String>;
+    fn encode_state(&self, state: &State) -> String;
+}
+
+/// Codec signature for a pair of functions, decoding actions and encoding states.
+///
+/// The first function receives the `params` of a `dispatch` request as raw JSON text, and returns the action or an error message.
+/// The second one returns the state as raw JSON text, e.g. using `serde_json::to_string`.
+/// Requires the `rpc` feature.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Store, StoreRpcHandler};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let handler = StoreRpcHandler::new((
+///     |params: &str| params.parse::<u8>().map_err(|error| error.to_string()),
+///     |state: &u8| state.to_string()
+/// ));
+/// ```
+impl<State, Action, Decode, Encode> RpcCodec<State, Action> for (Decode, Encode)
+where
+    Decode: Fn(&str) -> Result<Action, String>,
+    Encode: Fn(&State) -> String,
+{
+    fn decode_action(&self, params: &str) -> Result<Action, String> {
+        (self.0)(params)
+    }
+
+    fn encode_state(&self, state: &State) -> String {
+        (self.1)(state)
+    }
+}
+
+/// Drives a store over JSON-RPC 2.0, e.g. from a debugging UI.
+///
+/// Two methods are supported: `dispatch`, whose `params` get decoded into an action and dispatched, and `get_state`, returning the encoded state.
+/// Only the request envelope is parsed by the handler: actions and states go through an [`RpcCodec`](trait.RpcCodec.html).
+/// As the crate has no dependencies, the handler is not bound on serde traits: plug `serde_json::from_str` and `serde_json::to_string` into the codec instead.
+/// Requests nested more than 128 levels deep are rejected with a parse error, so remote clients can't exhaust the stack.
+/// Requires the `rpc` feature.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Store, StoreRpcHandler};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+/// let handler = StoreRpcHandler::new((
+///     |params: &str| params.parse::<u8>().map_err(|error| error.to_string()),
+///     |state: &u8| state.to_string()
+/// ));
+///
+/// handler.handle(&mut store, r#"{"jsonrpc": "2.0", "method": "dispatch", "params": 2}"#);
+///
+/// let response = handler.handle(&mut store, r#"{"jsonrpc": "2.0", "method": "get_state", "id": 1}"#);
+/// assert_eq!(response.as_deref(), Some(r#"{"jsonrpc":"2.0","result":2,"id":1}"#));
+/// ```
+pub struct StoreRpcHandler<Codec> {
+    codec: Codec,
+}
+
+impl<Codec> StoreRpcHandler<Codec> {
+    /// Creates a handler using `codec` for actions and states.
+    pub fn new(codec: Codec) -> Self {
+        Self { codec }
+    }
+
+    /// Handles a single request, returning the response.
+    ///
+    /// Notifications (requests without an `id`) get no response, as mandated by JSON-RPC.
+    /// Malformed requests, unknown methods and undecodable actions get an error response.
+    pub fn handle<State, Action>(
+        &self,
+        store: &mut Store<State, Action>,
+        request: &str,
+    ) -> Option<String>
+    where
+        Codec: RpcCodec<State, Action>,
+    {
+        let members = match Scanner::new(request).object() {
+            Some(members) => members,
+            None => return Some(error_response("null", PARSE_ERROR, "parse error")),
+        };
+        let member = |name: &str| {
+            members
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| *value)
+        };
+
+        let id = member("id");
+        let response_id = id.unwrap_or("null");
+        if member("jsonrpc") != Some("\"2.0\"") {
+            return Some(error_response(
+                response_id,
+                INVALID_REQUEST,
+                "invalid request",
+            ));
+        }
+
+        let result = match member("method") {
+            Some("\"dispatch\"") => {
+                let params = member("params").unwrap_or("null");
+                match self.codec.decode_action(params) {
+                    Ok(action) => {
+                        store.dispatch(action);
+                        Ok("null".to_string())
+                    }
+                    Err(message) => Err((INVALID_PARAMS, message)),
+                }
+            }
+            Some("\"get_state\"") => Ok(self.codec.encode_state(store.state())),
+            Some(_) => Err((METHOD_NOT_FOUND, "method not found".to_string())),
+            None => Err((INVALID_REQUEST, "invalid request".to_string())),
+        };
+
+        id.map(|id| match result {
+            Ok(result) => format!(r#"{{"jsonrpc":"2.0","result":{},"id":{}}}"#, result, id),
+            Err((code, message)) => error_response(id, code, &message),
+        })
+    }
+}
+
+/// How deeply arrays and objects may be nested in a request.
+const MAX_DEPTH: usize = 128;
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// Formats an error response.
+fn error_response(id: &str, code: i32, message: &str) -> String {
+    let mut escaped = String::new();
+    for character in message.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            character if character.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", character as u32);
+            }
+            character => escaped.push(character),
+        }
+    }
+    format!(
+        r#"{{"jsonrpc":"2.0","error":{{"code":{},"message":"{}"}},"id":{}}}"#,
+        code, escaped, id
+    )
+}
+
+/// A minimal JSON scanner, splitting an object into its members while keeping their values as raw text.
+struct Scanner<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    /// Parses the whole text as an object.
+    fn object(mut self) -> Option<Vec<(String, &'a str)>> {
+        let members = self.members()?;
+        self.skip_whitespace();
+        if self.position == self.text.len() {
+            Some(members)
+        } else {
+            None
+        }
+    }
+
+    fn members(&mut self) -> Option<Vec<(String, &'a str)>> {
+        let mut members = Vec::new();
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.eat(b'}') {
+            return Some(members);
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            if self.eat(b'}') {
+                return Some(members);
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    /// Skips a value, returning its raw text.
+    fn value(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek()? {
+            byte @ b'{' | byte @ b'[' => {
+                if self.depth == MAX_DEPTH {
+                    return None;
+                }
+                self.depth += 1;
+                if byte == b'{' {
+                    self.members()?;
+                } else {
+                    self.array()?;
+                }
+                self.depth -= 1;
+            }
+            b'"' => {
+                self.string()?;
+            }
+            _ => {
+                while matches!(self.peek(), Some(byte) if !b",:}] \t\n\r".contains(&byte)) {
+                    self.position += 1;
+                }
+                let literal = &self.text[start..self.position];
+                let is_number = literal.parse::<f64>().is_ok()
+                    && matches!(literal.bytes().next(), Some(byte) if byte == b'-' || byte.is_ascii_digit());
+                if !is_number && !["true", "false", "null"].contains(&literal) {
+                    return None;
+                }
+            }
+        }
+        Some(&self.text[start..self.position])
+    }
+
+    fn array(&mut self) -> Option<()> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.eat(b']') {
+            return Some(());
+        }
+
+        loop {
+            self.value()?;
+            self.skip_whitespace();
+            if self.eat(b']') {
+                return Some(());
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut string = String::new();
+        loop {
+            let character = self.text[self.position..].chars().next()?;
+            self.position += character.len_utf8();
+            match character {
+                '"' => return Some(string),
+                '\\' => {
+                    let escape = self.peek()?;
+                    self.position += 1;
+                    string.push(match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let hex = self.text.get(self.position..self.position + 4)?;
+                            self.position += 4;
+                            char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+                                .unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return None,
+                    });
+                }
+                character if character.is_control() => return None,
+                character => string.push(character),
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(byte) if b" \t\n\r".contains(&byte)) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.eat(byte) {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
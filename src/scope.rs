@@ -0,0 +1,30 @@
+use crate::Store;
+
+/// A view on a slice of a store's state, dispatching its own actions to the store.
+///
+/// Created by [`Store::scope`](struct.Store.html#method.scope).
+pub struct ScopedStore<'a, State, Action, SubState, SubAction> {
+    store: &'a mut Store<State, Action>,
+    get: fn(&State) -> &SubState,
+    lift: fn(SubAction) -> Action,
+}
+
+impl<'a, State, Action, SubState, SubAction> ScopedStore<'a, State, Action, SubState, SubAction> {
+    pub(crate) fn new(
+        store: &'a mut Store<State, Action>,
+        get: fn(&State) -> &SubState,
+        lift: fn(SubAction) -> Action,
+    ) -> Self {
+        Self { store, get, lift }
+    }
+
+    /// Returns the current slice of the state.
+    pub fn state(&self) -> &SubState {
+        (self.get)(self.store.state())
+    }
+
+    /// Lifts an action into an action of the store and dispatches it.
+    pub fn dispatch(&mut self, action: SubAction) {
+        self.store.dispatch((self.lift)(action));
+    }
+}
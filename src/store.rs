@@ -1,17 +1,113 @@
-use crate::{Middleware, Reducible, Subscription, Vec};
-
-// TODO: should be part of a trait (this is the last thing we need to do to
-// address https://github.com/redux-rs/redux-rs/issues/5).
-type Reducer<State, Action> = fn(&State, Action) -> State;
+#[cfg(feature = "async")]
+use crate::async_middleware::{thread_sleep, Timeout};
+use crate::backend::StateStorage;
+#[cfg(feature = "causality")]
+use crate::causality::Causality;
+use crate::context::ContextCell;
+#[cfg(feature = "std")]
+use crate::crash::{panic_message, CrashReporting};
+use crate::effects::EffectBus;
+use crate::middleware::MiddlewareEntry;
+use crate::notification::{NotificationChannel, NotifyingReducer};
+#[cfg(feature = "std")]
+use crate::reducer::HashEqual;
+use crate::reducer::{
+    ComparingReducer, CowReducer, PartialEqual, PlainReducer, StateEq, StoreReducer,
+};
+#[cfg(feature = "test-util")]
+use crate::test::{StepReport, TraceEvent, TraceRecorder};
+#[cfg(feature = "causality")]
+use crate::{ActionGraph, ActionId};
+use crate::{
+    AroundMiddleware, Box, CloneableSubscription, ContextReducible, CowReducible, Dispatcher,
+    Extensions, Feature, Middleware, MiddlewareId, MiddlewareOrderError, MiddlewarePhase,
+    MiddlewareTag, Next, NotifyingReducible, Observable, Rc, ReducerRegistry, Reducible,
+    ScopedStore, StateBackend, Subscription, SubscriptionControl, SubscriptionId, Vec, VecDeque,
+};
+#[cfg(feature = "async")]
+use crate::{AsyncMiddleware, DispatchTimeout, Stream, TaskFuture, Timer, WaitFor};
+#[cfg(feature = "std")]
+use crate::{
+    CrashReport, CrashReporterMiddleware, Diff, DispatchMetrics, MetricsCollector,
+    ThrottledSubscription, TryReducible,
+};
+#[cfg(feature = "log")]
+use crate::{DiagnosticsLogger, LogLevel};
+use core::any::Any;
+use core::cell::{Cell, Ref, RefCell, RefMut};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::mem::{self, Discriminant};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::{poll_fn, Future};
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 /// A container holding a state and providing the possibility to dispatch actions.
 ///
 /// A store is defined by the state is holds and the actions it can dispatch.
 pub struct Store<State, Action> {
-    reducer: Reducer<State, Action>,
-    state: State,
-    middleware: Vec<Box<dyn Middleware<State, Action>>>,
-    subscriptions: Vec<Box<dyn Subscription<State>>>,
+    reducer: Rc<dyn StoreReducer<State, Action>>,
+    state: StateStorage<State>,
+    middleware: Vec<MiddlewareEntry<State, Action>>,
+    middleware_order: Vec<(MiddlewareTag, MiddlewareTag)>,
+    around_middleware: Vec<Box<dyn AroundMiddleware<State, Action>>>,
+    next_middleware_id: usize,
+    running_middleware: Cell<Option<MiddlewareId>>,
+    dispatch_after: Cell<Option<MiddlewareId>>,
+    middleware_capacity: Option<usize>,
+    subscription_capacity: Option<usize>,
+    transactions: usize,
+    reductions: usize,
+    change_detection: bool,
+    unhandled: Option<UnhandledHandler<State, Action>>,
+    action_observers: Vec<ActionObserver<Action>>,
+    halt_observers: Vec<HaltObserver<Action>>,
+    halt_clone_action: Option<fn(&Action) -> Action>,
+    first_dispatch: Cell<Option<FirstDispatchHook<State, Action>>>,
+    subscriptions: Vec<(SubscriptionId, Box<dyn Subscription<State>>)>,
+    cloneable: Vec<(SubscriptionId, Box<dyn CloneableSubscription<State>>)>,
+    next_subscription_id: usize,
+    detached: Rc<RefCell<Vec<SubscriptionId>>>,
+    fired: RefCell<Option<Vec<SubscriptionId>>>,
+    snapshots: SnapshotRing<State>,
+    pending: RefCell<VecDeque<(Action, Cause)>>,
+    queue_capacity: Option<(usize, QueueOverflowPolicy)>,
+    dispatch_paused: bool,
+    effects: EffectBus,
+    notifications: Option<Box<dyn Fn()>>,
+    notification_channel: Option<Rc<dyn Any>>,
+    context: Option<Box<dyn Any>>,
+    extensions: RefCell<Extensions>,
+    #[cfg(feature = "async")]
+    async_middleware: Vec<(usize, Box<dyn AsyncMiddleware<State, Action>>)>,
+    #[cfg(feature = "async")]
+    timer: Option<Box<dyn Timer>>,
+    #[cfg(feature = "std")]
+    metrics: Option<Box<dyn MetricsCollector>>,
+    #[cfg(feature = "std")]
+    subscription_panics: SubscriptionPanicPolicy,
+    #[cfg(feature = "std")]
+    error_handler: Option<ErrorHandler>,
+    #[cfg(feature = "std")]
+    crash_reporter: Option<CrashReporting<State, Action>>,
+    #[cfg(feature = "std")]
+    halt_reason: RefCell<Option<String>>,
+    #[cfg(feature = "std")]
+    checkpoints: RefCell<HashMap<String, State>>,
+    #[cfg(feature = "causality")]
+    causality: Causality,
+    #[cfg(feature = "test-util")]
+    trace: Option<TraceRecorder>,
+    #[cfg(feature = "log")]
+    logger: Option<Box<dyn DiagnosticsLogger>>,
 }
 
 impl<State, Action> Store<State, Action> {
@@ -38,12 +134,413 @@ impl<State, Action> Store<State, Action> {
     ///
     /// let mut store = Store::new(reducer, 0);
     /// ```
-    pub fn new(reducer: Reducer<State, Action>, initial_state: State) -> Self {
+    pub fn new<R: Reducible<State, Action> + 'static>(reducer: R, initial_state: State) -> Self {
+        Self::with_store_reducer(
+            Rc::new(PlainReducer(reducer)),
+            StateStorage::Inline(initial_state),
+        )
+    }
+
+    /// Creates a new store, seeding its state by replaying bootstrap actions through the reducer.
+    ///
+    /// Since no middleware or subscriptions are registered yet, only the reducer sees the bootstrap actions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// enum Action {
+    ///     AddTodo(&'static str)
+    /// }
+    ///
+    /// fn reducer(state: &Vec<&'static str>, action: Action) -> Vec<&'static str> {
+    ///     match action {
+    ///         Action::AddTodo(todo) => [state.as_slice(), &[todo]].concat()
+    ///     }
+    /// }
+    ///
+    /// let store = Store::bootstrapped(reducer, Vec::new(), vec![Action::AddTodo("Read the docs")]);
+    /// assert_eq!(*store.state(), vec!["Read the docs"]);
+    /// ```
+    pub fn bootstrapped<R, I>(reducer: R, initial_state: State, actions: I) -> Self
+    where
+        R: Reducible<State, Action> + 'static,
+        I: IntoIterator<Item = Action>,
+    {
+        let mut store = Self::new(reducer, initial_state);
+        actions
+            .into_iter()
+            .for_each(|action| store.dispatch(action));
+        store
+    }
+
+    /// Creates a new store keeping its state in a backend, e.g. for large or off-heap states.
+    ///
+    /// The backend's current state is used as the initial state.
+    /// See [`StateBackend`](trait.StateBackend.html), including its notes on performance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{InMemoryBackend, Store};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new_with_backend(reducer, InMemoryBackend::new(0));
+    ///
+    /// store.dispatch(2);
+    /// assert_eq!(*store.state(), 2);
+    /// ```
+    pub fn new_with_backend<R, B>(reducer: R, backend: B) -> Self
+    where
+        R: Reducible<State, Action> + 'static,
+        B: StateBackend<State> + 'static,
+    {
+        Self::with_store_reducer(
+            Rc::new(PlainReducer(reducer)),
+            StateStorage::Backend(Box::new(backend)),
+        )
+    }
+
+    /// Creates a new store whose reducer receives read-only context on every dispatch.
+    ///
+    /// The context can be replaced later on using [`set_context`](#method.set_context).
+    /// See [`ContextReducible`](trait.ContextReducible.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// struct Locale {
+    ///     decimal_separator: char
+    /// }
+    ///
+    /// fn reducer(_: &String, value: u8, locale: &Locale) -> String {
+    ///     format!("{}{}5", value, locale.decimal_separator)
+    /// }
+    ///
+    /// let mut store = Store::new_with_context(reducer, String::new(), Locale { decimal_separator: '.' });
+    ///
+    /// store.dispatch(1);
+    /// assert_eq!(store.state(), "1.5");
+    ///
+    /// store.set_context(Locale { decimal_separator: ',' });
+    /// store.dispatch(1);
+    /// assert_eq!(store.state(), "1,5");
+    /// ```
+    pub fn new_with_context<R, Context>(reducer: R, initial_state: State, context: Context) -> Self
+    where
+        R: ContextReducible<State, Action, Context> + 'static,
+        Context: 'static,
+    {
+        let context = Rc::new(RefCell::new(context));
+        let reducer_context = context.clone();
+        let mut store = Self::new(
+            move |state: &State, action: Action| {
+                reducer.reduce(state, action, &reducer_context.borrow())
+            },
+            initial_state,
+        );
+        store.context = Some(Box::new(ContextCell(context)));
+        store
+    }
+
+    /// Creates a new store from all reducers of a registry, run in registration order.
+    ///
+    /// See [`ReducerRegistry`](struct.ReducerRegistry.html).
+    /// If no reducer got registered, dispatching leaves the state unchanged.
+    ///
+    /// Warning: this requires `State` and `Action` to be `Clone`.
+    pub fn new_with_registered(
+        registry: ReducerRegistry<State, Action>,
+        initial_state: State,
+    ) -> Self
+    where
+        State: Clone + 'static,
+        Action: Clone + 'static,
+    {
+        Self::new(registry.into_reducer(), initial_state)
+    }
+
+    /// Creates a new store only notifying subscriptions when an action changed the state.
+    ///
+    /// A state equal to the previous one is discarded.
+    /// See [`new_with_change_detection_by`](#method.new_with_change_detection_by) to compare states differently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// fn reducer(state: &u8, value: u8) -> u8 {
+    ///     value
+    /// }
+    ///
+    /// let mut store = Store::new_with_change_detection(reducer, 0);
+    /// store.subscribe(|state: &u8| println!("Changed to {}", state));
+    ///
+    /// store.dispatch(0); // Prints nothing.
+    /// store.dispatch(1); // Prints "Changed to 1".
+    /// ```
+    pub fn new_with_change_detection<R: Reducible<State, Action> + 'static>(
+        reducer: R,
+        initial_state: State,
+    ) -> Self
+    where
+        State: PartialEq,
+    {
+        Self::with_comparing_reducer(reducer, initial_state, PartialEqual)
+    }
+
+    /// Creates a new store only notifying subscriptions when an action changed the state, according to `eq`.
+    ///
+    /// `eq` returns whether two states are to be considered equal, e.g. ignoring some fields.
+    /// A new state equal to the previous one is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// struct State {
+    ///     value: u8,
+    ///     updated_at: u64
+    /// }
+    ///
+    /// fn reducer(state: &State, value: u8) -> State {
+    ///     State { value, updated_at: state.updated_at + 1 }
+    /// }
+    ///
+    /// let mut store = Store::new_with_change_detection_by(
+    ///     reducer,
+    ///     State { value: 0, updated_at: 0 },
+    ///     |a: &State, b: &State| a.value == b.value
+    /// );
+    /// ```
+    pub fn new_with_change_detection_by<R, E>(reducer: R, initial_state: State, eq: E) -> Self
+    where
+        R: Reducible<State, Action> + 'static,
+        E: Fn(&State, &State) -> bool + 'static,
+    {
+        Self::with_comparing_reducer(reducer, initial_state, eq)
+    }
+
+    /// Creates a new store only notifying subscriptions when an action changed the hash of the state.
+    ///
+    /// This is meant for states which can be hashed but not compared cheaply.
+    /// Both the previous and the new state are hashed on every dispatch.
+    /// Requires the `std` feature.
+    ///
+    /// Warning: two different states may have the same hash. In that rare case, the new state is discarded
+    /// like an unchanged one, so subscriptions are not notified and the store keeps the previous state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// fn reducer(_: &Vec<u8>, values: Vec<u8>) -> Vec<u8> {
+    ///     values
+    /// }
+    ///
+    /// let mut store = Store::new_with_hash_change_detection(reducer, vec![0]);
+    /// store.subscribe(|state: &Vec<u8>| println!("Changed to {:?}", state));
+    ///
+    /// store.dispatch(vec![0]); // Prints nothing.
+    /// store.dispatch(vec![0, 1]); // Prints "Changed to [0, 1]".
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_with_hash_change_detection<R: Reducible<State, Action> + 'static>(
+        reducer: R,
+        initial_state: State,
+    ) -> Self
+    where
+        State: Hash,
+    {
+        Self::with_comparing_reducer(reducer, initial_state, HashEqual)
+    }
+
+    fn with_comparing_reducer<R, E>(reducer: R, initial_state: State, eq: E) -> Self
+    where
+        R: Reducible<State, Action> + 'static,
+        E: StateEq<State> + 'static,
+    {
+        Self::with_detecting_reducer(
+            Rc::new(ComparingReducer(reducer, Rc::new(eq))),
+            initial_state,
+        )
+    }
+
+    /// Creates a new store whose reducer tells accurately whether the state changed.
+    pub(crate) fn with_detecting_reducer(
+        reducer: Rc<dyn StoreReducer<State, Action>>,
+        initial_state: State,
+    ) -> Self {
+        let mut store = Self::with_store_reducer(reducer, StateStorage::Inline(initial_state));
+        store.change_detection = true;
+        store
+    }
+
+    /// Creates a new store whose initial state is built from a configuration read from `reader`.
+    ///
+    /// The whole configuration is read as text, parsed by `parse` (e.g. a JSON or TOML deserializer), then turned into the initial state by `build`.
+    /// Requires the `std` feature.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ConfigError::Io`](enum.ConfigError.html#variant.Io) if reading fails, or with [`ConfigError::Parse`](enum.ConfigError.html#variant.Parse) if parsing does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// struct State {
+    ///     volume: u8
+    /// }
+    ///
+    /// # fn reducer(state: &State, volume: u8) -> State {
+    /// #     State { volume }
+    /// # }
+    /// #
+    /// let config = "42".as_bytes();
+    ///
+    /// let store = Store::from_config(
+    ///     reducer,
+    ///     config,
+    ///     |text: &str| text.trim().parse::<u8>(),
+    ///     |volume: u8| State { volume }
+    /// ).unwrap();
+    /// assert_eq!(store.state().volume, 42);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_config<R, Reader, Config, E, P, B>(
+        reducer: R,
+        mut reader: Reader,
+        parse: P,
+        build: B,
+    ) -> Result<Self, ConfigError<E>>
+    where
+        R: Reducible<State, Action> + 'static,
+        Reader: Read,
+        P: FnOnce(&str) -> Result<Config, E>,
+        B: FnOnce(Config) -> State,
+    {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(ConfigError::Io)?;
+        let config = parse(&text).map_err(ConfigError::Parse)?;
+        Ok(Self::new(reducer, build(config)))
+    }
+
+    /// Creates a new store using a copy-on-write reducer.
+    ///
+    /// The state only gets replaced when the reducer returns `Cow::Owned`, so actions leaving the state unchanged never clone it.
+    ///
+    /// See [`CowReducible`](trait.CowReducible.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// use std::borrow::Cow;
+    ///
+    /// fn reducer<'a>(state: &'a Vec<u8>, action: Option<u8>) -> Cow<'a, Vec<u8>> {
+    ///     match action {
+    ///         Some(value) => {
+    ///             let mut values = state.clone();
+    ///             values.push(value);
+    ///             Cow::Owned(values)
+    ///         }
+    ///         None => Cow::Borrowed(state)
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new_cow(reducer, Vec::new());
+    ///
+    /// store.dispatch(Some(1));
+    /// store.dispatch(None);
+    /// assert_eq!(*store.state(), vec![1]);
+    /// ```
+    pub fn new_cow<R: CowReducible<State, Action> + 'static>(
+        reducer: R,
+        initial_state: State,
+    ) -> Self
+    where
+        State: Clone,
+    {
+        Self::with_store_reducer(
+            Rc::new(CowReducer(reducer)),
+            StateStorage::Inline(initial_state),
+        )
+    }
+
+    fn with_store_reducer(
+        reducer: Rc<dyn StoreReducer<State, Action>>,
+        state: StateStorage<State>,
+    ) -> Self {
         Self {
             reducer,
-            state: initial_state,
+            state,
             middleware: Vec::new(),
+            middleware_order: Vec::new(),
+            around_middleware: Vec::new(),
+            next_middleware_id: 0,
+            running_middleware: Cell::new(None),
+            dispatch_after: Cell::new(None),
+            middleware_capacity: None,
+            subscription_capacity: None,
+            transactions: 0,
+            reductions: 0,
+            change_detection: false,
+            unhandled: None,
+            action_observers: Vec::new(),
+            halt_observers: Vec::new(),
+            halt_clone_action: None,
+            first_dispatch: Cell::new(None),
             subscriptions: Vec::new(),
+            cloneable: Vec::new(),
+            next_subscription_id: 0,
+            detached: Rc::new(RefCell::new(Vec::new())),
+            fired: RefCell::new(None),
+            snapshots: SnapshotRing {
+                capacity: 0,
+                states: VecDeque::new(),
+                clone_state: None,
+            },
+            pending: RefCell::new(VecDeque::new()),
+            queue_capacity: None,
+            dispatch_paused: false,
+            effects: EffectBus::new(),
+            notifications: None,
+            notification_channel: None,
+            context: None,
+            extensions: RefCell::new(Extensions::new()),
+            #[cfg(feature = "async")]
+            async_middleware: Vec::new(),
+            #[cfg(feature = "async")]
+            timer: None,
+            #[cfg(feature = "std")]
+            metrics: None,
+            #[cfg(feature = "std")]
+            subscription_panics: SubscriptionPanicPolicy::Propagate,
+            #[cfg(feature = "std")]
+            error_handler: None,
+            #[cfg(feature = "std")]
+            crash_reporter: None,
+            #[cfg(feature = "std")]
+            halt_reason: RefCell::new(None),
+            #[cfg(feature = "std")]
+            checkpoints: RefCell::new(HashMap::new()),
+            #[cfg(feature = "causality")]
+            causality: Causality::default(),
+            #[cfg(feature = "test-util")]
+            trace: None,
+            #[cfg(feature = "log")]
+            logger: None,
         }
     }
 
@@ -54,17 +551,153 @@ impl<State, Action> Store<State, Action> {
     /// ```
     /// # use redux_rs::Store;
     /// #
-    /// # let store = Store::new(|&u8, ()| 0, 0);
+    /// # let store = Store::new(|_: &u8, _: ()| 0, 0);
     /// #
     /// println!("Current state: {}", store.state());
     /// ```
     pub fn state(&self) -> &State {
-        &self.state
+        self.state.get()
+    }
+
+    /// Consumes the store, returning its current state.
+    ///
+    /// The reducer, middleware and subscriptions are dropped.
+    /// Actions still waiting in the queue are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.dispatch(3);
+    ///
+    /// assert_eq!(store.into_state(), 3);
+    /// ```
+    pub fn into_state(self) -> State {
+        self.state.into_state()
+    }
+
+    /// Consumes the store, returning its reducer and its state, along with whether the reducer tells accurately whether the state changed.
+    pub(crate) fn into_parts(self) -> (Rc<dyn StoreReducer<State, Action>>, State, bool) {
+        (self.reducer, self.state.into_state(), self.change_detection)
+    }
+
+    /// Creates an independent store sharing this store's reducer, starting from a clone of its state.
+    ///
+    /// The fork keeps its state inline and starts without middleware or subscriptions,
+    /// see [`fork_with_subscriptions`](#method.fork_with_subscriptions) to carry the latter over.
+    ///
+    /// Warning: this requires `State` to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 1);
+    /// let mut fork = store.fork();
+    ///
+    /// store.dispatch(1);
+    /// fork.dispatch(10);
+    /// assert_eq!(*store.state(), 2);
+    /// assert_eq!(*fork.state(), 11);
+    /// ```
+    pub fn fork(&self) -> Self
+    where
+        State: Clone,
+    {
+        let mut fork = Self::with_store_reducer(
+            self.reducer.clone(),
+            StateStorage::Inline(self.state().clone()),
+        );
+        fork.change_detection = self.change_detection;
+        fork
+    }
+
+    /// Forks the store like [`fork`](#method.fork), carrying over the subscriptions registered with [`subscribe_cloneable`](#method.subscribe_cloneable).
+    ///
+    /// Each store notifies its own clones of the subscriptions, while other subscriptions are not carried over.
+    ///
+    /// Warning: this requires `State` to be `Clone`.
+    pub fn fork_with_subscriptions(&self) -> Self
+    where
+        State: Clone,
+    {
+        let mut fork = self.fork();
+        let detached = self.detached.borrow();
+        for (id, subscription) in &self.cloneable {
+            if !detached.contains(id) {
+                let id = fork.push_subscription(subscription.clone_boxed());
+                fork.cloneable.push((id, subscription.clone_subscription()));
+            }
+        }
+        fork
+    }
+
+    /// Creates a view focused on a slice of the state, dispatching its own actions lifted into actions of this store.
+    ///
+    /// This allows composing components only knowing about their own state and actions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// struct State {
+    ///     counter: i8,
+    ///     name: &'static str
+    /// }
+    ///
+    /// enum CounterAction {
+    ///     Increment
+    /// }
+    ///
+    /// enum Action {
+    ///     Counter(CounterAction),
+    ///     Rename(&'static str)
+    /// }
+    ///
+    /// fn reducer(state: &State, action: Action) -> State {
+    ///     match action {
+    ///         Action::Counter(CounterAction::Increment) => State { counter: state.counter + 1, ..*state },
+    ///         Action::Rename(name) => State { name, ..*state }
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, State { counter: 0, name: "counter" });
+    ///
+    /// let mut counter = store.scope(|state: &State| &state.counter, Action::Counter);
+    /// counter.dispatch(CounterAction::Increment);
+    /// assert_eq!(*counter.state(), 1);
+    /// ```
+    pub fn scope<SubState, SubAction>(
+        &mut self,
+        get: fn(&State) -> &SubState,
+        lift: fn(SubAction) -> Action,
+    ) -> ScopedStore<'_, State, Action, SubState, SubAction> {
+        ScopedStore::new(self, get, lift)
+    }
+
+    /// Replaces the state without notifying subscriptions, returning the previous one.
+    pub(crate) fn replace_state(&mut self, state: State) -> State {
+        self.state.set(state)
     }
 
     /// Dispatches an action which is handles by the reducer, after the store got passed through the middleware.
     /// This can modify the state within the store.
     ///
+    /// The action is moved along the chain: each middleware takes it by value and hands it on, possibly replaced, and the reducer finally consumes it.
+    /// To dispatch an action kept around, see [`dispatch_ref`](#method.dispatch_ref).
+    ///
     /// # Example
     ///
     /// ```
@@ -89,108 +722,2960 @@ impl<State, Action> Store<State, Action> {
     /// println!("Current state: {}", store.state());
     /// ```
     pub fn dispatch(&mut self, action: Action) {
-        if self.middleware.is_empty() {
-            self.dispatch_reducer(action);
-        } else {
-            self.dispatch_middleware(0, action);
+        if self.dispatch_paused {
+            self.enqueue(action);
+            return;
         }
-    }
 
-    /// Runs one middleware.
-    fn dispatch_middleware(&mut self, index: usize, action: Action) {
-        if index == self.middleware.len() {
-            self.dispatch_reducer(action);
-        } else if let Some(action) = self.middleware[index].next(self, action) {
-            self.dispatch_middleware(index + 1, action);
-        }
+        self.dispatch_caused(action, self.current_cause());
     }
 
-    /// Runs the reducer.
-    fn dispatch_reducer(&mut self, action: Action) {
-        self.state = (&self.reducer).reduce(self.state(), action);
-        self.dispatch_subscriptions();
+    /// Dispatches an action, recording its cause if keeping track of causality.
+    #[cfg_attr(not(feature = "causality"), allow(unused_variables))]
+    fn dispatch_caused(&mut self, action: Action, cause: Cause) {
+        self.dispatch_after.set(cause.after);
+        #[cfg(feature = "causality")]
+        self.causality.begin(cause.action);
+        self.dispatch_action(action);
+        #[cfg(feature = "causality")]
+        self.causality.end();
     }
 
-    /// Runs all subscriptions.
-    fn dispatch_subscriptions(&self) {
-        self.subscriptions
-            .iter()
-            .for_each(|subscription| subscription.update(self.state()));
+    fn dispatch_action(&mut self, action: Action) {
+        #[cfg(feature = "std")]
+        {
+            if let Some(crash_reporter) = self.crash_reporter.take() {
+                let payload = crash_reporter.watch(self, action, Self::dispatch_action);
+                self.crash_reporter = Some(crash_reporter);
+                if let Some(payload) = payload {
+                    panic::resume_unwind(payload);
+                }
+                return;
+            }
+            if self.metrics.is_some() {
+                self.dispatch_measured(action);
+                return;
+            }
+        }
+
+        if let Some(action) = self.dispatch_middleware(action) {
+            if self.dispatch_reducer(action) {
+                self.dispatch_subscriptions();
+                self.remove_detached_subscriptions();
+            }
+        }
     }
 
-    /// Subscribes a callback to any change of the state.
+    /// Dispatches a copy of an action, leaving the original with the caller.
     ///
-    /// Subscriptions will be called, whenever an action is dispatched.
+    /// This is convenient with `Copy` actions, or to dispatch the same action several times.
     ///
-    /// See [`Subscription`](type.Subscription.html).
+    /// Warning: this requires `Action` to be `Clone`.
     ///
     /// # Example
     ///
     /// ```
-    /// use redux_rs::{Store, Subscription};
-    /// #
-    /// # type State = u8;
-    /// # let initial_state = 0;
+    /// # use redux_rs::Store;
     /// #
-    /// # fn reducer(_: &State, action: bool) -> State {
-    /// #     0
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
     /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
     ///
-    /// let mut store = Store::new(reducer, initial_state);
-    ///
-    /// let listener = |state: &State| {
-    ///     println!("Something changed! New value: {}", state);
-    /// };
-    ///
-    /// store.subscribe(listener);
+    /// let increment = 2;
+    /// store.dispatch_ref(&increment);
+    /// store.dispatch_ref(&increment);
+    /// assert_eq!(*store.state(), 4);
     /// ```
-    pub fn subscribe<S: Subscription<State> + 'static>(&mut self, callback: S) {
-        self.subscriptions.push(Box::new(callback));
+    pub fn dispatch_ref(&mut self, action: &Action)
+    where
+        Action: Clone,
+    {
+        self.dispatch(action.clone());
     }
 
-    /// Adds a custom middleware to the store.
-    ///
-    /// Middleware provides the possibility to intercept actions dispatched before they reach the reducer.
+    /// Dispatches an action like [`dispatch`](#method.dispatch), returning the ids of the subscriptions which fired, in order.
     ///
-    /// See [`Middleware`](type.Middleware.html).
-    pub fn add_middleware<M: Middleware<State, Action> + 'static>(&mut self, middleware: M) {
-        self.middleware.push(Box::new(middleware));
-    }
-
-    /// Replaces the currently used reducer.
+    /// Subscriptions skipping a state, e.g. the ones added using [`subscribe_selector`](#method.subscribe_selector), do not count as fired.
+    /// This helps checking that change detection and selectors skip the right subscriptions.
     ///
     /// # Example
     ///
     /// ```
     /// # use redux_rs::Store;
     /// #
-    /// # pub struct State(u8);
-    /// #
-    /// # impl State {
-    /// #     pub fn something_else() -> State {
-    /// #         State(1)
-    /// #     }
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
     /// # }
     /// #
-    /// # enum Action {
-    /// #     SomeAction
-    /// # }
+    /// let mut store = Store::new(reducer, 0);
+    /// let id = store.subscribe(|state: &u8| println!("New value: {}", state));
+    ///
+    /// assert_eq!(store.dispatch_with_report(1), vec![id]);
+    /// ```
+    pub fn dispatch_with_report(&mut self, action: Action) -> Vec<SubscriptionId> {
+        let previous = self.fired.replace(Some(Vec::new()));
+        self.dispatch(action);
+        let fired = self.fired.replace(previous).unwrap_or_default();
+        if let Some(outer) = self.fired.borrow_mut().as_mut() {
+            outer.extend(fired.iter().copied());
+        }
+        fired
+    }
+
+    /// Runs the middleware chain only, returning the action it passes on (if any) without reducing it.
+    ///
+    /// Together with [`apply_reducer`](#method.apply_reducer), this splits a dispatch in two phases,
+    /// e.g. to validate an action through the middleware and decide later on whether to commit it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
     /// #
-    /// # fn reducer(state: &State, action: Action) -> State {
-    /// #     State(0)
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
     /// # }
     /// #
-    /// # let mut store = Store::new(reducer, State(0));
-    /// #
-    /// store.dispatch(Action::SomeAction);
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware(|_: &Store<u8, u8>, increment: u8| Some(increment.min(10)));
     ///
-    /// store.replace_reducer(|state: &State, action: Action| {
+    /// let action = store.run_middleware(42);
+    /// assert_eq!(action, Some(10));
+    /// assert_eq!(*store.state(), 0);
+    ///
+    /// if let Some(action) = action {
+    ///     store.apply_reducer(action);
+    /// }
+    /// assert_eq!(*store.state(), 10);
+    /// ```
+    pub fn run_middleware(&self, action: Action) -> Option<Action> {
+        self.run_middleware_from(0, action)
+    }
+
+    /// Reduces an action and notifies subscriptions, skipping the middleware chain.
+    ///
+    /// Around middleware still wraps the reducer.
+    /// See [`run_middleware`](#method.run_middleware).
+    pub fn apply_reducer(&mut self, action: Action) {
+        if self.dispatch_reducer(action) {
+            self.dispatch_subscriptions();
+            self.remove_detached_subscriptions();
+        }
+    }
+
+    /// Returns the state the reducer would produce for an action, without changing the store.
+    ///
+    /// Neither middleware nor subscriptions run, and the current state is left untouched.
+    /// This is useful for previews and validation, as long as the reducer is pure.
+    ///
+    /// Warning: this requires `State` and `Action` to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let store = Store::new(reducer, 1);
+    ///
+    /// assert_eq!(store.preview(&2), 3);
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn preview(&self, action: &Action) -> State
+    where
+        State: Clone,
+        Action: Clone,
+    {
+        let mut state = self.state().clone();
+        self.reducer.reduce_in_place(&mut state, action.clone());
+        state
+    }
+
+    /// Dispatches an action computed from the current state.
+    ///
+    /// This avoids reading the state and dispatching in separate steps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// enum Action {
+    ///     Enable,
+    ///     Disable
+    /// }
+    ///
+    /// fn reducer(_: &bool, action: Action) -> bool {
+    ///     match action {
+    ///         Action::Enable => true,
+    ///         Action::Disable => false
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, false);
+    ///
+    /// // Toggle.
+    /// store.dispatch_with(|enabled| if *enabled { Action::Disable } else { Action::Enable });
+    /// assert!(*store.state());
+    /// ```
+    pub fn dispatch_with<F: FnOnce(&State) -> Action>(&mut self, f: F) {
+        let action = f(self.state());
+        self.dispatch(action);
+    }
+
+    /// Replaces the state with the one computed by `f` from the current state, then notifies subscriptions.
+    ///
+    /// Unlike dispatching, no action is involved: neither middleware nor the reducer run.
+    /// This is useful to apply changes coming from outside the store, e.g. a patch received from a server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 1);
+    ///
+    /// store.update_state(|state| state * 10);
+    /// assert_eq!(*store.state(), 10);
+    /// ```
+    pub fn update_state<F: FnOnce(&State) -> State>(&mut self, f: F) {
+        let state = f(self.state());
+        self.replace_state(state);
+        self.dispatch_subscriptions();
+    }
+
+    /// Gives `f` mutable access to the state, then notifies subscriptions.
+    ///
+    /// This intentionally bypasses the reducer for imperative edits, e.g. in performance-critical code avoiding to rebuild the state.
+    /// Since no action is involved, neither middleware nor the reducer run, and nothing gets recorded by action logging.
+    /// Subscriptions are notified even if `f` left the state unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &Vec<u8>, value: u8) -> Vec<u8> {
+    /// #     let mut state = state.clone();
+    /// #     state.push(value);
+    /// #     state
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, vec![1, 2]);
+    ///
+    /// store.state_mut_scope(|values| values.push(3));
+    /// assert_eq!(*store.state(), vec![1, 2, 3]);
+    /// ```
+    pub fn state_mut_scope<F: FnOnce(&mut State)>(&mut self, f: F) {
+        f(self.state.get_mut());
+        self.state.commit();
+        self.dispatch_subscriptions();
+    }
+
+    /// Applies a fallible reducer to the state, then notifies subscriptions if it succeeded.
+    ///
+    /// Like [`update_state`](#method.update_state), neither middleware nor the store's reducer run.
+    /// On error, the state is left as is and the error is reported to the [global error handler](#method.set_global_error_handler).
+    ///
+    /// See [`TryReducible`](trait.TryReducible.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// fn withdraw(balance: &u8, amount: u8) -> Result<u8, &'static str> {
+    ///     balance.checked_sub(amount).ok_or("insufficient funds")
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 5);
+    ///
+    /// assert_eq!(store.try_reduce(withdraw, 2), Ok(()));
+    /// assert_eq!(store.try_reduce(withdraw, 10), Err("insufficient funds"));
+    /// assert_eq!(*store.state(), 3);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_reduce<R>(&mut self, reducer: R, action: Action) -> Result<(), R::Error>
+    where
+        R: TryReducible<State, Action>,
+        R::Error: fmt::Display,
+    {
+        match reducer.try_reduce(self.state(), action) {
+            Ok(state) => {
+                self.replace_state(state);
+                self.dispatch_subscriptions();
+                Ok(())
+            }
+            Err(error) => {
+                self.report_error(StoreError::Reducer(error.to_string()));
+                Err(error)
+            }
+        }
+    }
+
+    /// Stores a copy of the current state under a label, to compare against later.
+    ///
+    /// Checkpointing an existing label replaces its state.
+    /// See [`diff_from_checkpoint`](#method.diff_from_checkpoint).
+    ///
+    /// Warning: this requires `State` to be `Clone`.
+    #[cfg(feature = "std")]
+    pub fn checkpoint(&self, label: &str)
+    where
+        State: Clone,
+    {
+        self.checkpoints
+            .borrow_mut()
+            .insert(label.to_string(), self.state().clone());
+    }
+
+    /// Returns what changed since the state was checkpointed under a label, or `None` if there is no such checkpoint.
+    ///
+    /// See [`Diff`](trait.Diff.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Diff, Store};
+    /// #
+    /// # fn reducer(state: &Counter, increment: u8) -> Counter {
+    /// #     Counter(state.0 + increment)
+    /// # }
+    /// #
+    /// #[derive(Clone)]
+    /// struct Counter(u8);
+    ///
+    /// impl Diff for Counter {
+    ///     type Patch = i16;
+    ///
+    ///     fn diff(&self, newer: &Self) -> i16 {
+    ///         i16::from(newer.0) - i16::from(self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, Counter(0));
+    ///
+    /// store.checkpoint("screen opened");
+    /// store.dispatch(2);
+    /// store.dispatch(3);
+    ///
+    /// assert_eq!(store.diff_from_checkpoint("screen opened"), Some(5));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn diff_from_checkpoint(&self, label: &str) -> Option<State::Patch>
+    where
+        State: Diff,
+    {
+        self.checkpoints
+            .borrow()
+            .get(label)
+            .map(|checkpoint| checkpoint.diff(self.state()))
+    }
+
+    /// Keeps copies of the last `capacity` states produced by the reducer, e.g. for a crash reporter to dump recent history.
+    ///
+    /// Enabling the ring again changes its capacity, dropping the oldest states if needed.
+    /// See [`recent_states`](#method.recent_states).
+    ///
+    /// Warning: this requires `State` to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.enable_snapshot_ring(2);
+    ///
+    /// store.dispatch(1);
+    /// store.dispatch(2);
+    /// store.dispatch(3);
+    ///
+    /// assert_eq!(store.recent_states(), &[3, 6]);
+    /// ```
+    pub fn enable_snapshot_ring(&mut self, capacity: usize)
+    where
+        State: Clone,
+    {
+        let snapshots = &mut self.snapshots;
+        snapshots.capacity = capacity;
+        snapshots.clone_state = Some(State::clone);
+        let excess = snapshots.states.len().saturating_sub(capacity);
+        snapshots.states.drain(..excess);
+        snapshots.states.reserve(capacity - snapshots.states.len());
+    }
+
+    /// Returns the states kept by the snapshot ring, oldest first.
+    ///
+    /// Returns no states unless the ring got enabled using [`enable_snapshot_ring`](#method.enable_snapshot_ring).
+    pub fn recent_states(&self) -> &VecDeque<State> {
+        &self.snapshots.states
+    }
+
+    /// Replaces the context passed to the reducer, returning the previous one.
+    ///
+    /// See [`new_with_context`](#method.new_with_context).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the store was not created with a context of this type.
+    pub fn set_context<Context: 'static>(&mut self, context: Context) -> Context {
+        let cell = self
+            .context
+            .as_ref()
+            .and_then(|context| context.downcast_ref::<ContextCell<Context>>())
+            .expect("store has no context of this type");
+        cell.0.replace(context)
+    }
+
+    /// Creates a new store whose reducer signals outcomes through notifications besides the new state.
+    ///
+    /// Notifications are delivered to the handlers registered with [`subscribe_notifications`](#method.subscribe_notifications)
+    /// right after the reducer ran, before subscriptions get notified.
+    /// See [`NotifyingReducible`](trait.NotifyingReducible.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// struct Added(usize);
+    ///
+    /// fn reducer(state: &Vec<&'static str>, todo: &'static str) -> (Vec<&'static str>, Vec<Added>) {
+    ///     let mut todos = state.clone();
+    ///     todos.push(todo);
+    ///     (todos, vec![Added(state.len())])
+    /// }
+    ///
+    /// let mut store = Store::new_with_notifications(reducer, Vec::new());
+    /// store.subscribe_notifications(|added: &Added| println!("Added todo #{}", added.0));
+    ///
+    /// store.dispatch("Wash the car"); // Prints "Added todo #0".
+    /// ```
+    pub fn new_with_notifications<R, Notification>(reducer: R, initial_state: State) -> Self
+    where
+        R: NotifyingReducible<State, Action, Notification> + 'static,
+        Notification: 'static,
+    {
+        let channel = Rc::new(NotificationChannel::new());
+        let mut store = Self::with_store_reducer(
+            Rc::new(NotifyingReducer(reducer, channel.clone())),
+            StateStorage::Inline(initial_state),
+        );
+        let delivery_channel = channel.clone();
+        store.notifications = Some(Box::new(move || delivery_channel.deliver()));
+        store.notification_channel = Some(channel);
+        store
+    }
+
+    /// Subscribes a handler to the notifications of type `Notification` produced by the reducer.
+    ///
+    /// See [`new_with_notifications`](#method.new_with_notifications).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the store was not created with notifications of this type.
+    pub fn subscribe_notifications<Notification, F>(&mut self, handler: F)
+    where
+        Notification: 'static,
+        F: Fn(&Notification) + 'static,
+    {
+        let channel = self
+            .notification_channel
+            .as_ref()
+            .and_then(|channel| channel.downcast_ref::<NotificationChannel<Notification>>())
+            .expect("store has no notifications of this type");
+        channel.subscribe(handler);
+    }
+
+    /// Returns the extensions of the store, arbitrary data shared by middleware and subscriptions.
+    ///
+    /// See [`Extensions`](struct.Extensions.html).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extensions are currently borrowed mutably.
+    pub fn extensions(&self) -> Ref<'_, Extensions> {
+        self.extensions.borrow()
+    }
+
+    /// Returns the extensions of the store mutably.
+    ///
+    /// As this only needs a shared reference to the store, it can be used from within middleware.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// struct Dispatches(u32);
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.extensions_mut().insert(Dispatches(0));
+    ///
+    /// store.add_middleware(|store: &Store<u8, u8>, action: u8| {
+    ///     if let Some(dispatches) = store.extensions_mut().get_mut::<Dispatches>() {
+    ///         dispatches.0 += 1;
+    ///     }
+    ///     Some(action)
+    /// });
+    ///
+    /// store.dispatch(1);
+    /// assert_eq!(store.extensions().get::<Dispatches>().map(|dispatches| dispatches.0), Some(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extensions are currently borrowed.
+    pub fn extensions_mut(&self) -> RefMut<'_, Extensions> {
+        self.extensions.borrow_mut()
+    }
+
+    /// Queues an action to be dispatched later by [`drain_pending`](#method.drain_pending).
+    ///
+    /// As this only needs a shared reference to the store, it can be used from within middleware.
+    ///
+    /// The queue is unbounded by default, see [`set_queue_capacity`](#method.set_queue_capacity) to bound it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.enqueue(1);
+    /// store.enqueue(2);
+    /// assert_eq!(*store.state(), 0);
+    ///
+    /// store.drain_pending();
+    /// assert_eq!(*store.state(), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue is full and its overflow policy is [`QueueOverflowPolicy::Panic`](enum.QueueOverflowPolicy.html#variant.Panic).
+    pub fn enqueue(&self, action: Action) {
+        self.enqueue_caused(action, self.current_cause());
+    }
+
+    fn enqueue_caused(&self, action: Action, cause: Cause) {
+        let mut pending = self.pending.borrow_mut();
+        if let Some((capacity, policy)) = self.queue_capacity {
+            if pending.len() >= capacity {
+                match policy {
+                    QueueOverflowPolicy::RejectNewest => return,
+                    QueueOverflowPolicy::DropOldest => {
+                        pending.pop_front();
+                    }
+                    QueueOverflowPolicy::Panic => {
+                        panic!("action queue capacity of {} exceeded", capacity)
+                    }
+                }
+            }
+        }
+
+        pending.push_back((action, cause));
+    }
+
+    /// Returns the current state along with a handle to queue actions, both usable at the same time.
+    ///
+    /// Actions queued through the dispatcher are dispatched by [`drain_pending`](#method.drain_pending),
+    /// once the state is not borrowed anymore.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let (state, dispatcher) = store.split();
+    /// if *state < 10 {
+    ///     dispatcher.dispatch(1);
+    /// }
+    ///
+    /// store.drain_pending();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn split(&self) -> (&State, Dispatcher<'_, State, Action>) {
+        (self.state(), Dispatcher::new(self))
+    }
+
+    /// Bounds the queue of pending actions, applying `policy` to actions queued while it is full.
+    ///
+    /// This protects against buggy loops queueing actions endlessly.
+    /// The queue is unbounded by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{QueueOverflowPolicy, Store};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.set_queue_capacity(2, QueueOverflowPolicy::DropOldest);
+    ///
+    /// store.enqueue(1);
+    /// store.enqueue(2);
+    /// store.enqueue(3);
+    ///
+    /// store.drain_pending();
+    /// assert_eq!(*store.state(), 5);
+    /// ```
+    pub fn set_queue_capacity(&mut self, capacity: usize, policy: QueueOverflowPolicy) {
+        self.queue_capacity = Some((capacity, policy));
+    }
+
+    /// Emits an effect, a description of a side effect to be performed outside of the store.
+    ///
+    /// Effects can be of any type and get delivered to the subscribers of that type, see [`subscribe_effects`](#method.subscribe_effects).
+    /// As this only needs a shared reference to the store, it can be used from within middleware.
+    pub fn emit<E: 'static>(&self, effect: E) {
+        self.effects.emit(effect);
+    }
+
+    /// Subscribes a callback to all effects of type `E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// struct Notify(&'static str);
+    ///
+    /// fn notify_middleware(store: &Store<u8, ()>, action: ()) -> Option<()> {
+    ///     store.emit(Notify("dispatching"));
+    ///     Some(action)
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware(notify_middleware);
+    /// store.subscribe_effects(|notify: &Notify| println!("{}", notify.0));
+    ///
+    /// store.dispatch(());
+    /// ```
+    pub fn subscribe_effects<E: 'static, F: Fn(&E) + 'static>(&mut self, callback: F) {
+        self.effects.subscribe(callback);
+    }
+
+    /// Dispatches an action, returning all effects of type `E` emitted meanwhile.
+    ///
+    /// Effects still get delivered to their subscribers. Effects of other types are not returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// fn ping_middleware(store: &Store<u8, ()>, action: ()) -> Option<()> {
+    ///     store.emit("ping");
+    ///     Some(action)
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware(ping_middleware);
+    ///
+    /// assert_eq!(store.dispatch_collecting_effects::<&str>(()), vec!["ping"]);
+    /// ```
+    pub fn dispatch_collecting_effects<E: 'static>(&mut self, action: Action) -> Vec<E> {
+        self.effects.start_collecting();
+        self.dispatch(action);
+        self.effects.stop_collecting()
+    }
+
+    /// Returns the causality graph of all actions dispatched so far.
+    ///
+    /// Actions dispatched or queued while another action is being dispatched, e.g. by middleware, record the latter as their cause.
+    /// Only actions going through [`dispatch`](#method.dispatch), [`dispatch_async`](#method.dispatch_async) or [`drain_pending`](#method.drain_pending) get an id.
+    /// Since the graph keeps growing with every dispatch, the `causality` feature is best kept for debugging.
+    ///
+    /// See [`ActionGraph`](struct.ActionGraph.html).
+    #[cfg(feature = "causality")]
+    pub fn action_graph(&self) -> &ActionGraph {
+        self.causality.graph()
+    }
+
+    /// Returns the id of the action currently being dispatched, if any.
+    ///
+    /// This allows middleware to tag its own logs with the action id.
+    /// Requires the `causality` feature.
+    #[cfg(feature = "causality")]
+    pub fn current_action_id(&self) -> Option<ActionId> {
+        self.causality.current()
+    }
+
+    fn current_cause(&self) -> Cause {
+        Cause {
+            #[cfg(feature = "causality")]
+            action: self.causality.current(),
+            after: None,
+        }
+    }
+
+    /// Dispatches an action to the middleware following `after` only, e.g. for middleware re-dispatching its own output.
+    ///
+    /// The action is queued while dispatching is paused, and still skips `after` and everything before it once dispatched.
+    /// Without `after`, or if it got removed in the meantime, the action goes through the whole chain.
+    #[cfg(feature = "std")]
+    pub(crate) fn dispatch_after(&mut self, after: Option<MiddlewareId>, action: Action) {
+        let mut cause = self.current_cause();
+        cause.after = after;
+        if self.dispatch_paused {
+            self.enqueue_caused(action, cause);
+            return;
+        }
+
+        self.dispatch_caused(action, cause);
+    }
+
+    /// Returns the middleware currently handling an action, if any.
+    #[cfg(feature = "std")]
+    pub(crate) fn running_middleware(&self) -> Option<MiddlewareId> {
+        self.running_middleware.get()
+    }
+
+    /// Dispatches all queued actions in order, until the queue is empty.
+    ///
+    /// Actions queued while draining are dispatched as well.
+    /// Does nothing while dispatching is paused, see [`pause_dispatch`](#method.pause_dispatch).
+    pub fn drain_pending(&mut self) {
+        if self.dispatch_paused {
+            return;
+        }
+
+        loop {
+            let queued = self.pending.borrow_mut().pop_front();
+            match queued {
+                Some((action, cause)) => self.dispatch_caused(action, cause),
+                None => break,
+            }
+        }
+    }
+
+    /// Pauses dispatching: from now on, dispatched actions are queued without being processed.
+    ///
+    /// Unlike merely silencing subscriptions, neither middleware nor the reducer see the actions until dispatching is resumed
+    /// using [`resume_dispatch`](#method.resume_dispatch).
+    /// Actions are buffered in the same queue as [`enqueue`](#method.enqueue), so its capacity and overflow policy apply
+    /// (see [`set_queue_capacity`](#method.set_queue_capacity)), and [`drain_pending`](#method.drain_pending) does nothing meanwhile.
+    /// Only [`dispatch`](#method.dispatch) and the methods built on it are paused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.pause_dispatch();
+    /// store.dispatch(1);
+    /// store.dispatch(2);
+    /// assert_eq!(*store.state(), 0);
+    ///
+    /// store.resume_dispatch();
+    /// assert_eq!(*store.state(), 3);
+    /// ```
+    pub fn pause_dispatch(&mut self) {
+        self.dispatch_paused = true;
+    }
+
+    /// Resumes dispatching, dispatching all actions queued meanwhile in order.
+    ///
+    /// See [`pause_dispatch`](#method.pause_dispatch).
+    pub fn resume_dispatch(&mut self) {
+        self.dispatch_paused = false;
+        self.drain_pending();
+    }
+
+    /// Returns whether dispatching is paused, see [`pause_dispatch`](#method.pause_dispatch).
+    pub fn is_dispatch_paused(&self) -> bool {
+        self.dispatch_paused
+    }
+
+    /// Drives the store, dispatching the actions pulled from `source` until it returns `None`.
+    ///
+    /// Actions queued meanwhile, e.g. by middleware, are dispatched before pulling the next one.
+    /// This allows the store to own the main loop of an application.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let mut increments = vec![1, 2, 3].into_iter();
+    /// store.run(|| increments.next());
+    /// assert_eq!(*store.state(), 6);
+    /// ```
+    pub fn run<F: FnMut() -> Option<Action>>(&mut self, mut source: F) {
+        while let Some(action) = source() {
+            self.dispatch(action);
+            self.drain_pending();
+        }
+    }
+
+    /// Dispatches an action, running regular and async middleware as a single chain, in the order they were added.
+    ///
+    /// Regular middleware behaves as if it returned an immediately ready future.
+    /// The reducer and the subscriptions run once every middleware passed the action on.
+    ///
+    /// Like [`dispatch`](#method.dispatch), the action gets an id when keeping track of causality and is measured by the metrics collector.
+    /// While dispatching is paused, the action is queued instead: once dispatching is resumed, it is dispatched synchronously,
+    /// so it only goes through the regular middleware.
+    /// The crash reporter does not watch async dispatches, see [`set_crash_reporter`](#method.set_crash_reporter).
+    /// Requires the `async` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.add_async_middleware(|_: &Store<u8, u8>, increment: u8| async move {
+    ///     Some(increment * 2)
+    /// });
+    ///
+    /// let dispatch = store.dispatch_async(1);
+    /// // Poll `dispatch` with the executor of your choice.
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn dispatch_async(&mut self, action: Action) {
+        if self.dispatch_paused {
+            self.enqueue(action);
+            return;
+        }
+
+        #[cfg(feature = "causality")]
+        self.causality.begin(self.current_cause().action);
+        let guard = EndAsyncDispatch { store: self };
+
+        let start = Instant::now();
+        let action = guard.store.dispatch_chain_async(action).await;
+        if guard.store.metrics.is_some() {
+            guard.store.reduce_measured(start, action);
+        } else if let Some(action) = action {
+            if guard.store.dispatch_reducer(action) {
+                guard.store.dispatch_subscriptions();
+                guard.store.remove_detached_subscriptions();
+            }
+        }
+    }
+
+    /// Dispatches every action of a stream like [`dispatch_async`](#method.dispatch_async), until the stream ends.
+    ///
+    /// Actions are processed one at a time: the next one is only awaited once the previous one got dispatched.
+    /// Requires the `async` feature.
+    ///
+    /// See [`Stream`](trait.Stream.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// use std::task::{Context, Poll};
+    ///
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let mut increments = vec![1, 2, 3].into_iter();
+    /// let dispatch = store.dispatch_stream(move |_: &mut Context<'_>| Poll::Ready(increments.next()));
+    /// // Poll `dispatch` with the executor of your choice.
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn dispatch_stream<S: Stream<Item = Action>>(&mut self, stream: S) {
+        let mut stream = Box::pin(stream);
+        while let Some(action) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            self.dispatch_async(action).await;
+        }
+    }
+
+    /// Spawns a future, dispatching the action it resolves to once done.
+    ///
+    /// The store is shared with the task, which only keeps a weak reference to it:
+    /// if the store got dropped in the meantime, the action is dropped as well.
+    /// `spawn` receives the task and runs it with the executor of your choice.
+    /// Requires the `async` feature.
+    ///
+    /// # Panics
+    ///
+    /// The task panics if the store is borrowed when the future resolves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, TaskFuture};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// # fn reducer(state: &u8, value: u8) -> u8 {
+    /// #     value
+    /// # }
+    /// #
+    /// async fn fetch() -> u8 {
+    ///     42
+    /// }
+    ///
+    /// let store = Rc::new(RefCell::new(Store::new(reducer, 0)));
+    ///
+    /// Store::dispatch_from_future(&store, fetch(), |task: TaskFuture| {
+    ///     // Spawn `task` with the executor of your choice.
+    /// });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn dispatch_from_future<F, S>(store: &Rc<RefCell<Self>>, future: F, spawn: S)
+    where
+        State: 'static,
+        Action: 'static,
+        F: Future<Output = Action> + 'static,
+        S: FnOnce(TaskFuture),
+    {
+        let store = Rc::downgrade(store);
+        spawn(Box::pin(async move {
+            let action = future.await;
+            if let Some(store) = store.upgrade() {
+                store.borrow_mut().dispatch(action);
+            }
+        }));
+    }
+
+    /// Runs regular and async middleware as a single chain, in the order they were added.
+    #[cfg(feature = "async")]
+    async fn dispatch_chain_async(&self, mut action: Action) -> Option<Action> {
+        self.start_dispatch(&action);
+
+        let mut async_middleware = self.async_middleware.iter().peekable();
+        for (index, entry) in self.middleware.iter().enumerate() {
+            while let Some((_, middleware)) =
+                async_middleware.next_if(|(position, _)| *position <= index)
+            {
+                action = self
+                    .run_async_middleware(middleware.as_ref(), action)
+                    .await?;
+            }
+            action = self.run_middleware_entry(index, entry, action)?;
+        }
+        for (_, middleware) in async_middleware {
+            action = self
+                .run_async_middleware(middleware.as_ref(), action)
+                .await?;
+        }
+        Some(action)
+    }
+
+    /// Runs a single async middleware, reporting it to the global error handler if it halts the action with a reason.
+    #[cfg(feature = "async")]
+    async fn run_async_middleware(
+        &self,
+        middleware: &dyn AsyncMiddleware<State, Action>,
+        action: Action,
+    ) -> Option<Action> {
+        let action = middleware.next(self, action).await;
+        if let (None, Some(reason)) = (&action, self.halt_reason.take()) {
+            self.report_error(StoreError::AsyncMiddlewareHalted { reason });
+        }
+        action
+    }
+
+    /// Dispatches an action like [`dispatch_async`](#method.dispatch_async), giving up after `timeout`.
+    ///
+    /// On timeout, the action is dropped in whichever async middleware it was waiting on:
+    /// neither the reducer nor the subscriptions run, so the state is left unchanged.
+    /// Middleware that already passed the action on is not rolled back, so any side effects it performed remain.
+    ///
+    /// By default, the timer runs on a helper thread, so this works with any executor.
+    /// See [`set_timer`](#method.set_timer) to use another timer.
+    /// Requires the `async` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// # use std::time::Duration;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let dispatch = store.dispatch_async_timeout(1, Duration::from_secs(1));
+    /// // Resolves to `Err(DispatchTimeout)` if the dispatch takes longer than a second.
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn dispatch_async_timeout(
+        &mut self,
+        action: Action,
+        timeout: core::time::Duration,
+    ) -> Result<(), DispatchTimeout> {
+        let delay = match &self.timer {
+            Some(timer) => timer.sleep(timeout),
+            None => thread_sleep(timeout),
+        };
+        let result = Timeout::new(self.dispatch_async(action), delay).await;
+        if result.is_err() {
+            self.report_error(StoreError::Timeout);
+        }
+        result
+    }
+
+    /// Sets the timer used by async features waiting for some time, such as [`dispatch_async_timeout`](#method.dispatch_async_timeout).
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// See [`Timer`](trait.Timer.html).
+    #[cfg(feature = "async")]
+    pub fn set_timer<T: Timer + 'static>(&mut self, timer: T) {
+        self.timer = Some(Box::new(timer));
+    }
+
+    /// Dispatches an action while measuring the time spent in each phase.
+    #[cfg(feature = "std")]
+    fn dispatch_measured(&mut self, action: Action) {
+        let start = Instant::now();
+        let action = self.dispatch_middleware(action);
+        self.reduce_measured(start, action);
+    }
+
+    /// Finishes a measured dispatch started at `start`, once the middleware chain returned.
+    #[cfg(feature = "std")]
+    fn reduce_measured(&mut self, start: Instant, action: Option<Action>) {
+        let mut metrics = DispatchMetrics {
+            middleware_ns: start.elapsed().as_nanos(),
+            ..DispatchMetrics::default()
+        };
+
+        if let Some(action) = action {
+            let reducer_start = Instant::now();
+            let reduced = self.dispatch_reducer(action);
+            metrics.reducer_ns = reducer_start.elapsed().as_nanos();
+
+            if reduced {
+                let subscriptions_start = Instant::now();
+                self.dispatch_subscriptions();
+                metrics.subscriptions_ns = subscriptions_start.elapsed().as_nanos();
+                self.remove_detached_subscriptions();
+            }
+        }
+
+        metrics.total_ns = start.elapsed().as_nanos();
+        if let Some(collector) = &self.metrics {
+            collector.collect(metrics);
+        }
+    }
+
+    /// Runs the middleware chain, returning the action to pass to the reducer (if any).
+    fn dispatch_middleware(&self, action: Action) -> Option<Action> {
+        self.start_dispatch(&action);
+
+        let start = self
+            .dispatch_after
+            .take()
+            .and_then(|after| self.middleware.iter().position(|entry| entry.id == after))
+            .map_or(0, |index| index + 1);
+        self.run_middleware_from(start, action)
+    }
+
+    /// Runs the middleware chain from the given index on, without starting a dispatch.
+    fn run_middleware_from(&self, start: usize, action: Action) -> Option<Action> {
+        self.middleware
+            .iter()
+            .enumerate()
+            .skip(start)
+            .try_fold(action, |action, (index, entry)| {
+                self.run_middleware_entry(index, entry, action)
+            })
+    }
+
+    /// Runs whatever has to happen before the first middleware sees an action.
+    fn start_dispatch(&self, action: &Action) {
+        #[cfg(feature = "log")]
+        self.diagnose(LogLevel::Trace, format_args!("action dispatched"));
+
+        if let Some(hook) = self.first_dispatch.take() {
+            hook(self.state(), action);
+        }
+    }
+
+    /// Runs a single middleware of the chain, unless it does not handle the action.
+    fn run_middleware_entry(
+        &self,
+        index: usize,
+        entry: &MiddlewareEntry<State, Action>,
+        action: Action,
+    ) -> Option<Action> {
+        if !entry.handles(&action) {
+            return Some(action);
+        }
+
+        let halted = self
+            .halt_clone_action
+            .filter(|_| !self.halt_observers.is_empty())
+            .map(|clone_action| clone_action(&action));
+
+        #[cfg(feature = "test-util")]
+        self.record(TraceEvent::MiddlewareEntered(index));
+        let running = self.running_middleware.replace(Some(entry.id));
+        let action = entry.middleware.next(self, action);
+        self.running_middleware.set(running);
+        #[cfg(feature = "test-util")]
+        self.record(match action {
+            Some(_) => TraceEvent::MiddlewareForwarded(index),
+            None => TraceEvent::MiddlewareHalted(index),
+        });
+        #[cfg(feature = "log")]
+        if action.is_none() {
+            self.diagnose(
+                LogLevel::Debug,
+                format_args!("middleware {} halted the action", index),
+            );
+        }
+
+        #[cfg(feature = "std")]
+        if let (None, Some(reason)) = (&action, self.halt_reason.take()) {
+            self.report_error(StoreError::MiddlewareHalted { index, reason });
+        }
+
+        if let (None, Some(halted)) = (&action, halted) {
+            self.halt_observers
+                .iter()
+                .for_each(|observer| observer(index, &halted));
+        }
+        action
+    }
+
+    /// Runs the reducer wrapped in the around middleware, returning whether to notify subscriptions.
+    fn dispatch_reducer(&mut self, action: Action) -> bool {
+        if self.around_middleware.is_empty() {
+            return self.reduce(action);
+        }
+
+        let around = mem::take(&mut self.around_middleware);
+        let guard = RestoreAroundMiddleware {
+            store: self,
+            around,
+        };
+        let mut notify = false;
+        Next::new(guard.store, &guard.around, &mut notify).run(action);
+        notify
+    }
+
+    /// Returns how many times the reducer ran, e.g. to tell whether a dispatch got halted.
+    #[cfg(feature = "std")]
+    pub(crate) fn reductions(&self) -> usize {
+        self.reductions
+    }
+
+    /// Runs the reducer only, calling the unhandled action handler if the reducer did not handle the action.
+    ///
+    /// Returns whether to notify subscriptions.
+    pub(crate) fn reduce(&mut self, action: Action) -> bool {
+        let unhandled = self
+            .unhandled
+            .as_ref()
+            .map(|unhandled| (unhandled.clone_action)(&action));
+
+        let observed: Vec<(usize, Action)> = self
+            .action_observers
+            .iter()
+            .enumerate()
+            .filter(|(_, observer)| (observer.matcher)(&action))
+            .map(|(index, observer)| (index, (observer.clone_action)(&action)))
+            .collect();
+
+        let changed = self.reducer.reduce_in_place(self.state.get_mut(), action);
+        self.state.commit();
+        self.reductions = self.reductions.wrapping_add(1);
+        self.snapshots.push(self.state.get());
+        if let Some(deliver) = &self.notifications {
+            deliver();
+        }
+        #[cfg(feature = "test-util")]
+        self.record(TraceEvent::ReducerRan);
+        #[cfg(feature = "log")]
+        self.diagnose(LogLevel::Trace, format_args!("reducer ran"));
+
+        if let (false, Some(handler), Some(action)) = (changed, &self.unhandled, unhandled) {
+            (handler.handler)(self.state(), &action);
+        }
+        for (index, action) in observed {
+            (self.action_observers[index].callback)(&action);
+        }
+        changed || !self.change_detection
+    }
+
+    /// Runs all subscriptions.
+    #[cfg_attr(not(feature = "test-util"), allow(clippy::unused_enumerate_index))]
+    fn dispatch_subscriptions(&self) {
+        if self.transactions > 0 {
+            return;
+        }
+
+        let _notified = self
+            .subscriptions
+            .iter()
+            .enumerate()
+            .filter(|(_, (id, subscription))| {
+                !self.detached.borrow().contains(id) && subscription.should_update(self.state())
+            })
+            .inspect(|&(_index, (id, subscription))| {
+                #[cfg(feature = "std")]
+                self.update_subscription(_index, subscription.as_ref());
+                #[cfg(not(feature = "std"))]
+                subscription.update(self.state());
+                #[cfg(feature = "test-util")]
+                self.record(TraceEvent::SubscriptionFired(_index));
+                if let Some(fired) = self.fired.borrow_mut().as_mut() {
+                    fired.push(*id);
+                }
+            })
+            .count();
+        #[cfg(feature = "log")]
+        self.diagnose(
+            LogLevel::Trace,
+            format_args!("{} subscribers notified", _notified),
+        );
+    }
+
+    /// Runs a single subscription, applying the subscription panic policy.
+    #[cfg(feature = "std")]
+    fn update_subscription(&self, index: usize, subscription: &dyn Subscription<State>) {
+        match self.subscription_panics {
+            SubscriptionPanicPolicy::Propagate => subscription.update(self.state()),
+            SubscriptionPanicPolicy::Isolate => {
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| subscription.update(self.state())));
+                if let Err(payload) = result {
+                    let message = panic_message(payload.as_ref())
+                        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+                    self.report_error(StoreError::SubscriptionPanicked { index, message });
+                }
+            }
+        }
+    }
+
+    /// Records an event to the trace recorder, if any.
+    #[cfg(feature = "test-util")]
+    fn record(&self, event: TraceEvent) {
+        if let Some(trace) = &self.trace {
+            trace.record(event);
+        }
+    }
+
+    /// Sets a recorder receiving the trace of every dispatch.
+    ///
+    /// Requires the `test-util` feature.
+    ///
+    /// See [`TraceRecorder`](test/struct.TraceRecorder.html).
+    #[cfg(feature = "test-util")]
+    pub fn set_trace_recorder(&mut self, recorder: TraceRecorder) {
+        self.trace = Some(recorder);
+    }
+
+    /// Dispatches an action like [`dispatch`](#method.dispatch), reporting what happened.
+    ///
+    /// Middleware, the reducer and subscriptions run exactly as they would when dispatching.
+    /// A trace recorder set on the store, if any, still records the dispatch.
+    /// Requires the `test-util` feature.
+    ///
+    /// Warning: this requires `State` to be `Clone`.
+    ///
+    /// See [`StepReport`](test/struct.StepReport.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// let id = store.subscribe(|_: &u8| {});
+    ///
+    /// let report = store.step(2);
+    /// assert!(!report.halted);
+    /// assert_eq!(report.state, 2);
+    /// assert_eq!(report.subscriptions_fired, vec![id]);
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn step(&mut self, action: Action) -> StepReport<State>
+    where
+        State: Clone,
+    {
+        let recorder = TraceRecorder::new();
+        let previous = self.trace.replace(recorder.clone());
+        let subscriptions_fired = self.dispatch_with_report(action);
+        self.trace = previous;
+
+        let events = recorder.events();
+        if let Some(trace) = &self.trace {
+            events.iter().for_each(|event| trace.record(*event));
+        }
+
+        StepReport {
+            halted: !events.contains(&TraceEvent::ReducerRan),
+            state: self.state().clone(),
+            subscriptions_fired,
+        }
+    }
+
+    /// Subscribes a callback to any change of the state.
+    ///
+    /// Subscriptions will be called, whenever an action is dispatched.
+    ///
+    /// See [`Subscription`](type.Subscription.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use redux_rs::{Store, Subscription};
+    /// #
+    /// # type State = u8;
+    /// # let initial_state = 0;
+    /// #
+    /// # fn reducer(_: &State, action: bool) -> State {
+    /// #     0
+    /// # }
+    ///
+    /// let mut store = Store::new(reducer, initial_state);
+    ///
+    /// let listener = |state: &State| {
+    ///     println!("Something changed! New value: {}", state);
+    /// };
+    ///
+    /// store.subscribe(listener);
+    /// ```
+    ///
+    /// The returned id allows to unsubscribe later on, see [`unsubscribe`](#method.unsubscribe).
+    pub fn subscribe<S: Subscription<State> + 'static>(&mut self, callback: S) -> SubscriptionId {
+        self.remove_detached_subscriptions();
+        self.push_subscription(Box::new(callback))
+    }
+
+    /// Subscribes a callback like [`subscribe`](#method.subscribe), failing if the subscription capacity is exceeded.
+    ///
+    /// Only live subscriptions count: subscriptions which unsubscribed themselves make room for new ones.
+    /// See [`set_subscription_capacity`](#method.set_subscription_capacity).
+    pub fn try_subscribe<S: Subscription<State> + 'static>(
+        &mut self,
+        callback: S,
+    ) -> Result<SubscriptionId, CapacityError> {
+        self.remove_detached_subscriptions();
+        if let Some(capacity) = self.subscription_capacity {
+            if self.subscription_count() >= capacity {
+                return Err(CapacityError { capacity });
+            }
+        }
+
+        Ok(self.push_subscription(Box::new(callback)))
+    }
+
+    /// Subscribes many callbacks at once, returning their ids in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, Subscription};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let labels = vec!["first", "second"];
+    /// let ids = store.subscribe_all(labels.into_iter().map(|label| {
+    ///     Box::new(move |state: &u8| println!("{}: {}", label, state)) as Box<dyn Subscription<u8>>
+    /// }));
+    ///
+    /// store.dispatch(1); // Prints "first: 1" and "second: 1".
+    ///
+    /// for id in ids {
+    ///     store.unsubscribe(id);
+    /// }
+    /// ```
+    pub fn subscribe_all<I>(&mut self, subscriptions: I) -> Vec<SubscriptionId>
+    where
+        I: IntoIterator<Item = Box<dyn Subscription<State>>>,
+    {
+        self.remove_detached_subscriptions();
+        subscriptions
+            .into_iter()
+            .map(|subscription| self.push_subscription(subscription))
+            .collect()
+    }
+
+    /// Removes a subscription, returning whether it was registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// let id = store.subscribe(|state: &u8| println!("New value: {}", state));
+    ///
+    /// assert!(store.unsubscribe(id));
+    /// store.dispatch(1); // Prints nothing.
+    /// ```
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len = self.subscriptions.len();
+        self.subscriptions.retain(|(other, _)| *other != id);
+        self.cloneable.retain(|(other, _)| *other != id);
+        self.subscriptions.len() != len
+    }
+
+    /// Subscribes a callback running at most once per `min_interval`, however often the state changes.
+    ///
+    /// This is meant for subscribers doing expensive work, e.g. re-rendering.
+    /// States skipped within an interval are delivered by the next notification once it passed.
+    /// See [`ThrottledSubscription`](struct.ThrottledSubscription.html), which also allows to use another clock
+    /// and to flush the latest skipped state once notifications stopped.
+    #[cfg(feature = "std")]
+    pub fn subscribe_throttled<S: Subscription<State> + 'static>(
+        &mut self,
+        min_interval: Duration,
+        callback: S,
+    ) -> SubscriptionId
+    where
+        State: Clone + 'static,
+    {
+        self.subscribe(ThrottledSubscription::new(min_interval, callback))
+    }
+
+    /// Returns whether any subscription is registered, e.g. to skip preparing data nobody listens to.
+    ///
+    /// Subscriptions which unsubscribed themselves do not count, even before being removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// assert!(!store.has_subscriptions());
+    ///
+    /// store.subscribe(|state: &u8| println!("New value: {}", state));
+    /// assert!(store.has_subscriptions());
+    /// ```
+    pub fn has_subscriptions(&self) -> bool {
+        let detached = self.detached.borrow();
+        self.subscriptions
+            .iter()
+            .any(|(id, _)| !detached.contains(id))
+    }
+
+    /// Returns the number of registered subscriptions.
+    ///
+    /// Subscriptions which unsubscribed themselves do not count, even before being removed.
+    pub fn subscription_count(&self) -> usize {
+        let detached = self.detached.borrow();
+        self.subscriptions
+            .iter()
+            .filter(|(id, _)| !detached.contains(id))
+            .count()
+    }
+
+    /// Subscribes a callback to a part of the state, only notified when that part changed.
+    ///
+    /// `selector` picks the part of the state to watch, and `callback` receives it whenever it differs from the one seen last,
+    /// starting with the one selected from the current state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &(u8, u8), (first, second): (u8, u8)) -> (u8, u8) {
+    /// #     (state.0 + first, state.1 + second)
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, (0, 0));
+    ///
+    /// store.subscribe_selector(|state: &(u8, u8)| state.0, |first: &u8| println!("First changed: {}", first));
+    ///
+    /// store.dispatch((1, 0)); // Prints "First changed: 1".
+    /// store.dispatch((0, 1)); // Prints nothing.
+    /// ```
+    pub fn subscribe_selector<T, S, F>(&mut self, selector: S, callback: F) -> SubscriptionId
+    where
+        T: PartialEq + 'static,
+        S: Fn(&State) -> T + 'static,
+        F: Fn(&T) + 'static,
+    {
+        let last = RefCell::new(selector(self.state()));
+        self.subscribe(SelectorSubscription {
+            selector,
+            callback,
+            last,
+        })
+    }
+
+    /// Subscribes a callback to a projection of the state, e.g. a single field.
+    ///
+    /// `map` derives a value from the new state, which `callback` receives on every notification.
+    /// Unlike [`subscribe_selector`](#method.subscribe_selector), the callback fires even if the value did not change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &(u8, u8), (first, second): (u8, u8)) -> (u8, u8) {
+    /// #     (state.0 + first, state.1 + second)
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, (0, 0));
+    ///
+    /// store.subscribe_map(|state: &(u8, u8)| state.0, |first: u8| println!("First: {}", first));
+    ///
+    /// store.dispatch((1, 0)); // Prints "First: 1".
+    /// store.dispatch((0, 1)); // Prints "First: 1".
+    /// ```
+    pub fn subscribe_map<T, M, F>(&mut self, map: M, callback: F) -> SubscriptionId
+    where
+        M: Fn(&State) -> T + 'static,
+        F: Fn(T) + 'static,
+    {
+        self.subscribe(move |state: &State| callback(map(state)))
+    }
+
+    /// Subscribes a callback which can unsubscribe itself, e.g. to stop watching once some data got loaded.
+    ///
+    /// The callback receives a [`SubscriptionControl`](struct.SubscriptionControl.html) along with the state.
+    /// Unsubscribing through it takes effect immediately, but the subscription is only removed once the current notification completes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, SubscriptionControl};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.subscribe_with_control(|state: &u8, control: &SubscriptionControl| {
+    ///     println!("Loading: {}%", state);
+    ///     if *state >= 100 {
+    ///         control.unsubscribe();
+    ///     }
+    /// });
+    ///
+    /// store.dispatch(100); // Prints "Loading: 100%".
+    /// store.dispatch(1); // Prints nothing.
+    /// ```
+    pub fn subscribe_with_control<F>(&mut self, callback: F) -> SubscriptionId
+    where
+        F: Fn(&State, &SubscriptionControl) + 'static,
+    {
+        let control = SubscriptionControl::new(
+            SubscriptionId(self.next_subscription_id),
+            self.detached.clone(),
+        );
+        self.subscribe(move |state: &State| callback(state, &control))
+    }
+
+    /// Removes the subscriptions which unsubscribed themselves.
+    fn remove_detached_subscriptions(&mut self) {
+        let detached = mem::take(&mut *self.detached.borrow_mut());
+        if !detached.is_empty() {
+            self.subscriptions.retain(|(id, _)| !detached.contains(id));
+            self.cloneable.retain(|(id, _)| !detached.contains(id));
+        }
+    }
+
+    /// Subscribes a clonable callback like [`subscribe`](#method.subscribe), which gets carried over by [`fork_with_subscriptions`](#method.fork_with_subscriptions).
+    ///
+    /// See [`CloneableSubscription`](trait.CloneableSubscription.html).
+    pub fn subscribe_cloneable<S>(&mut self, callback: S) -> SubscriptionId
+    where
+        S: Subscription<State> + Clone + 'static,
+    {
+        let id = self.subscribe(callback.clone());
+        self.cloneable.push((id, Box::new(callback)));
+        id
+    }
+
+    /// Registers a subscription, assigning it a new id.
+    fn push_subscription(&mut self, subscription: Box<dyn Subscription<State>>) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription_id);
+        self.next_subscription_id += 1;
+        self.subscriptions.push((id, subscription));
+        id
+    }
+
+    /// Replaces all subscriptions at once.
+    ///
+    /// The previous subscriptions are dropped, and only the given ones are notified from now on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, Subscription};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.subscribe(|state: &u8| println!("Old binding: {}", state));
+    ///
+    /// let bindings: Vec<Box<dyn Subscription<u8>>> = vec![
+    ///     Box::new(|state: &u8| println!("New binding: {}", state))
+    /// ];
+    /// store.set_subscriptions(bindings);
+    ///
+    /// store.dispatch(1); // Prints "New binding: 1".
+    /// ```
+    pub fn set_subscriptions(&mut self, subscriptions: Vec<Box<dyn Subscription<State>>>) {
+        self.subscriptions.clear();
+        self.cloneable.clear();
+        for subscription in subscriptions {
+            self.push_subscription(subscription);
+        }
+    }
+
+    /// Limits the number of subscriptions, reserving space for all of them up front.
+    ///
+    /// Once `capacity` subscriptions are registered, [`try_subscribe`](#method.try_subscribe) returns an error instead of growing the storage.
+    /// Only [`try_subscribe`](#method.try_subscribe) enforces the capacity: every other way to subscribe, e.g. [`subscribe`](#method.subscribe),
+    /// [`subscribe_all`](#method.subscribe_all), [`set_subscriptions`](#method.set_subscriptions) or [`observe`](#method.observe), ignores it.
+    /// Subscriptions added that way still count towards it, so use [`try_subscribe`](#method.try_subscribe) wherever allocating is not an option.
+    pub fn set_subscription_capacity(&mut self, capacity: usize) {
+        self.subscription_capacity = Some(capacity);
+        self.subscriptions
+            .reserve_exact(capacity.saturating_sub(self.subscriptions.len()));
+    }
+
+    /// Sets what happens when a subscription panics.
+    ///
+    /// By default, the panic propagates, skipping the remaining subscriptions.
+    /// Isolated panics are only reported to the [global error handler](#method.set_global_error_handler), so set one to find out about them.
+    /// Requires the `std` feature.
+    ///
+    /// See [`SubscriptionPanicPolicy`](enum.SubscriptionPanicPolicy.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, StoreError, SubscriptionPanicPolicy};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.set_subscription_panic_policy(SubscriptionPanicPolicy::Isolate);
+    /// store.set_global_error_handler(|error: &StoreError| eprintln!("store error: {}", error));
+    ///
+    /// store.subscribe(|_: &u8| panic!("bad listener"));
+    /// store.subscribe(|state: &u8| println!("New value: {}", state));
+    ///
+    /// store.dispatch(1); // Prints "store error: subscription 0 panicked: bad listener", then "New value: 1".
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_subscription_panic_policy(&mut self, policy: SubscriptionPanicPolicy) {
+        self.subscription_panics = policy;
+    }
+
+    /// Sets a handler receiving every error arising in the store, e.g. to report them in production.
+    ///
+    /// Setting a handler replaces the previous one, if any.
+    /// While a handler is set, subscription panics isolated by [`SubscriptionPanicPolicy::Isolate`](enum.SubscriptionPanicPolicy.html#variant.Isolate)
+    /// are reported to it as well.
+    ///
+    /// See [`StoreError`](enum.StoreError.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{Store, StoreError};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware(|store: &Store<u8, u8>, increment: u8| {
+    ///     if increment > 10 {
+    ///         store.halt_with_reason("increment too large")
+    ///     } else {
+    ///         Some(increment)
+    ///     }
+    /// });
+    ///
+    /// store.set_global_error_handler(|error: &StoreError| eprintln!("store error: {}", error));
+    ///
+    /// store.dispatch(42); // Prints "store error: middleware 0 halted an action: increment too large".
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_global_error_handler<F: Fn(&StoreError) + 'static>(&mut self, handler: F) {
+        self.error_handler = Some(Box::new(handler));
+    }
+
+    /// Sets a crash reporter, watching every dispatch for panics.
+    ///
+    /// Setting a reporter replaces the previous one, if any.
+    /// Async dispatches are not watched, as they can't be unwound across await points.
+    /// Requires the `std` feature.
+    ///
+    /// See [`CrashReporterMiddleware`](struct.CrashReporterMiddleware.html).
+    #[cfg(feature = "std")]
+    pub fn set_crash_reporter<Reporter>(&mut self, middleware: CrashReporterMiddleware<Reporter>)
+    where
+        Action: fmt::Debug,
+        Reporter: Fn(&CrashReport<'_, State>) + 'static,
+    {
+        self.crash_reporter = Some(CrashReporting::new(middleware));
+    }
+
+    /// Halts an action as an error, returning `None` for a middleware to return.
+    ///
+    /// Middleware halting actions as part of its normal operation, e.g. filtering or buffering them, simply returns `None`.
+    /// Halting through this method instead reports the halt along with `reason` to the global error handler,
+    /// see [`set_global_error_handler`](#method.set_global_error_handler).
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn halt_with_reason<R: Into<String>>(&self, reason: R) -> Option<Action> {
+        self.halt_reason.replace(Some(reason.into()));
+        None
+    }
+
+    /// Reports an error to the global error handler, if any.
+    #[cfg(feature = "std")]
+    fn report_error(&self, error: StoreError) {
+        if let Some(handler) = &self.error_handler {
+            handler(&error);
+        }
+    }
+
+    /// Subscribes a callback receiving its own copy of the state on any change.
+    ///
+    /// The state is cloned once per owned subscriber and dispatch, while subscribers added using [`subscribe`](#method.subscribe) still only borrow it.
+    /// This is useful for subscribers which need ownership, e.g. to send the state to another thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// use std::sync::mpsc;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.subscribe_owned(move |state: u8| sender.send(state).unwrap());
+    ///
+    /// store.dispatch(1);
+    /// assert_eq!(receiver.recv(), Ok(1));
+    /// ```
+    pub fn subscribe_owned<F: Fn(State) + 'static>(&mut self, callback: F) -> SubscriptionId
+    where
+        State: Clone,
+    {
+        self.subscribe(move |state: &State| callback(state.clone()))
+    }
+
+    /// Creates an observable of the state, emitting whenever an action is dispatched.
+    ///
+    /// The observable is fed by a subscription, which is removed once the observable and all observables derived from it got dropped.
+    /// See [`Observable`](struct.Observable.html) and [`IntoObservable`](trait.IntoObservable.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let above_one = store.observe().filter(|state: &u8| *state > 1);
+    /// above_one.subscribe(|state: &u8| println!("Above one: {}", state));
+    /// ```
+    pub fn observe(&mut self) -> Observable<State>
+    where
+        State: 'static,
+    {
+        let observable = Observable::new(SubscriptionControl::new(
+            SubscriptionId(self.next_subscription_id),
+            self.detached.clone(),
+        ));
+        let emit = observable.emitter();
+        self.subscribe(move |state: &State| emit(state));
+        observable
+    }
+
+    /// Returns a future resolving once a dispatch leaves the state satisfying `predicate`.
+    ///
+    /// The future resolves immediately if the current state already satisfies it.
+    /// Requires the `async` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let ready = store.wait_for(|state: &u8| *state >= 2);
+    ///
+    /// store.dispatch(());
+    /// store.dispatch(());
+    ///
+    /// // `ready` resolves now.
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn wait_for<P: Fn(&State) -> bool + 'static>(&mut self, predicate: P) -> WaitFor
+    where
+        State: 'static,
+    {
+        if predicate(self.state()) {
+            return WaitFor::new(true);
+        }
+
+        let wait = WaitFor::new(false);
+        let resolve = wait.resolver();
+        self.subscribe_with_control(move |state: &State, control: &SubscriptionControl| {
+            if predicate(state) {
+                resolve();
+                control.unsubscribe();
+            }
+        });
+        wait
+    }
+
+    /// Observes actions reaching the reducer, independently of the state.
+    ///
+    /// `callback` is called with every action for which `matcher` returns `true`, right after the reducer ran (and before subscriptions).
+    /// Actions halted by middleware never reach the reducer, so they are not observed.
+    ///
+    /// Warning: this requires `Action` to be `Clone`, as the reducer consumes the action.
+    /// Only matching actions are cloned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// #[derive(Clone, Debug)]
+    /// enum Action {
+    ///     Navigate(&'static str),
+    ///     Increment
+    /// }
+    ///
+    /// # fn reducer(state: &u8, action: Action) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.on_action(
+    ///     |action: &Action| matches!(action, Action::Navigate(_)),
+    ///     |action: &Action| println!("Navigation happened: {:?}", action)
+    /// );
+    ///
+    /// store.dispatch(Action::Navigate("/home"));
+    /// ```
+    pub fn on_action<M, F>(&mut self, matcher: M, callback: F)
+    where
+        Action: Clone,
+        M: Fn(&Action) -> bool + 'static,
+        F: Fn(&Action) + 'static,
+    {
+        self.action_observers.push(ActionObserver {
+            matcher: Box::new(matcher),
+            callback: Box::new(callback),
+            clone_action: Action::clone,
+        });
+    }
+
+    /// Observes actions halted by middleware.
+    ///
+    /// `callback` is called with the index of the halting middleware (its position in the chain) and the action it halted.
+    ///
+    /// Warning: this requires `Action` to be `Clone`, as middleware consumes the action.
+    /// While a callback is set, actions are cloned before calling each middleware.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.add_middleware(|_: &Store<u8, u8>, increment: u8| if increment > 10 { None } else { Some(increment) });
+    ///
+    /// store.on_halt(|index: usize, increment: &u8| {
+    ///     println!("Middleware {} blocked an increment by {}", index, increment);
+    /// });
+    ///
+    /// store.dispatch(42); // Prints "Middleware 0 blocked an increment by 42".
+    /// ```
+    pub fn on_halt<F: Fn(usize, &Action) + 'static>(&mut self, callback: F)
+    where
+        Action: Clone,
+    {
+        self.halt_observers.push(Box::new(callback));
+        self.halt_clone_action = Some(Action::clone);
+    }
+
+    /// Sets a hook running once, on the next dispatched action, before any middleware.
+    ///
+    /// Afterwards, dispatching is not affected anymore.
+    /// Running the middleware alone, using [`run_middleware`](#method.run_middleware), does not count as a dispatch.
+    /// This is useful for lazy initialization, e.g. starting to load data on the first user interaction.
+    /// Setting a hook replaces the one not run yet, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.on_first_dispatch(|state: &u8, increment: &u8| {
+    ///     println!("First dispatch: {} + {}", state, increment);
+    /// });
+    ///
+    /// store.dispatch(1); // Prints "First dispatch: 0 + 1".
+    /// store.dispatch(2); // Prints nothing.
+    /// ```
+    pub fn on_first_dispatch<F: FnOnce(&State, &Action) + 'static>(&mut self, hook: F) {
+        self.first_dispatch.set(Some(Box::new(hook)));
+    }
+
+    /// Notifies all subscriptions with the current state, without dispatching anything.
+    ///
+    /// This is useful to force subscribers to re-read the state, e.g. after bulk operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 42);
+    ///
+    /// store.subscribe(|state: &u8| {
+    ///     println!("Current value: {}", state);
+    /// });
+    ///
+    /// store.notify();
+    /// ```
+    pub fn notify(&self) {
+        self.dispatch_subscriptions();
+    }
+
+    /// Adds a custom middleware to the store.
+    ///
+    /// Middleware provides the possibility to intercept actions dispatched before they reach the reducer.
+    ///
+    /// Returns an id which can be used to refer to the middleware later on.
+    ///
+    /// See [`Middleware`](type.Middleware.html).
+    pub fn add_middleware<M: Middleware<State, Action> + 'static>(
+        &mut self,
+        middleware: M,
+    ) -> MiddlewareId {
+        self.push_middleware(MiddlewareEntry::new(middleware))
+    }
+
+    /// Adds an async middleware to the store.
+    ///
+    /// Async middleware only runs when dispatching with [`dispatch_async`](#method.dispatch_async),
+    /// which runs regular and async middleware as a single chain, in the order they were added.
+    /// Async middleware belongs to the normal phase, see [`add_middleware_in_phase`](#method.add_middleware_in_phase).
+    /// Dispatching with [`dispatch`](#method.dispatch) skips async middleware.
+    /// Requires the `async` feature.
+    ///
+    /// See [`AsyncMiddleware`](trait.AsyncMiddleware.html).
+    #[cfg(feature = "async")]
+    pub fn add_async_middleware<M: AsyncMiddleware<State, Action> + 'static>(
+        &mut self,
+        middleware: M,
+    ) {
+        self.async_middleware
+            .push((self.middleware.len(), Box::new(middleware)));
+    }
+
+    /// Adds an around middleware to the store, wrapping the reducer.
+    ///
+    /// Around middleware runs after all regular middleware, the first one added being the outermost.
+    /// Subscriptions are notified once the outermost around middleware returned, if the reducer ran.
+    ///
+    /// See [`AroundMiddleware`](trait.AroundMiddleware.html).
+    pub fn add_around_middleware<M: AroundMiddleware<State, Action> + 'static>(
+        &mut self,
+        middleware: M,
+    ) {
+        self.around_middleware.push(Box::new(middleware));
+    }
+
+    /// Adds a custom middleware to the store, labelled with a tag.
+    ///
+    /// Tags can be used to declare ordering constraints between middleware,
+    /// see [`require_middleware_order`](#method.require_middleware_order).
+    pub fn add_tagged_middleware<M: Middleware<State, Action> + 'static>(
+        &mut self,
+        tag: MiddlewareTag,
+        middleware: M,
+    ) -> MiddlewareId {
+        let mut entry = MiddlewareEntry::new(middleware);
+        entry.tag = Some(tag);
+        self.push_middleware(entry)
+    }
+
+    /// Adds all middleware contributed by a feature, returning their ids in order.
+    ///
+    /// The middleware are appended to the chain in the order the feature lists them.
+    /// See [`Feature`](trait.Feature.html).
+    pub fn add_feature_middleware<F: Feature<State, Action>>(
+        &mut self,
+        feature: F,
+    ) -> Vec<MiddlewareId> {
+        feature
+            .middleware()
+            .into_iter()
+            .map(|middleware| self.push_middleware(MiddlewareEntry::boxed(middleware)))
+            .collect()
+    }
+
+    /// Adds a custom middleware to the store, running within the given phase.
+    ///
+    /// The chain runs phase by phase, keeping insertion order within each phase.
+    /// Middleware added otherwise belongs to [`MiddlewarePhase::Normal`](enum.MiddlewarePhase.html#variant.Normal).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{MiddlewarePhase, Store};
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.add_middleware_in_phase(MiddlewarePhase::Post, |_: &Store<u8, u8>, increment: u8| {
+    ///     println!("Reducing an increment by {}", increment);
+    ///     Some(increment)
+    /// });
+    /// store.add_middleware(|_: &Store<u8, u8>, increment: u8| Some(increment.min(10)));
+    ///
+    /// store.dispatch(42); // Prints "Reducing an increment by 10".
+    /// ```
+    pub fn add_middleware_in_phase<M: Middleware<State, Action> + 'static>(
+        &mut self,
+        phase: MiddlewarePhase,
+        middleware: M,
+    ) -> MiddlewareId {
+        let mut entry = MiddlewareEntry::new(middleware);
+        entry.phase = phase;
+        self.push_middleware(entry)
+    }
+
+    /// Adds a custom middleware to the store, only invoked for actions of the given enum variant.
+    ///
+    /// The store skips the middleware for all other actions without calling it, which is cheaper than matching inside of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// use std::mem;
+    ///
+    /// enum Action {
+    ///     Login(String),
+    ///     Logout
+    /// }
+    ///
+    /// # fn reducer(state: &bool, action: Action) -> bool {
+    /// #     match action {
+    /// #         Action::Login(_) => true,
+    /// #         Action::Logout => false
+    /// #     }
+    /// # }
+    /// #
+    /// fn login_middleware(_: &Store<bool, Action>, action: Action) -> Option<Action> {
+    ///     if let Action::Login(user) = &action {
+    ///         println!("{} logged in", user);
+    ///     }
+    ///     Some(action)
+    /// }
+    ///
+    /// let mut store = Store::new(reducer, false);
+    /// store.add_routed_middleware(mem::discriminant(&Action::Login(String::new())), login_middleware);
+    /// ```
+    pub fn add_routed_middleware<M: Middleware<State, Action> + 'static>(
+        &mut self,
+        discriminant: Discriminant<Action>,
+        middleware: M,
+    ) -> MiddlewareId {
+        let mut entry = MiddlewareEntry::new(middleware);
+        entry.route = Some(discriminant);
+        self.push_middleware(entry)
+    }
+
+    /// Assigns a fresh id to the middleware and appends it to its phase of the chain, unless the middleware capacity is exceeded.
+    fn try_push_middleware(
+        &mut self,
+        entry: MiddlewareEntry<State, Action>,
+    ) -> Result<MiddlewareId, CapacityError> {
+        if let Some(capacity) = self.middleware_capacity {
+            if self.middleware.len() >= capacity {
+                return Err(CapacityError { capacity });
+            }
+        }
+
+        Ok(self.push_middleware(entry))
+    }
+
+    /// Assigns a fresh id to the middleware and appends it to its phase of the chain.
+    fn push_middleware(&mut self, mut entry: MiddlewareEntry<State, Action>) -> MiddlewareId {
+        entry.id = MiddlewareId(self.next_middleware_id);
+        self.next_middleware_id += 1;
+
+        let id = entry.id;
+        let phase = entry.phase;
+        let index = self
+            .middleware
+            .iter()
+            .position(|other| other.phase > phase)
+            .unwrap_or(self.middleware.len());
+        self.middleware.insert(index, entry);
+
+        // Async middleware belongs to the normal phase.
+        #[cfg(feature = "async")]
+        for (position, _) in &mut self.async_middleware {
+            if *position > index || (*position == index && phase < MiddlewarePhase::Normal) {
+                *position += 1;
+            }
+        }
+        id
+    }
+
+    /// Adds a custom middleware like [`add_middleware`](#method.add_middleware), failing if the middleware capacity is exceeded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// store.set_middleware_capacity(1);
+    ///
+    /// assert!(store.try_add_middleware(|_: &Store<u8, u8>, action: u8| Some(action)).is_ok());
+    /// assert!(store.try_add_middleware(|_: &Store<u8, u8>, action: u8| Some(action)).is_err());
+    /// ```
+    pub fn try_add_middleware<M: Middleware<State, Action> + 'static>(
+        &mut self,
+        middleware: M,
+    ) -> Result<MiddlewareId, CapacityError> {
+        self.try_push_middleware(MiddlewareEntry::new(middleware))
+    }
+
+    /// Limits the number of middleware, reserving space for all of them up front.
+    ///
+    /// Once `capacity` middleware are registered, [`try_add_middleware`](#method.try_add_middleware) returns an error instead of growing the storage.
+    /// Infallible methods ignore the capacity, so use the `try_` variants wherever allocating is not an option.
+    pub fn set_middleware_capacity(&mut self, capacity: usize) {
+        self.middleware_capacity = Some(capacity);
+        self.middleware
+            .reserve_exact(capacity.saturating_sub(self.middleware.len()));
+    }
+
+    /// Enables or disables a middleware without removing it from the chain.
+    ///
+    /// Disabled middleware is skipped when dispatching, but keeps its position and id.
+    /// Returns `false` if no middleware with the given id is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// let id = store.add_middleware(|_: &Store<u8, u8>, _: u8| None);
+    ///
+    /// store.set_middleware_enabled(id, false);
+    /// store.dispatch(1);
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn set_middleware_enabled(&mut self, id: MiddlewareId, enabled: bool) -> bool {
+        match self.middleware.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => {
+                entry.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the ids of all middleware, in the order they run.
+    ///
+    /// Disabled middleware is listed as well, see [`set_middleware_enabled`](#method.set_middleware_enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, increment: u8) -> u8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    /// let first = store.add_middleware(|_: &Store<u8, u8>, action: u8| Some(action));
+    /// let second = store.add_middleware(|_: &Store<u8, u8>, action: u8| Some(action));
+    ///
+    /// assert_eq!(store.middleware_ids(), vec![first, second]);
+    /// ```
+    pub fn middleware_ids(&self) -> Vec<MiddlewareId> {
+        self.middleware.iter().map(|entry| entry.id).collect()
+    }
+
+    /// Returns the tags of all middleware, in the order they run, with `None` for untagged middleware.
+    ///
+    /// See [`add_tagged_middleware`](#method.add_tagged_middleware).
+    pub fn middleware_tags(&self) -> Vec<Option<MiddlewareTag>> {
+        self.middleware.iter().map(|entry| entry.tag).collect()
+    }
+
+    /// Declares that middleware tagged `before` must run before middleware tagged `after`.
+    ///
+    /// Constraints are only checked by [`validate_middleware_order`](#method.validate_middleware_order).
+    pub fn require_middleware_order(&mut self, before: MiddlewareTag, after: MiddlewareTag) {
+        self.middleware_order.push((before, after));
+    }
+
+    /// Checks that the middleware satisfy all declared ordering constraints.
+    ///
+    /// Constraints involving a tag no middleware is labelled with are satisfied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::{MiddlewareTag, Store};
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     *state
+    /// # }
+    /// #
+    /// # fn auth_middleware(_: &Store<u8, ()>, action: ()) -> Option<()> {
+    /// #     Some(action)
+    /// # }
+    /// #
+    /// # fn logging_middleware(_: &Store<u8, ()>, action: ()) -> Option<()> {
+    /// #     Some(action)
+    /// # }
+    /// #
+    /// const AUTH: MiddlewareTag = MiddlewareTag("auth");
+    /// const LOGGING: MiddlewareTag = MiddlewareTag("logging");
+    ///
+    /// let mut store = Store::new(reducer, 0);
+    /// store.require_middleware_order(AUTH, LOGGING);
+    ///
+    /// store.add_tagged_middleware(LOGGING, logging_middleware);
+    /// store.add_tagged_middleware(AUTH, auth_middleware);
+    ///
+    /// assert!(store.validate_middleware_order().is_err());
+    /// ```
+    pub fn validate_middleware_order(&self) -> Result<(), MiddlewareOrderError> {
+        let position = |tag: MiddlewareTag| {
+            self.middleware
+                .iter()
+                .position(|entry| entry.tag == Some(tag))
+        };
+        let last_position = |tag: MiddlewareTag| {
+            self.middleware
+                .iter()
+                .rposition(|entry| entry.tag == Some(tag))
+        };
+
+        for &(before, after) in &self.middleware_order {
+            if let (Some(last_before), Some(first_after)) = (last_position(before), position(after))
+            {
+                if last_before > first_after {
+                    return Err(MiddlewareOrderError { before, after });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets a logger receiving diagnostics about the store's own control flow.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// See [`DiagnosticsLogger`](trait.DiagnosticsLogger.html).
+    #[cfg(feature = "log")]
+    pub fn set_diagnostics_logger<L: DiagnosticsLogger + 'static>(&mut self, logger: L) {
+        self.logger = Some(Box::new(logger));
+    }
+
+    /// Sends a message to the diagnostics logger, if any.
+    #[cfg(feature = "log")]
+    fn diagnose(&self, level: LogLevel, message: fmt::Arguments<'_>) {
+        if let Some(logger) = &self.logger {
+            logger.log(level, &message.to_string());
+        }
+    }
+
+    /// Sets a collector receiving timing metrics after every dispatch.
+    ///
+    /// Metrics are off by default, so dispatching does not pay for measuring time unless a collector is set.
+    ///
+    /// See [`MetricsCollector`](trait.MetricsCollector.html).
+    #[cfg(feature = "std")]
+    pub fn set_metrics_collector<C: MetricsCollector + 'static>(&mut self, collector: C) {
+        self.metrics = Some(Box::new(collector));
+    }
+
+    /// Replaces the currently used reducer.
+    ///
+    /// The new reducer is adapted like the previous one, so stores detecting changes keep doing so.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a plain reducer can't stand in for the current one,
+    /// i.e. for stores created using [`new_cow`](#method.new_cow), [`new_with_notifications`](#method.new_with_notifications) or [`combine_stores`](fn.combine_stores.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # pub struct State(u8);
+    /// #
+    /// # impl State {
+    /// #     pub fn something_else() -> State {
+    /// #         State(1)
+    /// #     }
+    /// # }
+    /// #
+    /// # enum Action {
+    /// #     SomeAction
+    /// # }
+    /// #
+    /// # fn reducer(state: &State, action: Action) -> State {
+    /// #     State(0)
+    /// # }
+    /// #
+    /// # let mut store = Store::new(reducer, State(0));
+    /// #
+    /// store.dispatch(Action::SomeAction);
+    ///
+    /// store.replace_reducer(|state: &State, action: Action| {
     ///     State::something_else()
     /// });
     ///
     /// store.dispatch(Action::SomeAction);
     /// ```
-    pub fn replace_reducer(&mut self, reducer: Reducer<State, Action>) {
-        self.reducer = reducer;
+    pub fn replace_reducer<R: Reducible<State, Action> + 'static>(&mut self, reducer: R)
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        self.reducer = self.adapt_reducer(reducer);
+    }
+
+    /// Adapts a reducer like the current one, so that swapping it keeps change detection.
+    fn adapt_reducer<R: Reducible<State, Action> + 'static>(
+        &self,
+        reducer: R,
+    ) -> Rc<dyn StoreReducer<State, Action>>
+    where
+        State: 'static,
+        Action: 'static,
+    {
+        self.reducer
+            .adapt(Box::new(reducer))
+            .expect("the reducer of this store can't be replaced by a plain one")
+    }
+
+    /// Temporarily replaces the reducer while running `f`, restoring the previous one afterwards.
+    ///
+    /// The previous reducer is restored even if `f` panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a plain reducer can't stand in for the current one, see [`replace_reducer`](#method.replace_reducer).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &u8, _: ()) -> u8 {
+    /// #     state + 1
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// store.with_reducer(|_: &u8, _: ()| 42, |store| {
+    ///     store.dispatch(());
+    ///     assert_eq!(*store.state(), 42);
+    /// });
+    ///
+    /// store.dispatch(());
+    /// assert_eq!(*store.state(), 43);
+    /// ```
+    pub fn with_reducer<R, T, F>(&mut self, reducer: R, f: F) -> T
+    where
+        State: 'static,
+        Action: 'static,
+        R: Reducible<State, Action> + 'static,
+        F: FnOnce(&mut Self) -> T,
+    {
+        let reducer = self.adapt_reducer(reducer);
+        let previous = mem::replace(&mut self.reducer, reducer);
+        let guard = RestoreReducer {
+            store: self,
+            reducer: previous,
+        };
+        f(guard.store)
+    }
+
+    /// Sets a handler called whenever the reducer did not handle an action.
+    ///
+    /// An action counts as unhandled when it left the state unchanged, which only some stores can tell:
+    /// copy-on-write reducers (see [`new_cow`](#method.new_cow)) returning the borrowed state,
+    /// or stores detecting changes (see [`new_with_change_detection`](#method.new_with_change_detection)) finding the new state equal.
+    /// Otherwise, reducers always produce a new state, so they handle every action.
+    ///
+    /// This is useful to log actions nobody cares about, or to panic on them in tests.
+    ///
+    /// Warning: this requires `Action` to be `Clone`, as the reducer consumes the action.
+    /// Actions are only cloned while a handler is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// enum Action {
+    ///     Increment,
+    ///     Unknown
+    /// }
+    ///
+    /// fn reducer(state: &u8, action: Action) -> Cow<u8> {
+    ///     match action {
+    ///         Action::Increment => Cow::Owned(state + 1),
+    ///         Action::Unknown => Cow::Borrowed(state)
+    ///     }
+    /// }
+    ///
+    /// let mut store = Store::new_cow(reducer, 0);
+    /// store.set_unhandled_action_handler(|_: &u8, action: &Action| {
+    ///     println!("Unhandled action: {:?}", action);
+    /// });
+    ///
+    /// store.dispatch(Action::Unknown); // Prints "Unhandled action: Unknown".
+    /// ```
+    pub fn set_unhandled_action_handler<H: Fn(&State, &Action) + 'static>(&mut self, handler: H)
+    where
+        Action: Clone,
+    {
+        self.unhandled = Some(UnhandledHandler {
+            handler: Box::new(handler),
+            clone_action: Action::clone,
+        });
+    }
+
+    /// Runs `f` as a transaction, which either commits all actions dispatched in it or none.
+    ///
+    /// The state is snapshotted before running `f`.
+    /// If `f` returns `false`, the snapshot is restored, as if nothing was dispatched.
+    /// Subscriptions don't fire while the transaction runs: they fire once after a commit, and not at all after a rollback.
+    /// Nested transactions only notify subscriptions when the outermost one commits.
+    ///
+    /// Returns whether the transaction was committed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use redux_rs::Store;
+    /// #
+    /// # fn reducer(state: &i8, increment: i8) -> i8 {
+    /// #     state + increment
+    /// # }
+    /// #
+    /// let mut store = Store::new(reducer, 0);
+    ///
+    /// let committed = store.transaction(|store| {
+    ///     store.dispatch(5);
+    ///     store.dispatch(-10);
+    ///     *store.state() >= 0
+    /// });
+    ///
+    /// assert!(!committed);
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn transaction<F: FnOnce(&mut Self) -> bool>(&mut self, f: F) -> bool
+    where
+        State: Clone,
+    {
+        let snapshot = self.state().clone();
+
+        self.transactions += 1;
+        let commit = {
+            let guard = EndTransaction { store: self };
+            f(guard.store)
+        };
+
+        if commit {
+            self.dispatch_subscriptions();
+        } else {
+            self.state.set(snapshot);
+        }
+        commit
+    }
+}
+
+/// Error returned when adding more middleware or subscriptions than a store has capacity for.
+///
+/// See [`Store::set_middleware_capacity`](struct.Store.html#method.set_middleware_capacity).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    /// The capacity which would have been exceeded.
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "store capacity of {} exceeded", self.capacity)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// Error returned when a store cannot be created from a configuration.
+///
+/// See [`Store::from_config`](struct.Store.html#method.from_config).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum ConfigError<E> {
+    /// Reading the configuration failed.
+    Io(io::Error),
+    /// Parsing the configuration failed.
+    Parse(E),
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Display> fmt::Display for ConfigError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "cannot read configuration: {}", error),
+            ConfigError::Parse(error) => write!(f, "cannot parse configuration: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ConfigError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            ConfigError::Parse(error) => Some(error),
+        }
+    }
+}
+
+/// Where a queued action comes from.
+struct Cause {
+    /// The action which was being dispatched when the action got queued, if keeping track of causality.
+    #[cfg(feature = "causality")]
+    action: Option<ActionId>,
+    /// The middleware the action already went through, see [`Store::dispatch_after`].
+    after: Option<MiddlewareId>,
+}
+
+/// What to do with an action queued while the queue of pending actions is full.
+///
+/// See [`Store::set_queue_capacity`](struct.Store.html#method.set_queue_capacity).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueueOverflowPolicy {
+    /// Drops the action being queued.
+    RejectNewest,
+    /// Drops the oldest queued action to make room for the new one.
+    DropOldest,
+    /// Panics.
+    Panic,
+}
+
+/// What to do when a subscription panics.
+///
+/// See [`Store::set_subscription_panic_policy`](struct.Store.html#method.set_subscription_panic_policy).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscriptionPanicPolicy {
+    /// Lets the panic unwind through the dispatch, skipping the remaining subscriptions.
+    Propagate,
+    /// Catches the panic, reports it to the global error handler (if any) and goes on with the remaining subscriptions.
+    Isolate,
+}
+
+/// An error arising in a store, as reported to the global error handler.
+///
+/// See [`Store::set_global_error_handler`](struct.Store.html#method.set_global_error_handler).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StoreError {
+    /// A fallible reducer failed with the given message, see [`Store::try_reduce`](struct.Store.html#method.try_reduce).
+    Reducer(String),
+    /// The middleware at the given index halted an action as an error, see [`Store::halt_with_reason`](struct.Store.html#method.halt_with_reason).
+    MiddlewareHalted {
+        /// The index of the middleware.
+        index: usize,
+        /// Why the action got halted.
+        reason: String,
+    },
+    /// An async middleware halted an action as an error, see [`Store::halt_with_reason`](struct.Store.html#method.halt_with_reason).
+    AsyncMiddlewareHalted {
+        /// Why the action got halted.
+        reason: String,
+    },
+    /// An async dispatch timed out, see [`Store::dispatch_async_timeout`](struct.Store.html#method.dispatch_async_timeout).
+    Timeout,
+    /// The subscription at the given index panicked with the given message, while panics were isolated.
+    SubscriptionPanicked {
+        /// The index of the subscription.
+        index: usize,
+        /// The panic message.
+        message: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Reducer(message) => write!(f, "reducer failed: {}", message),
+            StoreError::MiddlewareHalted { index, reason } => {
+                write!(f, "middleware {} halted an action: {}", index, reason)
+            }
+            StoreError::AsyncMiddlewareHalted { reason } => {
+                write!(f, "async middleware halted an action: {}", reason)
+            }
+            StoreError::Timeout => write!(f, "dispatch timed out"),
+            StoreError::SubscriptionPanicked { index, message } => {
+                write!(f, "subscription {} panicked: {}", index, message)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StoreError {}
+
+#[cfg(feature = "std")]
+type ErrorHandler = Box<dyn Fn(&StoreError)>;
+
+type UnhandledCallback<State, Action> = Box<dyn Fn(&State, &Action)>;
+
+/// A callback for actions the reducer did not handle, see [`Store::set_unhandled_action_handler`].
+struct UnhandledHandler<State, Action> {
+    handler: UnhandledCallback<State, Action>,
+    clone_action: fn(&Action) -> Action,
+}
+
+/// A subscription to a part of the state, see [`Store::subscribe_selector`].
+struct SelectorSubscription<T, S, F> {
+    selector: S,
+    callback: F,
+    last: RefCell<T>,
+}
+
+impl<State, T, S, F> Subscription<State> for SelectorSubscription<T, S, F>
+where
+    T: PartialEq,
+    S: Fn(&State) -> T,
+    F: Fn(&T),
+{
+    fn update(&self, state: &State) {
+        let selected = (self.selector)(state);
+        if selected != *self.last.borrow() {
+            self.last.replace(selected);
+            (self.callback)(&self.last.borrow());
+        }
+    }
+
+    fn should_update(&self, state: &State) -> bool {
+        (self.selector)(state) != *self.last.borrow()
+    }
+}
+
+/// The last states produced by the reducer, see [`Store::enable_snapshot_ring`].
+struct SnapshotRing<State> {
+    capacity: usize,
+    states: VecDeque<State>,
+    clone_state: Option<fn(&State) -> State>,
+}
+
+impl<State> SnapshotRing<State> {
+    fn push(&mut self, state: &State) {
+        let clone_state = match self.clone_state {
+            Some(clone_state) if self.capacity > 0 => clone_state,
+            _ => return,
+        };
+        if self.states.len() == self.capacity {
+            self.states.pop_front();
+        }
+        self.states.push_back(clone_state(state));
+    }
+}
+
+type HaltObserver<Action> = Box<dyn Fn(usize, &Action)>;
+
+type FirstDispatchHook<State, Action> = Box<dyn FnOnce(&State, &Action)>;
+
+/// A callback for actions reaching the reducer, see [`Store::on_action`].
+struct ActionObserver<Action> {
+    matcher: Box<dyn Fn(&Action) -> bool>,
+    callback: Box<dyn Fn(&Action)>,
+    clone_action: fn(&Action) -> Action,
+}
+
+/// Restores a reducer when dropped.
+struct RestoreReducer<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    reducer: Rc<dyn StoreReducer<State, Action>>,
+}
+
+impl<State, Action> Drop for RestoreReducer<'_, State, Action> {
+    fn drop(&mut self) {
+        mem::swap(&mut self.store.reducer, &mut self.reducer);
+    }
+}
+
+/// Ends a transaction when dropped, even if it panicked.
+struct EndTransaction<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+}
+
+impl<State, Action> Drop for EndTransaction<'_, State, Action> {
+    fn drop(&mut self) {
+        self.store.transactions -= 1;
+    }
+}
+
+/// Ends an async dispatch when dropped, even if it got cancelled, e.g. by a timeout.
+#[cfg(feature = "async")]
+struct EndAsyncDispatch<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+}
+
+#[cfg(feature = "async")]
+impl<State, Action> Drop for EndAsyncDispatch<'_, State, Action> {
+    fn drop(&mut self) {
+        #[cfg(feature = "causality")]
+        self.store.causality.end();
+    }
+}
+
+/// Restores the around middleware when dropped, even if the chain panicked.
+struct RestoreAroundMiddleware<'a, State, Action> {
+    store: &'a mut Store<State, Action>,
+    around: Vec<Box<dyn AroundMiddleware<State, Action>>>,
+}
+
+impl<State, Action> Drop for RestoreAroundMiddleware<'_, State, Action> {
+    fn drop(&mut self) {
+        mem::swap(&mut self.store.around_middleware, &mut self.around);
     }
 }
+
+#[macro_export]
+/// Defines a module holding the reducer and a constructor for a store, given per-variant handlers.
+///
+/// Every handler is written as a closure receiving the current state and returning the new one.
+/// Fields of the variant are bound by its pattern and can be used in the handler.
+/// The generated module provides `reducer` and `make_store`, which creates a store starting from the given initial state.
+/// It sees the items of the enclosing module, so the state and action types have to be defined at module level.
+/// As with [`exhaustive_reducer!`](macro.exhaustive_reducer.html), every variant has to be handled and every handler has to end with a comma.
+///
+/// # Usage
+///
+/// ```
+/// # use redux_rs::define_store;
+/// #
+/// # type State = u8;
+/// #
+/// # enum Action {
+/// #     Increment,
+/// #     Add(u8)
+/// # }
+/// #
+/// define_store! {
+///     mod counter {
+///         state: State = 0,
+///         action: Action,
+///         handlers {
+///             Increment => |state| state + 1,
+///             Add(value) => |state| state + value,
+///         }
+///     }
+/// }
+/// #
+/// # fn main() {}
+/// ```
+/// (`State` and `Action` being the actual types.)
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::define_store;
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// define_store! {
+///     mod counter {
+///         state: i8 = 0,
+///         action: Action,
+///         handlers {
+///             Increment => |state| state + 1,
+///             Decrement => |state| state - 1,
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut store = counter::make_store();
+/// store.dispatch(Action::Increment);
+/// assert_eq!(*store.state(), 1);
+/// # }
+/// ```
+macro_rules! define_store {
+    (
+        $vis: vis mod $name: ident {
+            state: $state_type: ty = $initial: expr,
+            action: $action_type: ident,
+            handlers {
+                $($variant: ident $(($($tuple: tt)*))? $({$($fields: tt)*})? => |$state: ident| $body: expr,)+
+            }
+        }
+    ) => (
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// The reducer, dispatching every action to its handler.
+            pub fn reducer(state: &$state_type, action: $action_type) -> $state_type {
+                match action {
+                    $($action_type::$variant $(($($tuple)*))? $({$($fields)*})? => {
+                        let $state = state;
+                        $body
+                    })+
+                }
+            }
+
+            /// Creates a store with the reducer and the initial state.
+            pub fn make_store() -> $crate::Store<$state_type, $action_type> {
+                $crate::Store::new(reducer, $initial)
+            }
+        }
+    )
+}
@@ -1,5 +1,42 @@
+use crate::{Box, Rc, Vec};
+use core::cell::RefCell;
+
+/// A listener getting notified about state changes.
+///
+/// Besides closures (see below), any type can be a subscription by implementing this trait,
+/// which allows subscribers carrying their own configuration.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Store, Subscription};
+/// #
+/// # fn reducer(_: &u8, _: ()) -> u8 {
+/// #     0
+/// # }
+/// #
+/// struct Logger {
+///     label: &'static str
+/// }
+///
+/// impl Subscription<u8> for Logger {
+///     fn update(&self, state: &u8) {
+///         println!("{}: {}", self.label, state);
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.subscribe(Logger { label: "counter" });
+/// ```
 pub trait Subscription<State> {
     fn update(&self, state: &State);
+
+    /// Returns whether the subscription wants to be notified about a state, `true` by default.
+    ///
+    /// Subscriptions returning `false` are skipped, and do not count as fired.
+    fn should_update(&self, _state: &State) -> bool {
+        true
+    }
 }
 
 /// Function signature for a subscription.
@@ -35,3 +72,88 @@ where
         self(state)
     }
 }
+
+pub trait CloneableSubscription<State>: Subscription<State> {
+    fn clone_boxed(&self) -> Box<dyn Subscription<State>>;
+    fn clone_subscription(&self) -> Box<dyn CloneableSubscription<State>>;
+}
+
+/// Signature for a subscription which can be cloned, and thus carried over to a forked store.
+///
+/// Any `Clone` subscription qualifies, see [`Store::subscribe_cloneable`](struct.Store.html#method.subscribe_cloneable)
+/// and [`Store::fork_with_subscriptions`](struct.Store.html#method.fork_with_subscriptions).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{Store, Subscription};
+/// #
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// #[derive(Clone)]
+/// struct Logger {
+///     label: &'static str
+/// }
+///
+/// impl Subscription<u8> for Logger {
+///     fn update(&self, state: &u8) {
+///         println!("{}: {}", self.label, state);
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.subscribe_cloneable(Logger { label: "counter" });
+///
+/// let mut fork = store.fork_with_subscriptions();
+/// fork.dispatch(1); // Prints "counter: 1".
+/// ```
+impl<State, S> CloneableSubscription<State> for S
+where
+    S: Subscription<State> + Clone + 'static,
+{
+    fn clone_boxed(&self) -> Box<dyn Subscription<State>> {
+        Box::new(self.clone())
+    }
+
+    fn clone_subscription(&self) -> Box<dyn CloneableSubscription<State>> {
+        Box::new(self.clone())
+    }
+}
+
+/// An opaque handle to a subscription registered in a store.
+///
+/// Returned when subscribing, e.g. by [`Store::subscribe`](struct.Store.html#method.subscribe),
+/// and used to unsubscribe, see [`Store::unsubscribe`](struct.Store.html#method.unsubscribe).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub(crate) usize);
+
+/// A handle given to a subscription to control itself from within its callback.
+///
+/// See [`Store::subscribe_with_control`](struct.Store.html#method.subscribe_with_control).
+pub struct SubscriptionControl {
+    id: SubscriptionId,
+    detached: Rc<RefCell<Vec<SubscriptionId>>>,
+}
+
+impl SubscriptionControl {
+    pub(crate) fn new(id: SubscriptionId, detached: Rc<RefCell<Vec<SubscriptionId>>>) -> Self {
+        Self { id, detached }
+    }
+
+    /// Returns the id of the subscription.
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+
+    /// Unsubscribes the subscription.
+    ///
+    /// The subscription is not notified anymore, but only gets removed once the current notification completes.
+    pub fn unsubscribe(&self) {
+        let mut detached = self.detached.borrow_mut();
+        if !detached.contains(&self.id) {
+            detached.push(self.id);
+        }
+    }
+}
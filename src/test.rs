@@ -0,0 +1,616 @@
+//! Helpers for testing reducers in isolation.
+//!
+//! Requires the `test-util` feature.
+
+#[cfg(feature = "async")]
+use crate::{Box, Clock, SleepFuture, Timer};
+use crate::{Rc, Reducible, Store, SubscriptionId, Vec};
+use core::cell::RefCell;
+use core::fmt::Debug;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    time::{Duration, Instant},
+};
+
+/// Asserts that reducing `state` with `action` results in `expected`.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::test::assert_reduces;
+/// #
+/// fn reducer(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// assert_reduces(reducer, &1, 2, 3);
+/// ```
+pub fn assert_reduces<State, Action, R>(reducer: R, state: &State, action: Action, expected: State)
+where
+    State: PartialEq + Debug,
+    R: Reducible<State, Action>,
+{
+    assert_eq!(reducer.reduce(state, action), expected);
+}
+
+/// Folds a sequence of actions through a reducer, returning the final state.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::test::reduce_sequence;
+/// #
+/// fn reducer(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// assert_eq!(reduce_sequence(reducer, 0, vec![1, 2, 3]), 6);
+/// ```
+pub fn reduce_sequence<State, Action, R, I>(reducer: R, initial_state: State, actions: I) -> State
+where
+    R: Reducible<State, Action>,
+    I: IntoIterator<Item = Action>,
+{
+    actions.into_iter().fold(initial_state, |state, action| {
+        reducer.reduce(&state, action)
+    })
+}
+
+/// Asserts that two reducers produce equal states for every combination of `states` and `actions`.
+///
+/// This helps refactoring a reducer safely: the first mismatch gets reported along with the state and action leading to it.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::test::assert_reducers_equivalent;
+/// #
+/// fn old(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// fn new(state: &u8, increment: u8) -> u8 {
+///     increment + state
+/// }
+///
+/// assert_reducers_equivalent(old, new, &[0, 1, 2], &[0, 1, 2]);
+/// ```
+pub fn assert_reducers_equivalent<State, Action, Old, New>(
+    old: Old,
+    new: New,
+    states: &[State],
+    actions: &[Action],
+) where
+    State: PartialEq + Clone + Debug,
+    Action: Clone + Debug,
+    Old: Reducible<State, Action>,
+    New: Reducible<State, Action>,
+{
+    for state in states {
+        for action in actions {
+            let expected = old.reduce(state, action.clone());
+            let actual = new.reduce(state, action.clone());
+            assert!(
+                actual == expected,
+                "reducers diverge for state {:?} and action {:?}: old reducer returned {:?}, new reducer returned {:?}",
+                state,
+                action,
+                expected,
+                actual
+            );
+        }
+    }
+}
+
+/// Dispatches `action`, asserting that it left the state of `store` unchanged.
+///
+/// Use [`assert_halted`](fn.assert_halted.html) to also assert that the action never reached the reducer.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// use redux_rs::test::assert_no_change;
+///
+/// fn reducer(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// let mut store = Store::new(reducer, 1);
+/// assert_no_change(&mut store, 0);
+/// ```
+pub fn assert_no_change<State, Action>(store: &mut Store<State, Action>, action: Action)
+where
+    State: PartialEq + Clone + Debug,
+{
+    let before = store.state().clone();
+    store.dispatch(action);
+    assert_eq!(
+        *store.state(),
+        before,
+        "state changed although no change was expected"
+    );
+}
+
+/// Dispatches `action`, asserting that it got halted before reaching the reducer and left the state of `store` unchanged.
+///
+/// See [`Store::step`](../struct.Store.html#method.step).
+pub fn assert_halted<State, Action>(store: &mut Store<State, Action>, action: Action)
+where
+    State: PartialEq + Clone + Debug,
+{
+    let before = store.state().clone();
+    let report = store.step(action);
+    assert!(report.halted, "action was not halted");
+    assert_eq!(
+        report.state, before,
+        "state changed although no change was expected"
+    );
+}
+
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+}
+
+/// Source of randomness used for fuzzing.
+///
+/// Any function returning random `u64`s can be used, see [`XorShift`](struct.XorShift.html) for a simple seeded generator.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::test::Rng;
+/// #
+/// let mut counter = 0;
+/// let mut rng = move || {
+///     counter += 1;
+///     counter
+/// };
+///
+/// assert_eq!(rng.next_u64(), 1);
+/// ```
+impl<Function> Rng for Function
+where
+    Function: FnMut() -> u64,
+{
+    fn next_u64(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// A small, deterministic pseudo random number generator.
+///
+/// This is not suitable for cryptography, but good enough to generate actions reproducibly from a seed.
+#[derive(Clone, Debug)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        // A zero state would only ever produce zeroes.
+        Self { state: seed.max(1) }
+    }
+}
+
+impl Rng for XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+/// Types which can be generated randomly, e.g. actions to fuzz a store with.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::test::{Arbitrary, Rng};
+/// #
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// impl Arbitrary for Action {
+///     fn arbitrary(rng: &mut dyn Rng) -> Self {
+///         match rng.next_u64() % 2 {
+///             0 => Action::Increment,
+///             _ => Action::Decrement
+///         }
+///     }
+/// }
+/// ```
+pub trait Arbitrary {
+    /// Generates a random value.
+    fn arbitrary(rng: &mut dyn Rng) -> Self;
+}
+
+/// Dispatches `iterations` random actions, asserting that `invariant` holds for the state after each of them.
+///
+/// Panics with the offending action if the invariant does not hold.
+/// Panics raised by the store itself, e.g. by an overflowing reducer, propagate as well.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// use redux_rs::test::{fuzz_dispatch, Arbitrary, Rng, XorShift};
+///
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Increment,
+///     Decrement
+/// }
+///
+/// impl Arbitrary for Action {
+///     fn arbitrary(rng: &mut dyn Rng) -> Self {
+///         match rng.next_u64() % 2 {
+///             0 => Action::Increment,
+///             _ => Action::Decrement
+///         }
+///     }
+/// }
+///
+/// fn reducer(state: &u8, action: Action) -> u8 {
+///     match action {
+///         Action::Increment => state.saturating_add(1),
+///         Action::Decrement => state.saturating_sub(1)
+///     }
+/// }
+///
+/// let mut store = Store::new(reducer, 0);
+/// fuzz_dispatch(&mut store, 1000, &mut XorShift::new(42), |_: &u8| true);
+/// ```
+pub fn fuzz_dispatch<State, Action, R, I>(
+    store: &mut Store<State, Action>,
+    iterations: usize,
+    rng: &mut R,
+    invariant: I,
+) where
+    Action: Arbitrary + Clone + Debug,
+    R: Rng,
+    I: Fn(&State) -> bool,
+{
+    for iteration in 0..iterations {
+        let action = Action::arbitrary(rng);
+        store.dispatch(action.clone());
+        assert!(
+            invariant(store.state()),
+            "invariant violated after dispatching {:?} (iteration {})",
+            action,
+            iteration
+        );
+    }
+}
+
+/// An event happening while a store dispatches an action, as recorded by a [`TraceRecorder`](struct.TraceRecorder.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// The middleware at the given index got called.
+    MiddlewareEntered(usize),
+    /// The middleware at the given index passed an action on.
+    MiddlewareForwarded(usize),
+    /// The middleware at the given index halted the chain.
+    MiddlewareHalted(usize),
+    /// The reducer ran.
+    ReducerRan,
+    /// The subscription at the given index got notified.
+    SubscriptionFired(usize),
+}
+
+/// Records the ordered trace of events of dispatches.
+///
+/// Clones share the same trace, so a clone can be handed to a store while the original one is used for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::Store;
+/// use redux_rs::test::{TraceEvent, TraceRecorder};
+///
+/// # fn reducer(state: &u8, _: ()) -> u8 {
+/// #     state + 1
+/// # }
+/// #
+/// let mut store = Store::new(reducer, 0);
+///
+/// let recorder = TraceRecorder::new();
+/// store.set_trace_recorder(recorder.clone());
+///
+/// store.dispatch(());
+/// assert_eq!(recorder.events(), vec![TraceEvent::ReducerRan]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TraceRecorder {
+    events: Rc<RefCell<Vec<TraceEvent>>>,
+}
+
+impl TraceRecorder {
+    /// Creates an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events recorded so far.
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.events.borrow().clone()
+    }
+
+    /// Forgets all events recorded so far.
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    pub(crate) fn record(&self, event: TraceEvent) {
+        self.events.borrow_mut().push(event);
+    }
+}
+
+/// What happened when dispatching a single action, as returned by [`Store::step`](../struct.Store.html#method.step).
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepReport<State> {
+    /// Whether the action got halted before reaching the reducer.
+    pub halted: bool,
+    /// The state after the dispatch.
+    pub state: State,
+    /// The subscriptions which got notified, in order.
+    pub subscriptions_fired: Vec<SubscriptionId>,
+}
+
+/// A deterministic scheduler for testing async code without real timers.
+///
+/// Time only advances when told to, and spawned tasks only run when the scheduler is driven,
+/// so debouncing, retries and timeouts can be tested without sleeping.
+/// Clones share the same time and tasks.
+///
+/// The scheduler is a [`Clock`](../trait.Clock.html) and a [`Timer`](../trait.Timer.html),
+/// so it can be handed to anything measuring or waiting for time, e.g. using [`Store::set_timer`](../struct.Store.html#method.set_timer).
+///
+/// Requires the `async` feature.
+///
+/// # Example
+///
+/// ```
+/// use redux_rs::test::TestScheduler;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use std::time::Duration;
+///
+/// let scheduler = TestScheduler::new();
+/// let done = Rc::new(Cell::new(false));
+///
+/// let sleep = scheduler.sleep(Duration::from_secs(1));
+/// let finished = done.clone();
+/// scheduler.spawn(async move {
+///     sleep.await;
+///     finished.set(true);
+/// });
+///
+/// scheduler.advance(Duration::from_millis(999));
+/// assert!(!done.get());
+///
+/// scheduler.advance(Duration::from_millis(1));
+/// assert!(done.get());
+/// ```
+#[cfg(feature = "async")]
+#[derive(Clone, Default)]
+pub struct TestScheduler {
+    inner: Rc<RefCell<SchedulerState>>,
+}
+
+#[cfg(feature = "async")]
+struct SchedulerState {
+    now: Instant,
+    timers: Vec<(Instant, Waker)>,
+    tasks: Vec<ScheduledTask>,
+}
+
+#[cfg(feature = "async")]
+impl Default for SchedulerState {
+    fn default() -> Self {
+        Self {
+            now: Instant::now(),
+            timers: Vec::new(),
+            tasks: Vec::new(),
+        }
+    }
+}
+
+/// A spawned task, along with whether it got woken since it was last polled.
+#[cfg(feature = "async")]
+struct ScheduledTask {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    woken: Arc<WokenFlag>,
+}
+
+#[cfg(feature = "async")]
+struct WokenFlag(AtomicBool);
+
+#[cfg(feature = "async")]
+impl WokenFlag {
+    fn woken() -> Arc<Self> {
+        Arc::new(Self(AtomicBool::new(true)))
+    }
+
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Wake for WokenFlag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "async")]
+impl TestScheduler {
+    /// Creates a scheduler stopped at the current time, without any tasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a future resolving once the scheduler's time advanced by `duration`.
+    pub fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(Sleep {
+            scheduler: self.clone(),
+            deadline: self.now() + duration,
+        })
+    }
+
+    /// Spawns a task, which first runs when the scheduler is driven next.
+    pub fn spawn<F: Future<Output = ()> + 'static>(&self, future: F) {
+        self.inner.borrow_mut().tasks.push(ScheduledTask {
+            future: Box::pin(future),
+            woken: WokenFlag::woken(),
+        });
+    }
+
+    /// Moves time forward, firing all timers due until then, and runs the tasks until none can make progress.
+    ///
+    /// Timers fire in order of their deadlines, so tasks observe time advancing step by step.
+    pub fn advance(&self, duration: Duration) {
+        let target = self.now() + duration;
+        while let Some(deadline) = self.next_deadline().filter(|deadline| *deadline <= target) {
+            self.advance_to(deadline);
+        }
+        self.advance_to(target);
+    }
+
+    /// Runs all tasks until none of them can make progress without time advancing.
+    pub fn run_until_stalled(&self) {
+        loop {
+            let mut tasks = mem::take(&mut self.inner.borrow_mut().tasks);
+            let mut progressed = false;
+
+            tasks.retain_mut(|task| {
+                if !task.woken.take() {
+                    return true;
+                }
+
+                progressed = true;
+                let waker = Waker::from(task.woken.clone());
+                task.future
+                    .as_mut()
+                    .poll(&mut Context::from_waker(&waker))
+                    .is_pending()
+            });
+
+            // Tasks spawned while polling got added in the meantime.
+            let mut inner = self.inner.borrow_mut();
+            tasks.append(&mut inner.tasks);
+            inner.tasks = tasks;
+
+            if !progressed {
+                break;
+            }
+        }
+    }
+
+    /// Drives a future to completion, running spawned tasks and advancing time to the next timer whenever everything is stalled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the future cannot complete, because everything is stalled and no timer is pending.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let woken = WokenFlag::woken();
+        let waker = Waker::from(woken.clone());
+
+        loop {
+            if woken.take() {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker))
+                {
+                    return output;
+                }
+            }
+
+            self.run_until_stalled();
+            if woken.0.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            match self.next_deadline() {
+                Some(deadline) => self.advance_to(deadline),
+                None => panic!(
+                    "future cannot make progress: all tasks are stalled and no timer is pending"
+                ),
+            }
+        }
+    }
+
+    /// Returns the earliest pending timer deadline, if any.
+    fn next_deadline(&self) -> Option<Instant> {
+        self.inner
+            .borrow()
+            .timers
+            .iter()
+            .map(|(deadline, _)| *deadline)
+            .min()
+    }
+
+    /// Sets the time, firing all timers due, and runs the tasks until stalled.
+    fn advance_to(&self, now: Instant) {
+        let due: Vec<Waker> = {
+            let mut inner = self.inner.borrow_mut();
+            inner.now = inner.now.max(now);
+
+            let now = inner.now;
+            let (due, pending) = mem::take(&mut inner.timers)
+                .into_iter()
+                .partition(|(deadline, _)| *deadline <= now);
+            inner.timers = pending;
+            due.into_iter().map(|(_, waker)| waker).collect()
+        };
+
+        due.into_iter().for_each(Waker::wake);
+        self.run_until_stalled();
+    }
+}
+
+#[cfg(feature = "async")]
+impl Clock for TestScheduler {
+    fn now(&self) -> Instant {
+        self.inner.borrow().now
+    }
+}
+
+#[cfg(feature = "async")]
+impl Timer for TestScheduler {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        TestScheduler::sleep(self, duration)
+    }
+}
+
+/// Future resolving once a scheduler's time reached a deadline.
+#[cfg(feature = "async")]
+struct Sleep {
+    scheduler: TestScheduler,
+    deadline: Instant,
+}
+
+#[cfg(feature = "async")]
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut inner = self.scheduler.inner.borrow_mut();
+        if inner.now >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        inner.timers.push((self.deadline, cx.waker().clone()));
+        Poll::Pending
+    }
+}
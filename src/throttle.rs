@@ -0,0 +1,123 @@
+use crate::{Clock, Rc, Subscription};
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+/// A subscription running its callback at most once per interval, e.g. to re-render at most once per frame.
+///
+/// The first notification runs the callback right away, and further notifications within the interval only keep
+/// the latest state around.
+/// That pending state is delivered by the next notification once the interval passed, which brings an even newer state,
+/// or by [`flush`](#method.flush), e.g. at the end of a frame once notifications stopped.
+/// Clones share their pending state, so keep a clone around to flush it after subscribing to a store.
+///
+/// See [`Store::subscribe_throttled`](struct.Store.html#method.subscribe_throttled).
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{ManualClock, Store, ThrottledSubscription};
+/// use std::time::Duration;
+///
+/// # fn reducer(state: &u8, increment: u8) -> u8 {
+/// #     state + increment
+/// # }
+/// #
+/// let clock = ManualClock::new();
+/// let throttled = ThrottledSubscription::new(Duration::from_millis(16), |state: &u8| println!("Rendering {}", state))
+///     .with_clock(clock.clone());
+///
+/// let mut store = Store::new(reducer, 0);
+/// store.subscribe(throttled.clone());
+///
+/// store.dispatch(1); // Prints "Rendering 1".
+/// store.dispatch(2); // Prints nothing.
+///
+/// clock.advance(Duration::from_millis(16));
+/// throttled.flush(); // Prints "Rendering 3".
+/// ```
+pub struct ThrottledSubscription<State, S> {
+    subscription: Rc<S>,
+    min_interval: Duration,
+    clock: Rc<dyn Clock>,
+    last_run: Rc<Cell<Option<Instant>>>,
+    pending: Rc<RefCell<Option<State>>>,
+}
+
+impl<State, S> ThrottledSubscription<State, S> {
+    /// Throttles a subscription, running it at most once per `min_interval`.
+    pub fn new(min_interval: Duration, subscription: S) -> Self {
+        Self {
+            subscription: Rc::new(subscription),
+            min_interval,
+            clock: Rc::new(Instant::now),
+            last_run: Rc::new(Cell::new(None)),
+            pending: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Uses the given clock to measure intervals.
+    ///
+    /// See [`Clock`](trait.Clock.html).
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Rc::new(clock);
+        self
+    }
+
+    /// Returns whether a skipped state still waits to be delivered.
+    pub fn is_pending(&self) -> bool {
+        self.pending.borrow().is_some()
+    }
+
+    /// Returns whether the interval since the last run passed at `now`.
+    fn is_due(&self, now: Instant) -> bool {
+        match self.last_run.get() {
+            Some(last_run) => now.duration_since(last_run) >= self.min_interval,
+            None => true,
+        }
+    }
+}
+
+impl<State, S: Subscription<State>> ThrottledSubscription<State, S> {
+    /// Delivers the latest skipped state if the interval passed, returning whether the callback ran.
+    pub fn flush(&self) -> bool {
+        let now = self.clock.now();
+        if !self.is_due(now) {
+            return false;
+        }
+
+        let pending = self.pending.borrow_mut().take();
+        match pending {
+            Some(state) => {
+                self.last_run.set(Some(now));
+                self.subscription.update(&state);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<State, S> Clone for ThrottledSubscription<State, S> {
+    fn clone(&self) -> Self {
+        Self {
+            subscription: self.subscription.clone(),
+            min_interval: self.min_interval,
+            clock: self.clock.clone(),
+            last_run: self.last_run.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<State: Clone, S: Subscription<State>> Subscription<State> for ThrottledSubscription<State, S> {
+    fn update(&self, state: &State) {
+        let now = self.clock.now();
+        if self.is_due(now) {
+            self.pending.borrow_mut().take();
+            self.last_run.set(Some(now));
+            self.subscription.update(state);
+        } else {
+            *self.pending.borrow_mut() = Some(state.clone());
+        }
+    }
+}
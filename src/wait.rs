@@ -0,0 +1,52 @@
+use crate::Rc;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Default)]
+struct Signal {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/// Future resolving once the state of a store satisfies a predicate.
+///
+/// Created by [`Store::wait_for`](struct.Store.html#method.wait_for).
+pub struct WaitFor {
+    signal: Rc<RefCell<Signal>>,
+}
+
+impl WaitFor {
+    pub(crate) fn new(done: bool) -> Self {
+        Self {
+            signal: Rc::new(RefCell::new(Signal { done, waker: None })),
+        }
+    }
+
+    /// Returns a callback resolving this future.
+    pub(crate) fn resolver(&self) -> impl Fn() {
+        let signal = self.signal.clone();
+        move || {
+            let mut signal = signal.borrow_mut();
+            signal.done = true;
+            if let Some(waker) = signal.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl Future for WaitFor {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut signal = self.signal.borrow_mut();
+        if signal.done {
+            Poll::Ready(())
+        } else {
+            signal.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
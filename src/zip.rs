@@ -0,0 +1,89 @@
+use crate::reducer::StoreReducer;
+use crate::{Rc, Store};
+
+/// An action for either half of a store created by [`combine_stores`](fn.combine_stores.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<Left, Right> {
+    /// An action for the first store.
+    Left(Left),
+    /// An action for the second store.
+    Right(Right),
+}
+
+/// Combines two stores into one holding both states as a tuple.
+///
+/// Actions are routed to the reducer of the matching half: `Either::Left` to the first store's, `Either::Right` to the second's.
+/// Subscriptions on the combined store see both states.
+/// Only the reducers and the states are kept: middleware, subscriptions and other settings of the original stores are dropped.
+///
+/// # Example
+///
+/// ```
+/// # use redux_rs::{combine_stores, Either, Store};
+/// #
+/// fn counter_reducer(state: &u8, increment: u8) -> u8 {
+///     state + increment
+/// }
+///
+/// fn name_reducer(_: &&'static str, name: &'static str) -> &'static str {
+///     name
+/// }
+///
+/// let counter = Store::new(counter_reducer, 0);
+/// let name = Store::new(name_reducer, "");
+///
+/// let mut store = combine_stores(counter, name);
+///
+/// store.dispatch(Either::Left(1));
+/// store.dispatch(Either::Right("counter"));
+/// assert_eq!(*store.state(), (1, "counter"));
+/// ```
+pub fn combine_stores<StateA, ActionA, StateB, ActionB>(
+    first: Store<StateA, ActionA>,
+    second: Store<StateB, ActionB>,
+) -> Store<(StateA, StateB), Either<ActionA, ActionB>>
+where
+    StateA: 'static,
+    ActionA: 'static,
+    StateB: 'static,
+    ActionB: 'static,
+{
+    let (first, first_state, first_detects) = first.into_parts();
+    let (second, second_state, second_detects) = second.into_parts();
+    Store::with_detecting_reducer(
+        Rc::new(ZipReducer {
+            first,
+            first_detects,
+            second,
+            second_detects,
+        }),
+        (first_state, second_state),
+    )
+}
+
+/// Routes actions to the reducer of either half of a tuple state.
+struct ZipReducer<StateA, ActionA, StateB, ActionB> {
+    first: Rc<dyn StoreReducer<StateA, ActionA>>,
+    first_detects: bool,
+    second: Rc<dyn StoreReducer<StateB, ActionB>>,
+    second_detects: bool,
+}
+
+impl<StateA, ActionA, StateB, ActionB> StoreReducer<(StateA, StateB), Either<ActionA, ActionB>>
+    for ZipReducer<StateA, ActionA, StateB, ActionB>
+{
+    fn reduce_in_place(
+        &self,
+        state: &mut (StateA, StateB),
+        action: Either<ActionA, ActionB>,
+    ) -> bool {
+        match action {
+            Either::Left(action) => {
+                self.first.reduce_in_place(&mut state.0, action) || !self.first_detects
+            }
+            Either::Right(action) => {
+                self.second.reduce_in_place(&mut state.1, action) || !self.second_detects
+            }
+        }
+    }
+}
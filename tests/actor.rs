@@ -0,0 +1,79 @@
+use redux_rs::{ActorStore, Store};
+use std::sync::mpsc;
+use std::time::Duration;
+
+type State = i64;
+
+enum Action {
+    Add(i64),
+    Square,
+    Crash,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Add(value) => state + value,
+        Action::Square => state * state,
+        Action::Crash => panic!("crash"),
+    }
+}
+
+#[test]
+fn actor_dispatch_and_wait() {
+    let actor = ActorStore::spawn(|| Store::new(reducer, 0));
+    assert_eq!(actor.state(), 0);
+
+    actor.dispatch(Action::Add(3));
+    actor.dispatch(Action::Square);
+    actor.dispatch(Action::Add(1));
+
+    assert_eq!(actor.wait_for(|state| *state == 10), 10);
+    assert_eq!(actor.state(), 10);
+}
+
+#[test]
+fn actor_subscriptions_on_worker() {
+    let (sender, receiver) = mpsc::channel();
+    let actor = ActorStore::spawn(move || {
+        let mut store = Store::new(reducer, 1);
+        store.subscribe(move |state: &State| sender.send(*state).unwrap());
+        store
+    });
+
+    actor.dispatch(Action::Add(1));
+    actor.dispatch(Action::Square);
+
+    assert_eq!(receiver.recv(), Ok(2));
+    assert_eq!(receiver.recv(), Ok(4));
+}
+
+#[test]
+fn actor_wait_timeout() {
+    let actor = ActorStore::spawn(|| Store::new(reducer, 0));
+    actor.dispatch(Action::Add(1));
+
+    assert_eq!(
+        actor.wait_for_timeout(|state| *state == 1, Duration::from_secs(5)),
+        Some(1)
+    );
+    assert_eq!(
+        actor.wait_for_timeout(|state| *state == 2, Duration::from_millis(10)),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "the worker thread panicked")]
+fn actor_wait_for_after_worker_panic() {
+    let actor = ActorStore::spawn(|| Store::new(reducer, 0));
+    actor.dispatch(Action::Crash);
+    actor.wait_for(|state| *state == 1);
+}
+
+#[test]
+#[should_panic(expected = "the worker thread panicked")]
+fn actor_wait_timeout_after_worker_panic() {
+    let actor = ActorStore::spawn(|| Store::new(reducer, 0));
+    actor.dispatch(Action::Crash);
+    actor.wait_for_timeout(|state| *state == 1, Duration::from_secs(60));
+}
@@ -0,0 +1,37 @@
+use redux_rs::{HistoryStore, Store};
+
+type State = i8;
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[test]
+fn aggregate_counts_increments() {
+    let mut history = HistoryStore::new(Store::new(reducer, 0));
+    history.dispatch(Action::Increment);
+
+    history.record_actions();
+    history.dispatch(Action::Increment);
+    history.dispatch(Action::Decrement);
+    history.dispatch(Action::Increment);
+    history.undo();
+    history.dispatch(Action::Increment);
+
+    let increments = history.aggregate(0, |count, action| match action {
+        Action::Increment => count + 1,
+        Action::Decrement => count,
+    });
+    assert_eq!(increments, 3);
+    assert_eq!(history.actions().len(), 4);
+    assert_eq!(*history.state(), 2);
+}
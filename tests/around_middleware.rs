@@ -0,0 +1,117 @@
+use redux_rs::{Next, Store};
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    SlowIncrement,
+    Explode,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::SlowIncrement => {
+            thread::sleep(Duration::from_millis(20));
+            state + 1
+        }
+        Action::Explode => panic!("boom"),
+    }
+}
+
+#[test]
+fn around_middleware_measures_reducer() {
+    let elapsed = Rc::new(Cell::new(Duration::default()));
+    let measured = elapsed.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_around_middleware(move |action: Action, next: Next<State, Action>| {
+        let start = Instant::now();
+        next.run(action);
+        measured.set(start.elapsed());
+    });
+
+    store.dispatch(Action::SlowIncrement);
+
+    assert_eq!(*store.state(), 1);
+    assert!(elapsed.get() >= Duration::from_millis(20));
+}
+
+#[test]
+fn around_middleware_before_and_after() {
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let recorded = transitions.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_around_middleware(move |action: Action, next: Next<State, Action>| {
+        let before = *next.state();
+        let after = *next.run(action);
+        recorded.borrow_mut().push((before, after));
+    });
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*transitions.borrow(), vec![(0, 1), (1, 2)]);
+}
+
+#[test]
+fn around_middleware_nesting_order() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    for name in ["outer", "inner"] {
+        let calls = calls.clone();
+        store.add_around_middleware(move |action: Action, next: Next<State, Action>| {
+            calls.borrow_mut().push(format!("{} before", name));
+            next.run(action);
+            calls.borrow_mut().push(format!("{} after", name));
+        });
+    }
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        *calls.borrow(),
+        vec!["outer before", "inner before", "inner after", "outer after"]
+    );
+}
+
+#[test]
+fn around_middleware_halts() {
+    let notified = Rc::new(Cell::new(false));
+    let subscribed = notified.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(move |_: &State| subscribed.set(true));
+    store.add_around_middleware(|_: Action, _: Next<State, Action>| {});
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*store.state(), 0);
+    assert!(!notified.get());
+}
+
+#[test]
+fn around_middleware_survives_panic() {
+    let calls = Rc::new(Cell::new(0));
+    let counted = calls.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_around_middleware(move |action: Action, next: Next<State, Action>| {
+        counted.set(counted.get() + 1);
+        next.run(action);
+    });
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(Action::Explode)));
+    assert!(result.is_err());
+
+    store.dispatch(Action::Increment);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(*store.state(), 1);
+}
@@ -0,0 +1,212 @@
+#![cfg(feature = "async")]
+
+use redux_rs::{DispatchMetrics, MiddlewarePhase, Store, SubscriptionControl};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Fetch,
+    Loaded(i8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Fetch => *state,
+        Action::Loaded(value) => value,
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Future resolving on its second poll, crossing an await point.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn logging_middleware(
+    log: &Log,
+    label: &'static str,
+) -> impl Fn(&Store<State, Action>, Action) -> Option<Action> {
+    let log = log.clone();
+    move |_: &Store<State, Action>, action: Action| {
+        log.borrow_mut().push(format!("{}: {:?}", label, action));
+        Some(action)
+    }
+}
+
+#[test]
+fn async_chain_interleaves_sync_and_async() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(logging_middleware(&log, "before"));
+    let fetch_log = log.clone();
+    store.add_async_middleware(move |_: &Store<State, Action>, action: Action| {
+        let log = fetch_log.clone();
+        async move {
+            match action {
+                Action::Fetch => {
+                    YieldNow(false).await;
+                    log.borrow_mut().push("fetched".to_string());
+                    Some(Action::Loaded(42))
+                }
+                action => Some(action),
+            }
+        }
+    });
+    store.add_middleware(logging_middleware(&log, "after"));
+
+    block_on(store.dispatch_async(Action::Fetch));
+
+    assert_eq!(*store.state(), 42);
+    assert_eq!(
+        *log.borrow(),
+        vec!["before: Fetch", "fetched", "after: Loaded(42)"]
+    );
+}
+
+#[test]
+fn async_chain_halts_in_sync_middleware() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(|_: &Store<State, Action>, _: Action| async {
+        Some(Action::Loaded(1))
+    });
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+    store.add_middleware(logging_middleware(&log, "never"));
+
+    block_on(store.dispatch_async(Action::Fetch));
+
+    assert_eq!(*store.state(), 0);
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn sync_dispatch_skips_async_middleware() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(|_: &Store<State, Action>, _: Action| async { None });
+    store.add_middleware(logging_middleware(&log, "sync"));
+
+    store.dispatch(Action::Loaded(3));
+
+    assert_eq!(*store.state(), 3);
+    assert_eq!(*log.borrow(), vec!["sync: Loaded(3)"]);
+}
+
+#[test]
+fn async_chain_respects_phases() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let async_log = log.clone();
+    store.add_async_middleware(move |_: &Store<State, Action>, action: Action| {
+        async_log.borrow_mut().push("async".to_string());
+        async move { Some(action) }
+    });
+    store.add_middleware_in_phase(MiddlewarePhase::Post, logging_middleware(&log, "post"));
+    store.add_middleware_in_phase(MiddlewarePhase::Pre, logging_middleware(&log, "pre"));
+
+    block_on(store.dispatch_async(Action::Loaded(1)));
+
+    assert_eq!(
+        *log.borrow(),
+        vec!["pre: Loaded(1)", "async", "post: Loaded(1)"]
+    );
+}
+
+#[test]
+fn async_chain_removes_detached_subscriptions() {
+    let mut store = Store::new(reducer, 0);
+    let captured = Rc::new(());
+    let subscription_captured = captured.clone();
+    store.subscribe_with_control(move |_: &State, control: &SubscriptionControl| {
+        let _ = &subscription_captured;
+        control.unsubscribe();
+    });
+
+    block_on(store.dispatch_async(Action::Loaded(1)));
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(Rc::strong_count(&captured), 1);
+}
+
+#[test]
+fn async_dispatch_queued_while_paused() {
+    let mut store = Store::new(reducer, 0);
+    store.pause_dispatch();
+
+    block_on(store.dispatch_async(Action::Loaded(1)));
+    assert_eq!(*store.state(), 0);
+
+    store.resume_dispatch();
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn async_dispatch_collects_metrics() {
+    let collected = Rc::new(RefCell::new(Vec::new()));
+    let captured = collected.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store
+        .set_metrics_collector(move |metrics: DispatchMetrics| captured.borrow_mut().push(metrics));
+
+    block_on(store.dispatch_async(Action::Loaded(1)));
+    assert_eq!(collected.borrow().len(), 1);
+}
+
+#[cfg(feature = "causality")]
+#[test]
+fn async_dispatch_gets_an_action_id() {
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(|store: &Store<State, Action>, action: Action| {
+        let cause = store.current_action_id();
+        async move {
+            assert!(cause.is_some());
+            Some(action)
+        }
+    });
+
+    block_on(store.dispatch_async(Action::Loaded(1)));
+    assert_eq!(store.action_graph().len(), 1);
+    assert_eq!(store.current_action_id(), None);
+}
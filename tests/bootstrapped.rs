@@ -0,0 +1,57 @@
+use redux_rs::Store;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    user: Option<&'static str>,
+    theme: &'static str,
+}
+
+enum Action {
+    Login(&'static str),
+    SetTheme(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Login(user) => State {
+            user: Some(user),
+            ..state.clone()
+        },
+        Action::SetTheme(theme) => State {
+            theme,
+            ..state.clone()
+        },
+    }
+}
+
+#[test]
+fn bootstrapped_replays_seed_actions() {
+    let store = Store::bootstrapped(
+        reducer,
+        State::default(),
+        vec![Action::Login("guest"), Action::SetTheme("dark")],
+    );
+
+    assert_eq!(
+        *store.state(),
+        State {
+            user: Some("guest"),
+            theme: "dark",
+        }
+    );
+}
+
+#[test]
+fn bootstrapped_then_dispatch() {
+    let notified = Rc::new(Cell::new(0));
+    let captured = notified.clone();
+
+    let mut store = Store::bootstrapped(reducer, State::default(), vec![Action::SetTheme("dark")]);
+    store.subscribe(move |_: &State| captured.set(captured.get() + 1));
+    store.dispatch(Action::Login("admin"));
+
+    assert_eq!(store.state().theme, "dark");
+    assert_eq!(notified.get(), 1);
+}
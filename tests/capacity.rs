@@ -0,0 +1,73 @@
+use redux_rs::{CapacityError, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn pass_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    Some(action)
+}
+
+#[test]
+fn capacity_rejects_fourth_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.set_middleware_capacity(3);
+
+    for _ in 0..3 {
+        assert!(store.try_add_middleware(pass_middleware).is_ok());
+    }
+    assert_eq!(
+        store.try_add_middleware(pass_middleware),
+        Err(CapacityError { capacity: 3 })
+    );
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn capacity_rejects_subscription() {
+    let mut store = Store::new(reducer, 0);
+    store.set_subscription_capacity(1);
+
+    assert!(store.try_subscribe(|_: &State| {}).is_ok());
+    assert_eq!(
+        store.try_subscribe(|_: &State| {}),
+        Err(CapacityError { capacity: 1 })
+    );
+}
+
+#[test]
+fn capacity_leaves_add_middleware_infallible() {
+    let mut store = Store::new(reducer, 0);
+    store.set_middleware_capacity(0);
+    store.set_subscription_capacity(0);
+
+    store.add_middleware(pass_middleware);
+    store.subscribe(|_: &State| {});
+    assert!(store.try_add_middleware(pass_middleware).is_err());
+    assert!(store.try_subscribe(|_: &State| {}).is_err());
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn capacity_ignores_detached_subscriptions() {
+    let mut store = Store::new(reducer, 0);
+    store.set_subscription_capacity(1);
+
+    let observable = store.observe();
+    assert!(store.try_subscribe(|_: &State| {}).is_err());
+
+    drop(observable);
+    assert!(store.try_subscribe(|_: &State| {}).is_ok());
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "causality")]
+
+use redux_rs::Store;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    IncrementTwice,
+    IncrementFourTimes,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        _ => *state,
+    }
+}
+
+fn thunk_middleware(store: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::IncrementTwice => {
+            store.enqueue(Action::Increment);
+            store.enqueue(Action::Increment);
+            None
+        }
+        Action::IncrementFourTimes => {
+            store.enqueue(Action::IncrementTwice);
+            store.enqueue(Action::IncrementTwice);
+            None
+        }
+        action => Some(action),
+    }
+}
+
+#[test]
+fn ids_are_monotonic() {
+    let mut store = Store::new(reducer, 0);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    let graph = store.action_graph();
+    let ids: Vec<_> = graph.ids().collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids[0] < ids[1]);
+    assert_eq!(graph.roots(), ids);
+}
+
+#[test]
+fn thunk_children_record_their_parent() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(thunk_middleware);
+
+    store.dispatch(Action::IncrementTwice);
+    store.drain_pending();
+    assert_eq!(*store.state(), 2);
+
+    let graph = store.action_graph();
+    let parent = graph.roots()[0];
+    let children = graph.children(parent);
+    assert_eq!(children.len(), 2);
+    for child in children {
+        assert_eq!(graph.cause(child), Some(parent));
+    }
+}
+
+#[test]
+fn causes_form_a_tree() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(thunk_middleware);
+
+    store.dispatch(Action::IncrementFourTimes);
+    store.drain_pending();
+    assert_eq!(*store.state(), 4);
+
+    let graph = store.action_graph();
+    assert_eq!(graph.len(), 7);
+    assert_eq!(graph.roots().len(), 1);
+
+    let root = graph.roots()[0];
+    let leaf = graph.ids().last().unwrap();
+    let causes = graph.causes(leaf);
+    assert_eq!(causes.len(), 2);
+    assert_eq!(causes[1], root);
+}
+
+#[test]
+fn current_action_id_is_visible_to_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(|store: &Store<State, Action>, action: Action| {
+        assert!(store.current_action_id().is_some());
+        Some(action)
+    });
+
+    assert!(store.current_action_id().is_none());
+    store.dispatch(Action::Increment);
+    assert!(store.current_action_id().is_none());
+}
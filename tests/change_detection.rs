@@ -0,0 +1,83 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq)]
+struct State {
+    value: i8,
+    updated_at: u32,
+}
+
+enum Action {
+    Set(i8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Set(value) => State {
+            value,
+            updated_at: state.updated_at + 1,
+        },
+    }
+}
+
+fn same_value(a: &State, b: &State) -> bool {
+    a.value == b.value
+}
+
+#[test]
+fn change_detection_by_ignores_field() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let recorded = seen.clone();
+
+    let mut store = Store::new_with_change_detection_by(
+        reducer,
+        State {
+            value: 0,
+            updated_at: 0,
+        },
+        same_value,
+    );
+    store.subscribe(move |state: &State| recorded.borrow_mut().push(state.value));
+
+    store.dispatch(Action::Set(0));
+    store.dispatch(Action::Set(0));
+    assert!(seen.borrow().is_empty());
+    assert_eq!(store.state().updated_at, 0);
+
+    store.dispatch(Action::Set(3));
+    assert_eq!(*seen.borrow(), vec![3]);
+    assert_eq!(store.state().updated_at, 1);
+}
+
+#[test]
+fn change_detection_partial_eq() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let recorded = seen.clone();
+
+    let mut store = Store::new_with_change_detection(|_: &i8, value: i8| value, 0);
+    store.subscribe(move |state: &i8| recorded.borrow_mut().push(*state));
+
+    store.dispatch(0);
+    store.dispatch(1);
+    store.dispatch(1);
+    store.dispatch(2);
+
+    assert_eq!(*seen.borrow(), vec![1, 2]);
+}
+
+#[test]
+fn change_detection_unhandled_action() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let recorded = unhandled.clone();
+
+    let mut store = Store::new_with_change_detection(|_: &i8, value: i8| value, 0);
+    store.set_unhandled_action_handler(move |_: &i8, action: &i8| {
+        recorded.borrow_mut().push(*action)
+    });
+
+    store.dispatch(0);
+    store.dispatch(1);
+
+    assert_eq!(*unhandled.borrow(), vec![0]);
+}
@@ -0,0 +1,46 @@
+use redux_rs::{checked_reduce, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn checked_reducer(state: &State, action: Action) -> Option<State> {
+    match action {
+        Action::Increment => state.checked_add(1),
+        Action::Decrement => state.checked_sub(1),
+    }
+}
+
+fn saturating_counter_reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state.saturating_add(1),
+        Action::Decrement => state.saturating_sub(1),
+    }
+}
+
+#[test]
+fn checked_reduce_at_max() {
+    let mut store = Store::new(checked_reduce(checked_reducer), i8::MAX);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), i8::MAX);
+
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), i8::MAX - 1);
+}
+
+#[test]
+fn checked_reduce_at_min() {
+    let mut store = Store::new(checked_reduce(checked_reducer), i8::MIN);
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), i8::MIN);
+}
+
+#[test]
+fn saturating_counter_at_max() {
+    let mut store = Store::new(saturating_counter_reducer, i8::MAX);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), i8::MAX);
+}
@@ -0,0 +1,84 @@
+use redux_rs::{Diff, Store};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    counter: i8,
+    label: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Patch {
+    counter: Option<i8>,
+    label: Option<String>,
+}
+
+impl Diff for State {
+    type Patch = Patch;
+
+    fn diff(&self, newer: &Self) -> Patch {
+        Patch {
+            counter: Some(newer.counter).filter(|counter| *counter != self.counter),
+            label: Some(newer.label.clone()).filter(|label| *label != self.label),
+        }
+    }
+}
+
+enum Action {
+    Increment,
+    Rename(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => State {
+            counter: state.counter + 1,
+            ..state.clone()
+        },
+        Action::Rename(label) => State {
+            label: label.to_string(),
+            ..state.clone()
+        },
+    }
+}
+
+#[test]
+fn checkpoint_diff() {
+    let mut store = Store::new(reducer, State::default());
+    store.dispatch(Action::Increment);
+
+    store.checkpoint("opened");
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        store.diff_from_checkpoint("opened"),
+        Some(Patch {
+            counter: Some(3),
+            label: None,
+        })
+    );
+}
+
+#[test]
+fn checkpoint_replaced() {
+    let mut store = Store::new(reducer, State::default());
+    store.checkpoint("opened");
+    store.dispatch(Action::Increment);
+
+    store.checkpoint("opened");
+    store.dispatch(Action::Rename("renamed"));
+
+    assert_eq!(
+        store.diff_from_checkpoint("opened"),
+        Some(Patch {
+            counter: None,
+            label: Some("renamed".to_string()),
+        })
+    );
+}
+
+#[test]
+fn checkpoint_missing() {
+    let store = Store::new(reducer, State::default());
+    assert_eq!(store.diff_from_checkpoint("opened"), None);
+}
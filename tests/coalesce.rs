@@ -0,0 +1,163 @@
+use redux_rs::{CoalesceMiddleware, ManualClock, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+type State = (i8, i8);
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Move(i8, i8),
+    MoveMany(Vec<(i8, i8)>),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Move(x, y) => (state.0 + x, state.1 + y),
+        Action::MoveMany(moves) => moves
+            .iter()
+            .fold(*state, |state, (x, y)| (state.0 + x, state.1 + y)),
+    }
+}
+
+fn combine(actions: Vec<Action>) -> Action {
+    Action::MoveMany(
+        actions
+            .into_iter()
+            .flat_map(|action| match action {
+                Action::Move(x, y) => vec![(x, y)],
+                Action::MoveMany(moves) => moves,
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn coalesce_count() {
+    let reduced = Rc::new(RefCell::new(Vec::new()));
+    let captured = reduced.clone();
+    let mut store = Store::new(
+        move |state: &State, action: Action| {
+            captured.borrow_mut().push(action.clone());
+            reducer(state, action)
+        },
+        (0, 0),
+    );
+    store.add_middleware(CoalesceMiddleware::every(10, combine));
+
+    for _ in 0..10 {
+        store.dispatch(Action::Move(1, 2));
+    }
+
+    assert_eq!(*store.state(), (10, 20));
+    assert_eq!(*reduced.borrow(), vec![Action::MoveMany(vec![(1, 2); 10])]);
+}
+
+#[test]
+fn coalesce_window() {
+    let clock = ManualClock::new();
+    let mut store = Store::new(reducer, (0, 0));
+    store.add_middleware(
+        CoalesceMiddleware::within(Duration::from_millis(16), combine).with_clock(clock.clone()),
+    );
+
+    for _ in 0..5 {
+        store.dispatch(Action::Move(1, 0));
+        clock.advance(Duration::from_millis(1));
+    }
+    assert_eq!(*store.state(), (0, 0));
+
+    clock.advance(Duration::from_millis(11));
+    store.dispatch(Action::Move(0, 1));
+    assert_eq!(*store.state(), (5, 1));
+
+    store.dispatch(Action::Move(1, 0));
+    assert_eq!(*store.state(), (5, 1));
+}
+
+#[test]
+fn coalesce_flush_trailing_batch() {
+    let reduced = Rc::new(RefCell::new(Vec::new()));
+    let captured = reduced.clone();
+    let mut store = Store::new(
+        move |state: &State, action: Action| {
+            captured.borrow_mut().push(action.clone());
+            reducer(state, action)
+        },
+        (0, 0),
+    );
+    let coalesce = CoalesceMiddleware::every(3, combine);
+    store.add_middleware(coalesce.clone());
+
+    for _ in 0..5 {
+        store.dispatch(Action::Move(1, 0));
+    }
+    assert_eq!(*store.state(), (3, 0));
+
+    coalesce.flush(&mut store);
+    assert_eq!(*store.state(), (5, 0));
+    assert_eq!(
+        *reduced.borrow(),
+        vec![
+            Action::MoveMany(vec![(1, 0); 3]),
+            Action::MoveMany(vec![(1, 0); 2]),
+        ]
+    );
+
+    coalesce.flush(&mut store);
+    assert_eq!(reduced.borrow().len(), 2);
+}
+
+#[test]
+fn coalesce_flush_while_paused() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let (before, after) = (seen.clone(), seen.clone());
+    let coalesce = CoalesceMiddleware::every(3, combine);
+
+    let mut store = Store::new(reducer, (0, 0));
+    store.add_middleware(move |_: &Store<State, Action>, action: Action| {
+        before.borrow_mut().push(("before", action.clone()));
+        Some(action)
+    });
+    store.add_middleware(coalesce.clone());
+    store.add_middleware(move |_: &Store<State, Action>, action: Action| {
+        after.borrow_mut().push(("after", action.clone()));
+        Some(action)
+    });
+
+    store.dispatch(Action::Move(1, 0));
+    store.dispatch(Action::Move(0, 1));
+
+    store.pause_dispatch();
+    coalesce.flush(&mut store);
+    assert_eq!(*store.state(), (0, 0));
+
+    store.resume_dispatch();
+    assert_eq!(*store.state(), (1, 1));
+    assert_eq!(
+        *seen.borrow(),
+        vec![
+            ("before", Action::Move(1, 0)),
+            ("before", Action::Move(0, 1)),
+            ("after", Action::MoveMany(vec![(1, 0), (0, 1)])),
+        ]
+    );
+}
+
+#[test]
+fn coalesce_flush_window() {
+    let clock = ManualClock::new();
+    let coalesce =
+        CoalesceMiddleware::within(Duration::from_millis(16), combine).with_clock(clock.clone());
+    let mut store = Store::new(reducer, (0, 0));
+    store.add_middleware(coalesce.clone());
+
+    store.dispatch(Action::Move(1, 0));
+    store.dispatch(Action::Move(0, 1));
+    coalesce.flush(&mut store);
+    assert_eq!(*store.state(), (1, 1));
+
+    clock.advance(Duration::from_millis(20));
+    store.dispatch(Action::Move(1, 0));
+    assert_eq!(*store.state(), (1, 1));
+}
@@ -0,0 +1,39 @@
+use redux_rs::{combine, combine_bounded, DepthError, Reducible};
+
+type State = i8;
+
+fn increment(state: &State, _: ()) -> State {
+    state + 1
+}
+
+// Nests `levels` combined reducers, each one wrapping the previous one.
+fn nested(levels: usize) -> Box<dyn Reducible<State, ()>> {
+    (0..levels).fold(Box::new(increment), |inner, _| {
+        Box::new(combine(vec![inner, Box::new(increment)]))
+    })
+}
+
+#[test]
+fn combine_depth_counts_nesting() {
+    assert_eq!(increment.depth(), 1);
+    assert_eq!(nested(3).depth(), 4);
+    assert_eq!(combine::<State, ()>(Vec::new()).depth(), 1);
+}
+
+#[test]
+fn combine_bounded_accepts_shallow() {
+    let reducer = combine_bounded(vec![nested(2), Box::new(increment)], 4).unwrap();
+    assert_eq!(reducer.reduce(&0, ()), 4);
+}
+
+#[test]
+fn combine_bounded_rejects_too_deep() {
+    let result = combine_bounded(vec![nested(4)], 4);
+    assert_eq!(
+        result.err(),
+        Some(DepthError {
+            depth: 6,
+            max_depth: 4
+        })
+    );
+}
@@ -0,0 +1,28 @@
+use redux_rs::{combine, ReducerRegistry, Reducible, Store};
+
+type State = i8;
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+}
+
+#[test]
+fn combine_empty_is_identity() {
+    let reducer = combine::<State, Action>(Vec::new());
+    assert_eq!(reducer.reduce(&3, Action::Increment), 3);
+
+    let mut store = Store::new(reducer, 3);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 3);
+}
+
+#[test]
+fn combine_empty_registry() {
+    let registry = ReducerRegistry::<State, Action>::new();
+    assert!(registry.is_empty());
+
+    let mut store = Store::new_with_registered(registry, -2);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), -2);
+}
@@ -0,0 +1,60 @@
+use redux_rs::{combine_reducers_first, Store};
+
+type State = i8;
+
+// `Action` needs to be `Clone` to be able to be used in `combine_reducers_first!`.
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+fn reducer_increment(state: &State, action: Action) -> Option<State> {
+    match action {
+        Action::Increment => Some(state + 1),
+        _ => None,
+    }
+}
+
+fn reducer_decrement(state: &State, action: Action) -> Option<State> {
+    match action {
+        Action::Decrement => Some(state - 1),
+        _ => None,
+    }
+}
+
+fn reducer_never(_: &State, _: Action) -> Option<State> {
+    panic!("the chain should have stopped before this reducer");
+}
+
+#[test]
+fn combine_first_short_circuits() {
+    let mut store = Store::new(
+        combine_reducers_first!(State, Action, reducer_increment, reducer_never),
+        0,
+    );
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+}
+
+#[test]
+fn combine_first_falls_through() {
+    let mut store = Store::new(
+        combine_reducers_first!(State, Action, reducer_increment, reducer_decrement),
+        0,
+    );
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), -1);
+}
+
+#[test]
+fn combine_first_unhandled() {
+    let mut store = Store::new(
+        combine_reducers_first!(State, Action, reducer_increment, reducer_decrement),
+        3,
+    );
+    store.dispatch(Action::Reset);
+    assert_eq!(*store.state(), 3);
+}
@@ -0,0 +1,78 @@
+use redux_rs::{combine_middleware, MapActionMiddleware, Store};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+fn reverse_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(Action::Decrement),
+        Action::Decrement => Some(Action::Increment),
+    }
+}
+
+fn only_increment_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Decrement => None,
+    }
+}
+
+#[test]
+fn combine_middleware_runs_all() {
+    let calls = Rc::new(Cell::new(0));
+    let counted = calls.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(combine_middleware!(
+        State,
+        Action,
+        move |_: &Store<State, Action>, action: Action| {
+            counted.set(counted.get() + 1);
+            Some(action)
+        },
+        reverse_middleware
+    ));
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(calls.get(), 1);
+    assert_eq!(*store.state(), -1);
+}
+
+#[test]
+fn combine_middleware_short_circuits() {
+    let calls = Rc::new(Cell::new(0));
+    let counted = calls.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(combine_middleware!(
+        State,
+        Action,
+        only_increment_middleware,
+        MapActionMiddleware::new(move |action: Action| {
+            counted.set(counted.get() + 1);
+            action
+        })
+    ));
+
+    store.dispatch(Action::Decrement);
+    assert_eq!(calls.get(), 0);
+    assert_eq!(*store.state(), 0);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(calls.get(), 1);
+    assert_eq!(*store.state(), 1);
+}
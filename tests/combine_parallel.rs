@@ -0,0 +1,88 @@
+use redux_rs::{combine_parallel, Reducible, Store};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct State {
+    clicks: u8,
+    max_seen: u8,
+}
+
+#[derive(Clone, Copy)]
+enum Action {
+    Click(u8),
+    Reset,
+}
+
+fn count_clicks(state: &State, action: Action) -> State {
+    match action {
+        Action::Click(_) => State {
+            clicks: state.clicks + 1,
+            ..*state
+        },
+        Action::Reset => State::default(),
+    }
+}
+
+fn track_max(state: &State, action: Action) -> State {
+    match action {
+        Action::Click(value) => State {
+            max_seen: state.max_seen.max(value),
+            ..*state
+        },
+        Action::Reset => State::default(),
+    }
+}
+
+fn merge_max(merged: State, candidate: State) -> State {
+    State {
+        clicks: merged.clicks.max(candidate.clicks),
+        max_seen: merged.max_seen.max(candidate.max_seen),
+    }
+}
+
+#[test]
+fn combine_parallel_merges_partial_updates() {
+    let reducer = combine_parallel(
+        vec![Box::new(count_clicks), Box::new(track_max)],
+        merge_max,
+        |state: &State| *state,
+    );
+    let mut store = Store::new(reducer, State::default());
+
+    store.dispatch(Action::Click(5));
+    store.dispatch(Action::Click(3));
+
+    assert_eq!(
+        *store.state(),
+        State {
+            clicks: 2,
+            max_seen: 5,
+        }
+    );
+}
+
+#[test]
+fn combine_parallel_init() {
+    // Starting from the default state, the merge alone decides the outcome.
+    let reducer = combine_parallel(
+        vec![Box::new(count_clicks), Box::new(track_max)],
+        merge_max,
+        |_: &State| State::default(),
+    );
+
+    let state = State {
+        clicks: 4,
+        max_seen: 9,
+    };
+    assert_eq!(reducer.reduce(&state, Action::Reset), State::default());
+}
+
+#[test]
+fn combine_parallel_empty() {
+    let reducer = combine_parallel(Vec::new(), merge_max, |state: &State| *state);
+
+    let state = State {
+        clicks: 1,
+        max_seen: 2,
+    };
+    assert_eq!(reducer.reduce(&state, Action::Click(3)), state);
+}
@@ -0,0 +1,109 @@
+use redux_rs::{combine_reducers, combine_reducers_first, combine_slices, Reducible, Store};
+
+// Every combinator below runs `add` and `double`, seeing how they compose.
+#[derive(Clone, Copy)]
+enum Action {
+    Add(i8),
+    Double,
+}
+
+fn add(state: &i8, action: Action) -> i8 {
+    match action {
+        Action::Add(value) => state + value,
+        Action::Double => *state,
+    }
+}
+
+fn double(state: &i8, action: Action) -> i8 {
+    match action {
+        Action::Add(_) => state * 2,
+        Action::Double => state * 2,
+    }
+}
+
+fn add_first(state: &i8, action: Action) -> Option<i8> {
+    match action {
+        Action::Add(value) => Some(state + value),
+        Action::Double => None,
+    }
+}
+
+fn double_first(state: &i8, action: Action) -> Option<i8> {
+    match action {
+        Action::Add(_) => Some(state * 2),
+        Action::Double => Some(state * 2),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Slices {
+    added: i8,
+    doubled: i8,
+}
+
+#[test]
+fn chain_feeds_each_output_into_next() {
+    let mut store = Store::new(combine_reducers!(i8, Action, add, double), 1);
+
+    // `double` sees the state produced by `add`.
+    store.dispatch(Action::Add(2));
+    assert_eq!(*store.state(), 6);
+
+    // Every reducer sees the original action.
+    store.dispatch(Action::Double);
+    assert_eq!(*store.state(), 12);
+}
+
+#[test]
+fn chain_order_matters() {
+    let mut store = Store::new(combine_reducers!(i8, Action, double, add), 1);
+
+    store.dispatch(Action::Add(2));
+    assert_eq!(*store.state(), 4);
+}
+
+#[test]
+fn slices_are_isolated() {
+    let mut store = Store::new(
+        combine_slices!(Slices, Action, added: add, doubled: double),
+        Slices {
+            added: 1,
+            doubled: 1,
+        },
+    );
+
+    // Each reducer only sees its own field, not what the other one produced.
+    store.dispatch(Action::Add(2));
+    assert_eq!(
+        *store.state(),
+        Slices {
+            added: 3,
+            doubled: 2
+        }
+    );
+
+    store.dispatch(Action::Double);
+    assert_eq!(
+        *store.state(),
+        Slices {
+            added: 3,
+            doubled: 4
+        }
+    );
+}
+
+#[test]
+fn first_match_stops_at_handling_reducer() {
+    let mut store = Store::new(
+        combine_reducers_first!(i8, Action, add_first, double_first),
+        1,
+    );
+
+    // `add_first` handles the action, so `double_first` does not run.
+    store.dispatch(Action::Add(2));
+    assert_eq!(*store.state(), 3);
+
+    // `add_first` passes, so `double_first` sees the original state.
+    store.dispatch(Action::Double);
+    assert_eq!(*store.state(), 6);
+}
@@ -0,0 +1,66 @@
+use redux_rs::{combine_stores, Either, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Counter = i8;
+
+enum CounterAction {
+    Increment,
+}
+
+fn counter_reducer(state: &Counter, action: CounterAction) -> Counter {
+    match action {
+        CounterAction::Increment => state + 1,
+    }
+}
+
+type Name = &'static str;
+
+enum NameAction {
+    Rename(&'static str),
+}
+
+fn name_reducer(_: &Name, action: NameAction) -> Name {
+    match action {
+        NameAction::Rename(name) => name,
+    }
+}
+
+#[test]
+fn combine_stores_dispatch_each_half() {
+    let counter = Store::new(counter_reducer, 0);
+    let name = Store::new(name_reducer, "initial");
+    let mut store = combine_stores(counter, name);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let captured = seen.clone();
+    store.subscribe(move |state: &(Counter, Name)| captured.borrow_mut().push(*state));
+
+    store.dispatch(Either::Left(CounterAction::Increment));
+    store.dispatch(Either::Right(NameAction::Rename("renamed")));
+    store.dispatch(Either::Left(CounterAction::Increment));
+
+    assert_eq!(*store.state(), (2, "renamed"));
+    assert_eq!(
+        *seen.borrow(),
+        vec![(1, "initial"), (1, "renamed"), (2, "renamed")]
+    );
+}
+
+#[test]
+fn combine_stores_change_detection() {
+    let counter = Store::new(counter_reducer, 0);
+    let name = Store::new_with_change_detection(name_reducer, "initial");
+    let mut store = combine_stores(counter, name);
+
+    let notifications = Rc::new(RefCell::new(0));
+    let captured = notifications.clone();
+    store.subscribe(move |_: &(Counter, Name)| *captured.borrow_mut() += 1);
+
+    store.dispatch(Either::Right(NameAction::Rename("initial")));
+    assert_eq!(*notifications.borrow(), 0);
+
+    store.dispatch(Either::Left(CounterAction::Increment));
+    store.dispatch(Either::Right(NameAction::Rename("renamed")));
+    assert_eq!(*notifications.borrow(), 2);
+}
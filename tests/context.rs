@@ -0,0 +1,48 @@
+use redux_rs::{Extensions, Store};
+
+type State = i8;
+
+enum Action {
+    Step,
+}
+
+struct Config {
+    step: i8,
+}
+
+fn reducer(state: &State, action: Action, config: &Config) -> State {
+    match action {
+        Action::Step => state + config.step,
+    }
+}
+
+#[test]
+fn context_changes_reduction() {
+    let mut store = Store::new_with_context(reducer, 0, Config { step: 1 });
+
+    store.dispatch(Action::Step);
+    assert_eq!(*store.state(), 1);
+
+    let previous = store.set_context(Config { step: 10 });
+    assert_eq!(previous.step, 1);
+
+    store.dispatch(Action::Step);
+    assert_eq!(*store.state(), 11);
+}
+
+#[test]
+fn context_kept_apart_from_extensions() {
+    let mut store = Store::new_with_context(reducer, 0, Config { step: 1 });
+    *store.extensions_mut() = Extensions::new();
+
+    store.set_context(Config { step: 10 });
+    store.dispatch(Action::Step);
+    assert_eq!(*store.state(), 10);
+}
+
+#[test]
+#[should_panic(expected = "store has no context of this type")]
+fn context_missing() {
+    let mut store = Store::new(|state: &State, _: Action| *state, 0);
+    store.set_context(Config { step: 1 });
+}
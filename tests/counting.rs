@@ -0,0 +1,58 @@
+use redux_rs::{CountingMiddleware, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+        Action::Reset => 0,
+    }
+}
+
+fn action_type(action: &Action) -> &'static str {
+    match action {
+        Action::Increment => "increment",
+        Action::Decrement => "decrement",
+        Action::Reset => "reset",
+    }
+}
+
+#[test]
+fn counts_per_action_type() {
+    let counting = CountingMiddleware::new(action_type);
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(counting.clone());
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    store.dispatch(Action::Increment);
+
+    let counts = counting.counts();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["increment"], 3);
+    assert_eq!(counts["decrement"], 1);
+    assert!(!counts.contains_key("reset"));
+    assert_eq!(*store.state(), 2);
+}
+
+#[test]
+fn reset_clears_counts() {
+    let counting = CountingMiddleware::new(action_type);
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(counting.clone());
+
+    store.dispatch(Action::Reset);
+    counting.reset();
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(counting.counts().len(), 1);
+    assert_eq!(counting.counts()["decrement"], 1);
+}
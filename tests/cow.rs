@@ -0,0 +1,54 @@
+use redux_rs::Store;
+use std::borrow::Cow;
+use std::cell::Cell;
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, PartialEq)]
+struct State {
+    counter: i8,
+}
+
+impl Clone for State {
+    fn clone(&self) -> Self {
+        CLONES.with(|clones| clones.set(clones.get() + 1));
+        State {
+            counter: self.counter,
+        }
+    }
+}
+
+enum Action {
+    Increment,
+    Unhandled,
+}
+
+fn reducer(state: &State, action: Action) -> Cow<'_, State> {
+    match action {
+        Action::Increment => Cow::Owned(State {
+            counter: state.counter + 1,
+        }),
+        Action::Unhandled => Cow::Borrowed(state),
+    }
+}
+
+#[test]
+fn cow_unhandled_no_clone() {
+    let mut store = Store::new_cow(reducer, State { counter: 0 });
+    store.dispatch(Action::Unhandled);
+    store.dispatch(Action::Unhandled);
+    assert_eq!(store.state().counter, 0);
+    assert_eq!(CLONES.with(Cell::get), 0);
+}
+
+#[test]
+fn cow_increment() {
+    let mut store = Store::new_cow(reducer, State { counter: 0 });
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Unhandled);
+    store.dispatch(Action::Increment);
+    assert_eq!(store.state().counter, 2);
+    assert_eq!(CLONES.with(Cell::get), 0);
+}
@@ -0,0 +1,101 @@
+use redux_rs::{CrashReport, CrashReporterMiddleware, Store};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Debug)]
+enum Action {
+    Increment,
+    Explode,
+    Reject,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Explode => panic!("boom"),
+        Action::Reject => unreachable!(),
+    }
+}
+
+fn rejecting_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Reject => panic!("rejected"),
+        action => Some(action),
+    }
+}
+
+#[test]
+fn crash_reporter_panicking_reducer() {
+    let reports = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = reports.clone();
+    store.set_crash_reporter(CrashReporterMiddleware::new(
+        move |report: &CrashReport<State>| {
+            captured.borrow_mut().push((
+                *report.state,
+                report.action.clone(),
+                report.message.clone(),
+            ));
+        },
+    ));
+
+    store.dispatch(Action::Increment);
+    assert!(reports.borrow().is_empty());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(Action::Explode)));
+    assert!(result.is_err());
+    assert_eq!(
+        *reports.borrow(),
+        vec![(1, "Explode".to_string(), Some("boom".to_string()))]
+    );
+}
+
+#[test]
+fn crash_reporter_backtrace_points_at_panic() {
+    let backtraces = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = backtraces.clone();
+    store.set_crash_reporter(CrashReporterMiddleware::new(
+        move |report: &CrashReport<State>| {
+            captured.borrow_mut().push(report.backtrace.to_string());
+        },
+    ));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(Action::Explode)));
+    assert!(result.is_err());
+    assert!(backtraces.borrow()[0].contains("crash_reporter::reducer"));
+}
+
+#[test]
+fn crash_reporter_panicking_middleware() {
+    let reports = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(rejecting_middleware);
+    let captured = reports.clone();
+    store.set_crash_reporter(CrashReporterMiddleware::new(
+        move |report: &CrashReport<State>| {
+            captured.borrow_mut().push((
+                *report.state,
+                report.action.clone(),
+                report.message.clone(),
+            ));
+        },
+    ));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(Action::Reject)));
+    assert!(result.is_err());
+    assert_eq!(
+        *reports.borrow(),
+        vec![(0, "Reject".to_string(), Some("rejected".to_string()))]
+    );
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+    assert_eq!(reports.borrow().len(), 1);
+}
@@ -0,0 +1,39 @@
+use redux_rs::{CrashReport, CrashReporterMiddleware, Store};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+type State = i8;
+
+#[derive(Debug)]
+enum Action {
+    Explode,
+}
+
+fn reducer(_: &State, action: Action) -> State {
+    match action {
+        Action::Explode => panic!("boom"),
+    }
+}
+
+#[test]
+fn crash_reporter_chains_to_previous_hook() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let hook_calls = calls.clone();
+    panic::set_hook(Box::new(move |_| {
+        hook_calls.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    let mut store = Store::new(reducer, 0);
+    store.set_crash_reporter(CrashReporterMiddleware::new(|_: &CrashReport<State>| {}));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(Action::Explode)));
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let result = panic::catch_unwind(|| panic!("outside"));
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let _ = panic::take_hook();
+}
@@ -0,0 +1,85 @@
+use redux_rs::{define_store, Store};
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    counter: i8,
+    label: &'static str,
+}
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Add(i8),
+    Rename { label: &'static str },
+}
+
+define_store! {
+    mod generated {
+        state: State = State { counter: 0, label: "initial" },
+        action: Action,
+        handlers {
+            Increment => |state| State {
+                counter: state.counter + 1,
+                ..state.clone()
+            },
+            Add(value) => |state| State {
+                counter: state.counter + value,
+                ..state.clone()
+            },
+            Rename { label } => |state| State {
+                label,
+                ..state.clone()
+            },
+        }
+    }
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => State {
+            counter: state.counter + 1,
+            ..state.clone()
+        },
+        Action::Add(value) => State {
+            counter: state.counter + value,
+            ..state.clone()
+        },
+        Action::Rename { label } => State {
+            label,
+            ..state.clone()
+        },
+    }
+}
+
+#[test]
+fn define_store_matches_hand_written() {
+    let mut generated = generated::make_store();
+    let mut hand_written = Store::new(
+        reducer,
+        State {
+            counter: 0,
+            label: "initial",
+        },
+    );
+    assert_eq!(generated.state(), hand_written.state());
+
+    let actions = [
+        Action::Increment,
+        Action::Add(4),
+        Action::Rename { label: "renamed" },
+        Action::Add(-2),
+    ];
+    for action in actions.iter() {
+        generated.dispatch(action.clone());
+        hand_written.dispatch(action.clone());
+        assert_eq!(generated.state(), hand_written.state());
+    }
+
+    assert_eq!(
+        *generated.state(),
+        State {
+            counter: 3,
+            label: "renamed"
+        }
+    );
+}
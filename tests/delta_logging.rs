@@ -0,0 +1,46 @@
+use redux_rs::{with_delta_logging_to, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+    Nothing,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Nothing => *state,
+    }
+}
+
+fn logged_store() -> (Store<State, Action>, Rc<RefCell<Vec<String>>>) {
+    let deltas = Rc::new(RefCell::new(Vec::new()));
+    let logged = deltas.clone();
+
+    let reducer = with_delta_logging_to(reducer, move |delta: &str| {
+        logged.borrow_mut().push(delta.to_string())
+    });
+    (Store::new(reducer, 0), deltas)
+}
+
+#[test]
+fn delta_logging_mutating_action() {
+    let (mut store, deltas) = logged_store();
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(*deltas.borrow(), vec!["Increment: 0 -> 1"]);
+}
+
+#[test]
+fn delta_logging_noop_action() {
+    let (mut store, deltas) = logged_store();
+    store.dispatch(Action::Nothing);
+
+    assert_eq!(*store.state(), 0);
+    assert!(deltas.borrow().is_empty());
+}
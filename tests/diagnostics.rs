@@ -0,0 +1,86 @@
+#![cfg(feature = "log")]
+
+use redux_rs::{LogLevel, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Ignored,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Ignored => *state,
+    }
+}
+
+fn ignore_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Ignored => None,
+    }
+}
+
+type Messages = Rc<RefCell<Vec<(LogLevel, String)>>>;
+
+fn logged_store() -> (Store<State, Action>, Messages) {
+    let messages = Rc::new(RefCell::new(Vec::new()));
+    let logged = messages.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(ignore_middleware);
+    store.subscribe(|_: &State| {});
+    store.subscribe(|_: &State| {});
+    store.set_diagnostics_logger(move |level: LogLevel, message: &str| {
+        logged.borrow_mut().push((level, message.to_string()))
+    });
+    (store, messages)
+}
+
+#[test]
+fn diagnostics_dispatch() {
+    let (mut store, messages) = logged_store();
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        *messages.borrow(),
+        vec![
+            (LogLevel::Trace, "action dispatched".to_string()),
+            (LogLevel::Trace, "reducer ran".to_string()),
+            (LogLevel::Trace, "2 subscribers notified".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_halted() {
+    let (mut store, messages) = logged_store();
+    store.dispatch(Action::Ignored);
+
+    assert_eq!(
+        *messages.borrow(),
+        vec![
+            (LogLevel::Trace, "action dispatched".to_string()),
+            (
+                LogLevel::Debug,
+                "middleware 0 halted the action".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_counts_notified_subscribers() {
+    let (mut store, messages) = logged_store();
+    store.subscribe_selector(|state: &State| *state > 5, |_: &bool| {});
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        messages.borrow().last(),
+        Some(&(LogLevel::Trace, "2 subscribers notified".to_string()))
+    );
+}
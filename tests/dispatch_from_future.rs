@@ -0,0 +1,53 @@
+#![cfg(all(feature = "async", feature = "test-util"))]
+
+use redux_rs::test::TestScheduler;
+use redux_rs::{Store, TaskFuture};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Loaded(i8),
+}
+
+fn reducer(_: &State, action: Action) -> State {
+    match action {
+        Action::Loaded(value) => value,
+    }
+}
+
+#[test]
+fn dispatch_from_future_on_completion() {
+    let scheduler = TestScheduler::new();
+    let store = Rc::new(RefCell::new(Store::new(reducer, 0)));
+
+    let sleep = scheduler.sleep(Duration::from_secs(1));
+    let computation = async move {
+        sleep.await;
+        Action::Loaded(6 * 7)
+    };
+    Store::dispatch_from_future(&store, computation, |task: TaskFuture| {
+        scheduler.spawn(task)
+    });
+
+    scheduler.run_until_stalled();
+    assert_eq!(*store.borrow().state(), 0);
+
+    scheduler.advance(Duration::from_secs(1));
+    assert_eq!(*store.borrow().state(), 42);
+}
+
+#[test]
+fn dispatch_from_future_store_dropped() {
+    let scheduler = TestScheduler::new();
+    let store = Rc::new(RefCell::new(Store::new(reducer, 0)));
+
+    Store::dispatch_from_future(&store, async { Action::Loaded(1) }, |task: TaskFuture| {
+        scheduler.spawn(task)
+    });
+    drop(store);
+
+    scheduler.run_until_stalled();
+}
@@ -0,0 +1,32 @@
+use redux_rs::Store;
+
+type State = i8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[test]
+fn dispatch_ref_same_action_twice() {
+    let mut store = Store::new(reducer, 0);
+
+    let increment = Action::Increment;
+    store.dispatch_ref(&increment);
+    store.dispatch_ref(&increment);
+    assert_eq!(*store.state(), 2);
+
+    let actions = [Action::Decrement, Action::Decrement, Action::Increment];
+    for action in actions.iter() {
+        store.dispatch_ref(action);
+    }
+    assert_eq!(*store.state(), 1);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "async")]
+
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Double,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Double => state * 2,
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn dispatch_stream_until_end() {
+    let mut actions = vec![
+        Action::Increment,
+        Action::Increment,
+        Action::Double,
+        Action::Increment,
+    ]
+    .into_iter();
+
+    // Every other poll is pending, like a source waiting for data.
+    let mut ready = false;
+    let stream = move |cx: &mut Context<'_>| {
+        ready = !ready;
+        if ready {
+            Poll::Ready(actions.next())
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    };
+
+    let mut store = Store::new(reducer, 0);
+    block_on(store.dispatch_stream(stream));
+
+    assert_eq!(*store.state(), 5);
+}
+
+#[test]
+fn dispatch_stream_runs_async_middleware() {
+    let seen = Rc::new(RefCell::new(0));
+    let captured = seen.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(move |_: &Store<State, Action>, action: Action| {
+        *captured.borrow_mut() += 1;
+        async move { Some(action) }
+    });
+
+    let mut actions = vec![Action::Increment, Action::Increment].into_iter();
+    block_on(store.dispatch_stream(move |_: &mut Context<'_>| Poll::Ready(actions.next())));
+
+    assert_eq!(*store.state(), 2);
+    assert_eq!(*seen.borrow(), 2);
+}
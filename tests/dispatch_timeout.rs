@@ -0,0 +1,155 @@
+#![cfg(feature = "async")]
+
+use redux_rs::{DispatchTimeout, Store, StoreError};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Future resolving after sleeping on another thread.
+struct Sleep {
+    done: Arc<AtomicBool>,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+fn sleep(duration: Duration) -> Sleep {
+    let done = Arc::new(AtomicBool::new(false));
+    let waker: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
+    let (thread_done, thread_waker) = (done.clone(), waker.clone());
+    thread::spawn(move || {
+        thread::sleep(duration);
+        thread_done.store(true, Ordering::SeqCst);
+        if let Some(waker) = thread_waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    });
+    Sleep { done, waker }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+        if self.done.load(Ordering::SeqCst) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn dispatch_async_timeout_completes() {
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(|_: &Store<State, Action>, action: Action| async move {
+        sleep(Duration::from_millis(10)).await;
+        Some(action)
+    });
+
+    let result = block_on(store.dispatch_async_timeout(Action::Increment, Duration::from_secs(5)));
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn dispatch_async_timeout_elapses() {
+    let mut store = Store::new(reducer, 0);
+    store.add_async_middleware(|_: &Store<State, Action>, action: Action| async move {
+        sleep(Duration::from_millis(500)).await;
+        Some(action)
+    });
+
+    let result =
+        block_on(store.dispatch_async_timeout(Action::Increment, Duration::from_millis(20)));
+
+    assert_eq!(result, Err(DispatchTimeout));
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn dispatch_async_runs_middleware_in_order() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+    store.add_async_middleware(|_: &Store<State, Action>, _: Action| async move {
+        panic!("async middleware must not run after a halt")
+    });
+
+    block_on(store.dispatch_async(Action::Increment));
+
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn dispatch_async_errors_reach_error_handler() {
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let captured = errors.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.set_global_error_handler(move |error: &StoreError| {
+        captured.borrow_mut().push(error.clone())
+    });
+    store.add_async_middleware(|_: &Store<State, Action>, action: Action| async move {
+        sleep(Duration::from_millis(500)).await;
+        Some(action)
+    });
+    store.add_async_middleware(|store: &Store<State, Action>, _: Action| {
+        let halted = store.halt_with_reason("rejected");
+        async move { halted }
+    });
+
+    let result =
+        block_on(store.dispatch_async_timeout(Action::Increment, Duration::from_millis(20)));
+    assert_eq!(result, Err(DispatchTimeout));
+
+    let result = block_on(store.dispatch_async_timeout(Action::Increment, Duration::from_secs(5)));
+    assert_eq!(result, Ok(()));
+
+    assert_eq!(
+        *errors.borrow(),
+        vec![
+            StoreError::Timeout,
+            StoreError::AsyncMiddlewareHalted {
+                reason: "rejected".to_string()
+            }
+        ]
+    );
+}
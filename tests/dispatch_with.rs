@@ -0,0 +1,53 @@
+use redux_rs::Store;
+
+#[derive(Debug, PartialEq)]
+struct State {
+    enabled: bool,
+    toggles: u8,
+}
+
+enum Action {
+    Enable,
+    Disable,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    State {
+        enabled: match action {
+            Action::Enable => true,
+            Action::Disable => false,
+        },
+        toggles: state.toggles + 1,
+    }
+}
+
+fn toggle(state: &State) -> Action {
+    if state.enabled {
+        Action::Disable
+    } else {
+        Action::Enable
+    }
+}
+
+#[test]
+fn dispatch_with_toggle() {
+    let mut store = Store::new(
+        reducer,
+        State {
+            enabled: false,
+            toggles: 0,
+        },
+    );
+
+    store.dispatch_with(toggle);
+    assert!(store.state().enabled);
+
+    store.dispatch_with(toggle);
+    assert_eq!(
+        *store.state(),
+        State {
+            enabled: false,
+            toggles: 2
+        }
+    );
+}
@@ -0,0 +1,67 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct State {
+    counter: i8,
+    name: &'static str,
+}
+
+enum Action {
+    Increment,
+    Rename(&'static str),
+    Nothing,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => State {
+            counter: state.counter + 1,
+            ..*state
+        },
+        Action::Rename(name) => State { name, ..*state },
+        Action::Nothing => *state,
+    }
+}
+
+#[test]
+fn dispatch_with_report_selector() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let captured = seen.clone();
+
+    let mut store = Store::new(reducer, State::default());
+    let all = store.subscribe(|_: &State| {});
+    let counter = store.subscribe_selector(
+        |state: &State| state.counter,
+        move |counter: &i8| captured.borrow_mut().push(*counter),
+    );
+
+    assert_eq!(
+        store.dispatch_with_report(Action::Increment),
+        vec![all, counter]
+    );
+    assert_eq!(
+        store.dispatch_with_report(Action::Rename("renamed")),
+        vec![all]
+    );
+    assert_eq!(*seen.borrow(), vec![1]);
+}
+
+#[test]
+fn dispatch_with_report_noop() {
+    let mut store = Store::new_with_change_detection(reducer, State::default());
+    store.subscribe(|_: &State| {});
+    store.subscribe_selector(|state: &State| state.counter, |_: &i8| {});
+
+    assert!(store.dispatch_with_report(Action::Nothing).is_empty());
+}
+
+#[test]
+fn dispatch_with_report_halted() {
+    let mut store = Store::new(reducer, State::default());
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+    store.subscribe(|_: &State| {});
+
+    assert!(store.dispatch_with_report(Action::Increment).is_empty());
+}
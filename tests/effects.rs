@@ -0,0 +1,63 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Save,
+    Increment,
+}
+
+#[derive(Debug, PartialEq)]
+enum Effect {
+    Persist(State),
+    Toast(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Save => *state,
+        Action::Increment => state + 1,
+    }
+}
+
+fn effects_middleware(store: &Store<State, Action>, action: Action) -> Option<Action> {
+    if let Action::Save = action {
+        store.emit(Effect::Persist(*store.state()));
+        store.emit(Effect::Toast("saved"));
+        store.emit("not an effect of this type");
+    }
+    Some(action)
+}
+
+#[test]
+fn dispatch_collecting_two_effects() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(effects_middleware);
+    store.dispatch(Action::Increment);
+
+    let effects = store.dispatch_collecting_effects::<Effect>(Action::Save);
+    assert_eq!(effects, vec![Effect::Persist(1), Effect::Toast("saved")]);
+
+    assert!(store
+        .dispatch_collecting_effects::<Effect>(Action::Increment)
+        .is_empty());
+}
+
+#[test]
+fn subscribe_effects_receives() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(effects_middleware);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let subscriber_seen = seen.clone();
+    store.subscribe_effects(move |effect: &Effect| {
+        if let Effect::Toast(message) = effect {
+            subscriber_seen.borrow_mut().push(*message);
+        }
+    });
+
+    store.dispatch(Action::Save);
+    assert_eq!(*seen.borrow(), vec!["saved"]);
+}
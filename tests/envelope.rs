@@ -0,0 +1,100 @@
+use redux_rs::{enveloped, ActionEnvelope, Middleware, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+// Persists every action, unless it is being replayed.
+struct PersistMiddleware {
+    persisted: Rc<RefCell<Vec<Action>>>,
+}
+
+impl Middleware<State, ActionEnvelope<Action>> for PersistMiddleware {
+    fn next(
+        &self,
+        _: &Store<State, ActionEnvelope<Action>>,
+        envelope: ActionEnvelope<Action>,
+    ) -> Option<ActionEnvelope<Action>> {
+        if envelope.meta("source") != Some("replay") {
+            self.persisted.borrow_mut().push(envelope.action().clone());
+        }
+        Some(envelope)
+    }
+}
+
+#[test]
+fn envelope_persistence_skips_replay() {
+    let persisted = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(enveloped(reducer), 0);
+    store.add_middleware(PersistMiddleware {
+        persisted: persisted.clone(),
+    });
+
+    store.dispatch(Action::Increment.into());
+    store.dispatch(ActionEnvelope::new(Action::Increment).with_meta("source", "replay"));
+    store.dispatch(ActionEnvelope::new(Action::Decrement).with_meta("source", "user"));
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(
+        *persisted.borrow(),
+        vec![Action::Increment, Action::Decrement]
+    );
+}
+
+#[test]
+fn envelope_middleware_modifies_meta() {
+    let persisted = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(enveloped(reducer), 0);
+    store.add_middleware(
+        |_: &Store<State, ActionEnvelope<Action>>, mut envelope: ActionEnvelope<Action>| {
+            envelope
+                .meta_mut()
+                .insert("source".to_string(), "replay".to_string());
+            Some(envelope)
+        },
+    );
+    store.add_middleware(PersistMiddleware {
+        persisted: persisted.clone(),
+    });
+
+    store.dispatch(Action::Increment.into());
+
+    assert_eq!(*store.state(), 1);
+    assert!(persisted.borrow().is_empty());
+}
+
+#[test]
+fn envelope_observed_by_inner_action() {
+    let observed = Rc::new(RefCell::new(Vec::new()));
+    let captured = observed.clone();
+
+    let mut store = Store::new(enveloped(reducer), 0);
+    store.on_action(
+        |envelope: &ActionEnvelope<Action>| *envelope.inner() == Action::Decrement,
+        move |envelope: &ActionEnvelope<Action>| {
+            captured
+                .borrow_mut()
+                .push(envelope.meta("source").map(str::to_string))
+        },
+    );
+
+    store.dispatch(Action::Increment.into());
+    store.dispatch(ActionEnvelope::new(Action::Decrement).with_meta("source", "keyboard"));
+
+    assert_eq!(*observed.borrow(), vec![Some("keyboard".to_string())]);
+}
@@ -0,0 +1,117 @@
+use redux_rs::{Store, StoreError, SubscriptionPanicPolicy};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Forbidden,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Forbidden => unreachable!(),
+    }
+}
+
+fn guard_middleware(store: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Forbidden => store.halt_with_reason("forbidden"),
+        action => Some(action),
+    }
+}
+
+fn filter_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Forbidden => None,
+        action => Some(action),
+    }
+}
+
+fn checked_reducer(state: &State, action: Action) -> Result<State, &'static str> {
+    match action {
+        Action::Increment => state.checked_add(1).ok_or("overflow"),
+        Action::Forbidden => Err("forbidden"),
+    }
+}
+
+fn collecting_store() -> (Store<State, Action>, Rc<RefCell<Vec<StoreError>>>) {
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let captured = errors.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.set_global_error_handler(move |error: &StoreError| {
+        captured.borrow_mut().push(error.clone())
+    });
+    (store, errors)
+}
+
+#[test]
+fn error_handler_receives_different_kinds() {
+    let (mut store, errors) = collecting_store();
+    store.add_middleware(guard_middleware);
+
+    store.dispatch(Action::Forbidden);
+    assert_eq!(
+        store.try_reduce(checked_reducer, Action::Forbidden),
+        Err("forbidden")
+    );
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        *errors.borrow(),
+        vec![
+            StoreError::MiddlewareHalted {
+                index: 0,
+                reason: "forbidden".to_string(),
+            },
+            StoreError::Reducer("forbidden".to_string()),
+        ]
+    );
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn error_handler_receives_subscription_panics() {
+    let (mut store, errors) = collecting_store();
+    store.set_subscription_panic_policy(SubscriptionPanicPolicy::Isolate);
+    store.subscribe(|_: &State| panic!("boom"));
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        *errors.borrow(),
+        vec![StoreError::SubscriptionPanicked {
+            index: 0,
+            message: "boom".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn try_reduce_success() {
+    let (mut store, errors) = collecting_store();
+    let notified = Rc::new(RefCell::new(Vec::new()));
+    let captured = notified.clone();
+    store.subscribe(move |state: &State| captured.borrow_mut().push(*state));
+
+    assert_eq!(store.try_reduce(checked_reducer, Action::Increment), Ok(()));
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(*notified.borrow(), vec![1]);
+    assert!(errors.borrow().is_empty());
+}
+
+#[test]
+fn error_handler_ignores_plain_halts() {
+    let (mut store, errors) = collecting_store();
+    store.add_middleware(filter_middleware);
+
+    store.dispatch(Action::Forbidden);
+    store.dispatch(Action::Increment);
+
+    assert!(errors.borrow().is_empty());
+    assert_eq!(*store.state(), 1);
+}
@@ -0,0 +1,59 @@
+use redux_rs::{exhaustive_reducer, Store};
+
+#[derive(Debug, PartialEq)]
+struct State {
+    counter: i8,
+    label: &'static str,
+}
+
+enum Action {
+    Increment,
+    Add(i8),
+    Rename { label: &'static str },
+    Reset(i8, &'static str),
+}
+
+exhaustive_reducer! {
+    fn reducer(state: &State, action: Action) -> State {
+        Action::Increment => State {
+            counter: state.counter + 1,
+            label: state.label,
+        },
+        Action::Add(value) => State {
+            counter: state.counter + value,
+            label: state.label,
+        },
+        Action::Rename { label } => State {
+            counter: state.counter,
+            label,
+        },
+        Action::Reset(counter, label) => State { counter, label },
+    }
+}
+
+#[test]
+fn exhaustive_reducer_all_variants() {
+    let mut store = Store::new(
+        reducer,
+        State {
+            counter: 0,
+            label: "initial",
+        },
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Add(4));
+    assert_eq!(store.state().counter, 5);
+
+    store.dispatch(Action::Rename { label: "renamed" });
+    assert_eq!(store.state().label, "renamed");
+
+    store.dispatch(Action::Reset(-1, "reset"));
+    assert_eq!(
+        *store.state(),
+        State {
+            counter: -1,
+            label: "reset"
+        }
+    );
+}
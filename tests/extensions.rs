@@ -0,0 +1,64 @@
+use redux_rs::{Extensions, Store};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+struct Counter(u32);
+
+fn counting_middleware(store: &Store<State, Action>, action: Action) -> Option<Action> {
+    let mut extensions = store.extensions_mut();
+    match extensions.get_mut::<Counter>() {
+        Some(counter) => counter.0 += 1,
+        None => {
+            extensions.insert(Counter(1));
+        }
+    }
+    Some(action)
+}
+
+#[test]
+fn extensions_shared_between_middleware() {
+    let seen = Rc::new(Cell::new(0));
+    let read = seen.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(counting_middleware);
+    store.add_middleware(move |store: &Store<State, Action>, action: Action| {
+        read.set(
+            store
+                .extensions()
+                .get::<Counter>()
+                .map_or(0, |counter| counter.0),
+        );
+        Some(action)
+    });
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(seen.get(), 2);
+}
+
+#[test]
+fn extensions_insert_replace_remove() {
+    let mut extensions = Extensions::new();
+
+    assert_eq!(extensions.insert(1u8), None);
+    assert_eq!(extensions.insert("name"), None);
+    assert_eq!(extensions.insert(2u8), Some(1));
+
+    assert_eq!(extensions.get::<u8>(), Some(&2));
+    assert_eq!(extensions.remove::<&str>(), Some("name"));
+    assert_eq!(extensions.get::<&str>(), None);
+}
@@ -0,0 +1,62 @@
+use redux_rs::{Middleware, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn logging(log: &Log, label: &'static str) -> Box<dyn Middleware<State, Action>> {
+    let log = log.clone();
+    Box::new(move |_: &Store<State, Action>, action: Action| {
+        log.borrow_mut().push(label);
+        Some(action)
+    })
+}
+
+mod auth {
+    use super::*;
+
+    pub fn middleware(log: &Log) -> impl Fn() -> Vec<Box<dyn Middleware<State, Action>>> + '_ {
+        move || vec![logging(log, "auth: session"), logging(log, "auth: token")]
+    }
+}
+
+mod analytics {
+    use super::*;
+
+    pub fn middleware(log: &Log) -> impl Fn() -> Vec<Box<dyn Middleware<State, Action>>> + '_ {
+        move || vec![logging(log, "analytics: track")]
+    }
+}
+
+#[test]
+fn feature_middleware_runs_in_order() {
+    let log = Log::default();
+
+    let mut store = Store::new(reducer, 0);
+    let auth_ids = store.add_feature_middleware(auth::middleware(&log));
+    let analytics_ids = store.add_feature_middleware(analytics::middleware(&log));
+
+    assert_eq!(auth_ids.len(), 2);
+    assert_eq!(analytics_ids.len(), 1);
+    assert_eq!(store.middleware_ids(), [auth_ids, analytics_ids].concat());
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(
+        *log.borrow(),
+        vec!["auth: session", "auth: token", "analytics: track"]
+    );
+}
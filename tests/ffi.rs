@@ -0,0 +1,58 @@
+#![cfg(feature = "ffi")]
+
+use redux_rs::ffi::*;
+use std::ffi::c_void;
+use std::ptr;
+
+extern "C" fn reducer(state: i64, action: i64) -> i64 {
+    state + action
+}
+
+extern "C" fn record_state(state: i64, user_data: *mut c_void) {
+    let states = unsafe { &mut *(user_data as *mut Vec<i64>) };
+    states.push(state);
+}
+
+#[test]
+fn ffi_dispatch_and_get_state() {
+    unsafe {
+        let store = redux_store_new(reducer, 1);
+        assert_eq!(redux_store_get_state(store), 1);
+
+        redux_store_dispatch(store, 2);
+        redux_store_dispatch(store, -5);
+        assert_eq!(redux_store_get_state(store), -2);
+
+        redux_store_free(store);
+    }
+}
+
+#[test]
+fn ffi_subscribe() {
+    let mut states: Vec<i64> = Vec::new();
+
+    unsafe {
+        let store = redux_store_new(reducer, 0);
+        redux_store_subscribe(
+            store,
+            record_state,
+            &mut states as *mut Vec<i64> as *mut c_void,
+        );
+
+        redux_store_dispatch(store, 3);
+        redux_store_dispatch(store, 4);
+
+        redux_store_free(store);
+    }
+
+    assert_eq!(states, vec![3, 7]);
+}
+
+#[test]
+fn ffi_null_store() {
+    unsafe {
+        redux_store_dispatch(ptr::null_mut(), 1);
+        assert_eq!(redux_store_get_state(ptr::null()), 0);
+        redux_store_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,101 @@
+use redux_rs::{Store, Subscription};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[derive(Clone)]
+struct Recorder {
+    seen: Rc<RefCell<Vec<State>>>,
+}
+
+impl Subscription<State> for Recorder {
+    fn update(&self, state: &State) {
+        self.seen.borrow_mut().push(*state);
+    }
+}
+
+#[test]
+fn fork_starts_from_current_state() {
+    let mut store = Store::new(reducer, 0);
+    store.dispatch(Action::Increment);
+
+    let mut fork = store.fork();
+    fork.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(*store.state(), 0);
+    assert_eq!(*fork.state(), 2);
+}
+
+#[test]
+fn fork_with_subscriptions_carries_cloneable_ones() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let plain = Rc::new(RefCell::new(0));
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_cloneable(Recorder { seen: seen.clone() });
+    let captured = plain.clone();
+    store.subscribe(move |_: &State| *captured.borrow_mut() += 1);
+
+    let mut fork = store.fork_with_subscriptions();
+
+    store.dispatch(Action::Increment);
+    fork.dispatch(Action::Decrement);
+    fork.dispatch(Action::Decrement);
+
+    assert_eq!(*seen.borrow(), vec![1, -1, -2]);
+    assert_eq!(*plain.borrow(), 1);
+}
+
+#[test]
+fn fork_with_subscriptions_skips_unsubscribed() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let id = store.subscribe_cloneable(Recorder { seen: seen.clone() });
+    store.unsubscribe(id);
+
+    let mut fork = store.fork_with_subscriptions();
+    fork.dispatch(Action::Increment);
+
+    assert!(seen.borrow().is_empty());
+}
+
+#[test]
+fn unsubscribe_releases_cloneable() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let id = store.subscribe_cloneable(Recorder { seen: seen.clone() });
+    store.unsubscribe(id);
+
+    assert_eq!(Rc::strong_count(&seen), 1);
+}
+
+#[test]
+fn set_subscriptions_releases_cloneable() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_cloneable(Recorder { seen: seen.clone() });
+    store.set_subscriptions(Vec::new());
+
+    let mut fork = store.fork_with_subscriptions();
+    fork.dispatch(Action::Increment);
+
+    assert_eq!(Rc::strong_count(&seen), 1);
+    assert!(seen.borrow().is_empty());
+}
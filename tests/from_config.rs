@@ -0,0 +1,90 @@
+use redux_rs::{ConfigError, Store};
+use std::io::{self, Read};
+
+#[derive(Debug, PartialEq)]
+struct State {
+    volume: u8,
+    muted: bool,
+}
+
+struct Config {
+    volume: u8,
+}
+
+fn reducer(state: &State, muted: bool) -> State {
+    State {
+        volume: state.volume,
+        muted,
+    }
+}
+
+// Parses `key = value` lines, only knowing about `volume`.
+fn parse(text: &str) -> Result<Config, String> {
+    let mut volume = None;
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        match line
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+        {
+            Some(("volume", value)) => volume = Some(value.parse().map_err(|_| "bad volume")?),
+            _ => return Err(format!("unexpected line `{}`", line)),
+        }
+    }
+    Ok(Config {
+        volume: volume.ok_or("missing volume")?,
+    })
+}
+
+fn build(config: Config) -> State {
+    State {
+        volume: config.volume,
+        muted: false,
+    }
+}
+
+#[test]
+fn from_config_builds_initial_state() {
+    let config = "\nvolume = 7\n";
+
+    let mut store = Store::from_config(reducer, config.as_bytes(), parse, build).unwrap();
+    assert_eq!(
+        *store.state(),
+        State {
+            volume: 7,
+            muted: false
+        }
+    );
+
+    store.dispatch(true);
+    assert!(store.state().muted);
+}
+
+#[test]
+fn from_config_parse_error() {
+    let result = Store::from_config(reducer, "volume = loud".as_bytes(), parse, build);
+
+    match result {
+        Err(ConfigError::Parse(error)) => assert_eq!(error, "bad volume"),
+        _ => panic!("expected a parse error"),
+    }
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk on fire"))
+    }
+}
+
+#[test]
+fn from_config_io_error() {
+    let result = Store::from_config(reducer, FailingReader, parse, build);
+
+    match result {
+        Err(error @ ConfigError::Io(_)) => {
+            assert_eq!(error.to_string(), "cannot read configuration: disk on fire")
+        }
+        _ => panic!("expected an io error"),
+    }
+}
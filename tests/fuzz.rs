@@ -0,0 +1,62 @@
+#![cfg(feature = "test-util")]
+
+use redux_rs::test::{fuzz_dispatch, Arbitrary, Rng, XorShift};
+use redux_rs::Store;
+
+type State = i8;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+impl Arbitrary for Action {
+    fn arbitrary(rng: &mut dyn Rng) -> Self {
+        match rng.next_u64() % 2 {
+            0 => Action::Increment,
+            _ => Action::Decrement,
+        }
+    }
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state.saturating_add(1),
+        Action::Decrement => state.saturating_sub(1),
+    }
+}
+
+fn unbounded_reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state.wrapping_add(1),
+        Action::Decrement => state.wrapping_sub(1),
+    }
+}
+
+#[test]
+fn fuzz_saturating_reducer() {
+    let mut store = Store::new(reducer, 0);
+    fuzz_dispatch(&mut store, 10_000, &mut XorShift::new(1), |_: &State| true);
+}
+
+#[test]
+#[should_panic(expected = "invariant violated")]
+fn fuzz_invariant_violated() {
+    let mut store = Store::new(unbounded_reducer, 0);
+    fuzz_dispatch(
+        &mut store,
+        10_000,
+        &mut XorShift::new(1),
+        |state: &State| (-3..=3).contains(state),
+    );
+}
+
+#[test]
+fn fuzz_deterministic_seed() {
+    let mut first = XorShift::new(5);
+    let mut second = XorShift::new(5);
+    for _ in 0..100 {
+        assert_eq!(first.next_u64(), second.next_u64());
+    }
+}
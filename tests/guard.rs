@@ -0,0 +1,35 @@
+use redux_rs::{guard_reducer, Store};
+
+type State = i8;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+fn non_negative(state: &State) -> bool {
+    *state >= 0
+}
+
+#[test]
+fn guard_reducer_holds() {
+    let mut store = Store::new(guard_reducer(reducer, non_negative), 0);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+#[should_panic(expected = "invariant violated after reducing Decrement")]
+fn guard_reducer_violated() {
+    let mut store = Store::new(guard_reducer(reducer, non_negative), 0);
+    store.dispatch(Action::Decrement);
+}
@@ -0,0 +1,44 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Not `PartialEq`, but hashable.
+#[derive(Clone, Hash)]
+struct State {
+    tags: Vec<&'static str>,
+}
+
+enum Action {
+    Tag(&'static str),
+    Nothing,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Tag(tag) => {
+            let mut tags = state.tags.clone();
+            tags.push(tag);
+            State { tags }
+        }
+        Action::Nothing => state.clone(),
+    }
+}
+
+#[test]
+fn hash_change_detection_skips_no_op() {
+    let notifications = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new_with_hash_change_detection(reducer, State { tags: Vec::new() });
+    let captured = notifications.clone();
+    store.subscribe(move |state: &State| captured.borrow_mut().push(state.tags.len()));
+
+    store.dispatch(Action::Nothing);
+    assert!(notifications.borrow().is_empty());
+
+    store.dispatch(Action::Tag("rust"));
+    store.dispatch(Action::Nothing);
+    store.dispatch(Action::Tag("redux"));
+
+    assert_eq!(*notifications.borrow(), vec![1, 2]);
+    assert_eq!(store.state().tags, vec!["rust", "redux"]);
+}
@@ -0,0 +1,92 @@
+use redux_rs::{HistoryStore, ManualClock, Store};
+use std::time::Duration;
+
+type State = String;
+
+fn reducer(state: &State, character: char) -> State {
+    let mut text = state.clone();
+    text.push(character);
+    text
+}
+
+fn history() -> (HistoryStore<State, char>, ManualClock) {
+    let clock = ManualClock::new();
+    let mut history = HistoryStore::new(Store::new(reducer, String::new()));
+    history.set_coalescing_window(Duration::from_millis(500));
+    history.set_clock(clock.clone());
+    (history, clock)
+}
+
+#[test]
+fn history_coalesces_rapid_edits() {
+    let (mut history, clock) = history();
+
+    history.dispatch('a');
+    clock.advance(Duration::from_millis(100));
+    history.dispatch('b');
+    clock.advance(Duration::from_millis(100));
+    history.dispatch('c');
+    assert_eq!(history.undo_len(), 1);
+
+    clock.advance(Duration::from_secs(2));
+    history.dispatch('d');
+    assert_eq!(history.undo_len(), 2);
+    assert_eq!(history.state(), "abcd");
+
+    assert!(history.undo());
+    assert_eq!(history.state(), "abc");
+    assert!(history.undo());
+    assert_eq!(history.state(), "");
+    assert!(!history.undo());
+}
+
+#[test]
+fn history_undo_boundary() {
+    let (mut history, _) = history();
+
+    history.dispatch('a');
+    history.mark_undo_boundary();
+    history.dispatch('b');
+    assert_eq!(history.undo_len(), 2);
+
+    history.undo();
+    assert_eq!(history.state(), "a");
+    history.redo();
+    assert_eq!(history.state(), "ab");
+    assert_eq!(history.redo_len(), 0);
+}
+
+#[test]
+fn history_limit() {
+    let (mut history, _) = history();
+    history.set_limit(2);
+
+    for character in "abcd".chars() {
+        history.dispatch(character);
+        history.mark_undo_boundary();
+    }
+    assert_eq!(history.undo_len(), 2);
+
+    history.undo();
+    history.undo();
+    assert_eq!(history.state(), "ab");
+}
+
+#[test]
+fn history_skips_halted_actions() {
+    let (mut history, _) = history();
+    history.record_actions();
+    history
+        .store_mut()
+        .add_middleware(|_: &Store<State, char>, character: char| {
+            Some(character).filter(|character| *character != '!')
+        });
+
+    history.dispatch('a');
+    history.mark_undo_boundary();
+    history.undo();
+    history.dispatch('!');
+    assert_eq!(history.undo_len(), 0);
+    assert_eq!(history.redo_len(), 1);
+    assert_eq!(history.actions(), ['a']);
+}
@@ -0,0 +1,37 @@
+use redux_rs::Store;
+
+#[derive(Debug, PartialEq)]
+struct State {
+    names: Vec<String>,
+}
+
+enum Action {
+    Add(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Add(name) => {
+            let mut names = state.names.clone();
+            names.push(name.to_string());
+            State { names }
+        }
+    }
+}
+
+#[test]
+fn into_state_after_dispatch() {
+    let mut store = Store::new(reducer, State { names: Vec::new() });
+    store.subscribe(|_: &State| {});
+    store.dispatch(Action::Add("first"));
+    store.dispatch(Action::Add("second"));
+
+    let state = store.into_state();
+
+    assert_eq!(
+        state,
+        State {
+            names: vec!["first".to_string(), "second".to_string()]
+        }
+    );
+}
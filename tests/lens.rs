@@ -0,0 +1,72 @@
+use redux_rs::{lens, update_at, Store};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Audio {
+    volume: u8,
+    muted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Settings {
+    audio: Audio,
+    theme: &'static str,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    settings: Settings,
+    counter: i8,
+}
+
+enum Action {
+    SetVolume(u8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::SetVolume(volume) => {
+            update_at(state, &lens!(State, settings.audio.volume), |_| volume)
+        }
+    }
+}
+
+fn initial_state() -> State {
+    State {
+        settings: Settings {
+            audio: Audio {
+                volume: 5,
+                muted: false,
+            },
+            theme: "dark",
+        },
+        counter: 3,
+    }
+}
+
+#[test]
+fn lens_update_nested_field() {
+    let mut store = Store::new(reducer, initial_state());
+    store.dispatch(Action::SetVolume(9));
+
+    let mut expected = initial_state();
+    expected.settings.audio.volume = 9;
+    assert_eq!(*store.state(), expected);
+}
+
+#[test]
+fn lens_update_leaves_original() {
+    let state = initial_state();
+    let muted = lens!(State, settings.audio.muted);
+
+    let updated = update_at(&state, &muted, |muted| !muted);
+
+    assert!(*muted.get(&updated));
+    assert!(!*muted.get(&state));
+}
+
+#[test]
+fn lens_tuple_field() {
+    let state = (1u8, (2u8, 3u8));
+    let updated = update_at(&state, &lens!((u8, (u8, u8)), 1.0), |value| value * 10);
+    assert_eq!(updated, (1, (20, 3)));
+}
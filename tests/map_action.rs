@@ -0,0 +1,40 @@
+use redux_rs::{MapActionMiddleware, Store};
+
+type State = i8;
+
+enum Action {
+    LegacyIncrement,
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+        Action::LegacyIncrement => panic!("legacy actions should have been migrated"),
+    }
+}
+
+fn migrate(action: Action) -> Action {
+    match action {
+        Action::LegacyIncrement => Action::Increment,
+        action => action,
+    }
+}
+
+#[test]
+fn map_action_legacy_increment() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(MapActionMiddleware::new(migrate));
+    store.dispatch(Action::LegacyIncrement);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn map_action_passthrough() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(MapActionMiddleware::new(migrate));
+    store.dispatch(Action::Decrement);
+    assert_eq!(*store.state(), -1);
+}
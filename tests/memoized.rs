@@ -0,0 +1,54 @@
+use redux_rs::{memoized_reducer, Store};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+enum Action {
+    Increment,
+    Reset,
+}
+
+fn counting_reducer(calls: Rc<Cell<usize>>) -> impl Fn(&State, Action) -> State {
+    move |state: &State, action: Action| {
+        calls.set(calls.get() + 1);
+        match action {
+            Action::Increment => state + 1,
+            Action::Reset => 0,
+        }
+    }
+}
+
+#[test]
+fn memoized_repeated_pairs() {
+    let calls = Rc::new(Cell::new(0));
+    let mut store = Store::new(memoized_reducer(counting_reducer(calls.clone()), 8), 0);
+
+    for _ in 0..3 {
+        store.dispatch(Action::Increment);
+        store.dispatch(Action::Reset);
+    }
+
+    assert_eq!(*store.state(), 0);
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn memoized_evicts_least_recently_used() {
+    let calls = Rc::new(Cell::new(0));
+    let reducer = memoized_reducer(counting_reducer(calls.clone()), 2);
+
+    assert_eq!(reducer(&0, Action::Increment), 1);
+    assert_eq!(reducer(&1, Action::Increment), 2);
+    assert_eq!(reducer(&0, Action::Increment), 1);
+    assert_eq!(calls.get(), 2);
+
+    // Evicts (1, Increment), which was used least recently.
+    assert_eq!(reducer(&2, Action::Increment), 3);
+    assert_eq!(reducer(&0, Action::Increment), 1);
+    assert_eq!(calls.get(), 3);
+
+    assert_eq!(reducer(&1, Action::Increment), 2);
+    assert_eq!(calls.get(), 4);
+}
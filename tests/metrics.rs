@@ -0,0 +1,78 @@
+use redux_rs::{DispatchMetrics, Store, SubscriptionControl};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn slow_reducer(state: &State, action: Action) -> State {
+    thread::sleep(Duration::from_millis(20));
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn halting_middleware(_: &Store<State, Action>, _: Action) -> Option<Action> {
+    None
+}
+
+#[test]
+fn metrics_slow_reducer() {
+    let mut store = Store::new(slow_reducer, 0);
+    store.subscribe(|_: &State| {});
+
+    let collected = Rc::new(Cell::new(None));
+    let collector_collected = collected.clone();
+    store.set_metrics_collector(move |metrics: DispatchMetrics| {
+        collector_collected.set(Some(metrics));
+    });
+
+    store.dispatch(Action::Increment);
+
+    let metrics = collected.get().unwrap();
+    assert!(metrics.reducer_ns >= Duration::from_millis(20).as_nanos());
+    assert!(metrics.reducer_ns > metrics.middleware_ns);
+    assert!(metrics.reducer_ns > metrics.subscriptions_ns);
+    assert!(metrics.total_ns >= metrics.reducer_ns);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn metrics_halted() {
+    let mut store = Store::new(slow_reducer, 0);
+    store.add_middleware(halting_middleware);
+
+    let collected = Rc::new(Cell::new(None));
+    let collector_collected = collected.clone();
+    store.set_metrics_collector(move |metrics: DispatchMetrics| {
+        collector_collected.set(Some(metrics));
+    });
+
+    store.dispatch(Action::Increment);
+
+    let metrics = collected.get().unwrap();
+    assert_eq!(metrics.reducer_ns, 0);
+    assert_eq!(metrics.subscriptions_ns, 0);
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn metrics_removes_detached_subscriptions() {
+    let mut store = Store::new(slow_reducer, 0);
+    store.set_metrics_collector(|_: DispatchMetrics| {});
+
+    let captured = Rc::new(());
+    let subscription_captured = captured.clone();
+    store.subscribe_with_control(move |_: &State, control: &SubscriptionControl| {
+        let _ = &subscription_captured;
+        control.unsubscribe();
+    });
+
+    store.dispatch(Action::Increment);
+    assert_eq!(Rc::strong_count(&captured), 1);
+}
@@ -0,0 +1,73 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn recording_middleware(
+    calls: &Rc<RefCell<Vec<&'static str>>>,
+    name: &'static str,
+) -> impl Fn(&Store<State, Action>, Action) -> Option<Action> {
+    let calls = calls.clone();
+    move |_, action| {
+        calls.borrow_mut().push(name);
+        Some(action)
+    }
+}
+
+#[test]
+fn middleware_enabled_toggle() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let first = store.add_middleware(recording_middleware(&calls, "first"));
+    store.add_middleware(recording_middleware(&calls, "second"));
+
+    assert!(store.set_middleware_enabled(first, false));
+    store.dispatch(Action::Increment);
+    assert_eq!(*calls.borrow(), vec!["second"]);
+
+    calls.borrow_mut().clear();
+    assert!(store.set_middleware_enabled(first, true));
+    store.dispatch(Action::Increment);
+    assert_eq!(*calls.borrow(), vec!["first", "second"]);
+
+    assert_eq!(*store.state(), 2);
+}
+
+#[test]
+fn middleware_enabled_skips_halting() {
+    let mut store = Store::new(reducer, 0);
+    let halt = store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 0);
+
+    store.set_middleware_enabled(halt, false);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn middleware_enabled_unknown_id() {
+    let mut store = Store::new(reducer, 0);
+    let id = store.add_middleware(|_: &Store<State, Action>, action: Action| Some(action));
+
+    let mut other = Store::new(reducer, 0);
+    other.add_middleware(|_: &Store<State, Action>, action: Action| Some(action));
+    other.add_middleware(|_: &Store<State, Action>, action: Action| Some(action));
+    let unknown = other.add_middleware(|_: &Store<State, Action>, action: Action| Some(action));
+
+    assert!(store.set_middleware_enabled(id, true));
+    assert!(!store.set_middleware_enabled(unknown, false));
+}
@@ -0,0 +1,47 @@
+use redux_rs::{MiddlewarePhase, MiddlewareTag, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn pass_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    Some(action)
+}
+
+const AUTH: MiddlewareTag = MiddlewareTag("auth");
+const LOGGING: MiddlewareTag = MiddlewareTag("logging");
+
+#[test]
+fn middleware_ids_in_order() {
+    let mut store = Store::new(reducer, 0);
+    let normal = store.add_middleware(pass_middleware);
+    let post = store.add_middleware_in_phase(MiddlewarePhase::Post, pass_middleware);
+    let pre = store.add_middleware_in_phase(MiddlewarePhase::Pre, pass_middleware);
+
+    assert_eq!(store.middleware_ids(), vec![pre, normal, post]);
+}
+
+#[test]
+fn middleware_tags_in_order() {
+    let mut store = Store::new(reducer, 0);
+    let logging = store.add_tagged_middleware(LOGGING, pass_middleware);
+    let untagged = store.add_middleware(pass_middleware);
+    let auth = store.add_tagged_middleware(AUTH, pass_middleware);
+
+    assert_eq!(store.middleware_ids(), vec![logging, untagged, auth]);
+    assert_eq!(
+        store.middleware_tags(),
+        vec![Some(LOGGING), None, Some(AUTH)]
+    );
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}
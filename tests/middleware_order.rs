@@ -0,0 +1,55 @@
+use redux_rs::{MiddlewareOrderError, MiddlewareTag, Store};
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn passthrough_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    Some(action)
+}
+
+const AUTH: MiddlewareTag = MiddlewareTag("auth");
+const LOGGING: MiddlewareTag = MiddlewareTag("logging");
+
+#[test]
+fn middleware_order_satisfied() {
+    let mut store = Store::new(reducer, 0);
+    store.require_middleware_order(AUTH, LOGGING);
+    store.add_tagged_middleware(AUTH, passthrough_middleware);
+    store.add_middleware(passthrough_middleware);
+    store.add_tagged_middleware(LOGGING, passthrough_middleware);
+    assert_eq!(store.validate_middleware_order(), Ok(()));
+}
+
+#[test]
+fn middleware_order_violated() {
+    let mut store = Store::new(reducer, 0);
+    store.require_middleware_order(AUTH, LOGGING);
+    store.add_tagged_middleware(LOGGING, passthrough_middleware);
+    store.add_tagged_middleware(AUTH, passthrough_middleware);
+    assert_eq!(
+        store.validate_middleware_order(),
+        Err(MiddlewareOrderError {
+            before: AUTH,
+            after: LOGGING,
+        })
+    );
+}
+
+#[test]
+fn middleware_order_missing_tag() {
+    let mut store = Store::new(reducer, 0);
+    store.require_middleware_order(AUTH, LOGGING);
+    store.add_tagged_middleware(LOGGING, passthrough_middleware);
+    assert_eq!(store.validate_middleware_order(), Ok(()));
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}
@@ -0,0 +1,54 @@
+use redux_rs::{MiddlewarePhase, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+fn reducer(state: &State, increment: i8) -> State {
+    state + increment
+}
+
+fn logging_middleware(
+    log: &Log,
+    label: &'static str,
+) -> impl Fn(&Store<State, i8>, i8) -> Option<i8> {
+    let log = log.clone();
+    move |_: &Store<State, i8>, increment: i8| {
+        log.borrow_mut().push(label);
+        Some(increment)
+    }
+}
+
+#[test]
+fn middleware_phase_order() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware_in_phase(MiddlewarePhase::Post, logging_middleware(&log, "post"));
+    store.add_middleware(logging_middleware(&log, "normal 1"));
+    store.add_middleware_in_phase(MiddlewarePhase::Pre, logging_middleware(&log, "pre"));
+    store.add_middleware_in_phase(
+        MiddlewarePhase::Normal,
+        logging_middleware(&log, "normal 2"),
+    );
+
+    store.dispatch(1);
+
+    assert_eq!(*log.borrow(), vec!["pre", "normal 1", "normal 2", "post"]);
+}
+
+#[test]
+fn middleware_phase_halt_in_pre() {
+    let log: Log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(logging_middleware(&log, "normal"));
+    store.add_middleware_in_phase(MiddlewarePhase::Pre, |_: &Store<State, i8>, _: i8| None);
+
+    store.dispatch(1);
+
+    assert_eq!(*store.state(), 0);
+    assert!(log.borrow().is_empty());
+}
@@ -0,0 +1,93 @@
+use redux_rs::{combine_reducers, namespaced_reducer, Reducible, Store};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    counter: i8,
+    todos: Vec<&'static str>,
+}
+
+#[derive(Clone)]
+enum CounterAction {
+    Increment,
+}
+
+#[derive(Clone)]
+enum TodoAction {
+    Add(&'static str),
+}
+
+#[derive(Clone)]
+enum Action {
+    Counter(CounterAction),
+    Todo(TodoAction),
+}
+
+fn counter_reducer(state: &State, action: CounterAction) -> State {
+    match action {
+        CounterAction::Increment => State {
+            counter: state.counter + 1,
+            ..state.clone()
+        },
+    }
+}
+
+fn todo_reducer(state: &State, action: TodoAction) -> State {
+    match action {
+        TodoAction::Add(todo) => {
+            let mut todos = state.todos.clone();
+            todos.push(todo);
+            State {
+                todos,
+                ..state.clone()
+            }
+        }
+    }
+}
+
+fn counter(state: &State, action: Action) -> State {
+    namespaced_reducer(
+        |action: &Action| match action {
+            Action::Counter(action) => Some(action),
+            _ => None,
+        },
+        counter_reducer,
+    )
+    .reduce(state, action)
+}
+
+fn todo(state: &State, action: Action) -> State {
+    namespaced_reducer(
+        |action: &Action| match action {
+            Action::Todo(action) => Some(action),
+            _ => None,
+        },
+        todo_reducer,
+    )
+    .reduce(state, action)
+}
+
+#[test]
+fn namespaced_reducers_own_actions() {
+    let mut store = Store::new(
+        combine_reducers!(State, Action, counter, todo),
+        State::default(),
+    );
+
+    store.dispatch(Action::Counter(CounterAction::Increment));
+    assert_eq!(
+        *store.state(),
+        State {
+            counter: 1,
+            todos: vec![]
+        }
+    );
+
+    store.dispatch(Action::Todo(TodoAction::Add("Sleep")));
+    assert_eq!(
+        *store.state(),
+        State {
+            counter: 1,
+            todos: vec!["Sleep"]
+        }
+    );
+}
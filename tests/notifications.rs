@@ -0,0 +1,84 @@
+use redux_rs::{Extensions, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    next_id: u32,
+    items: Vec<(u32, &'static str)>,
+}
+
+enum Action {
+    Add(&'static str),
+    Clear,
+}
+
+#[derive(Debug, PartialEq)]
+struct Added(u32);
+
+fn reducer(state: &State, action: Action) -> (State, Vec<Added>) {
+    let mut state = state.clone();
+    match action {
+        Action::Add(name) => {
+            let id = state.next_id;
+            state.next_id += 1;
+            state.items.push((id, name));
+            (state, vec![Added(id)])
+        }
+        Action::Clear => {
+            state.items.clear();
+            (state, Vec::new())
+        }
+    }
+}
+
+#[test]
+fn add_notifies_new_id() {
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut store = Store::new_with_notifications(reducer, State::default());
+
+    let captured = received.clone();
+    store.subscribe_notifications(move |added: &Added| captured.borrow_mut().push(added.0));
+
+    store.dispatch(Action::Add("first"));
+    store.dispatch(Action::Clear);
+    store.dispatch(Action::Add("second"));
+
+    assert_eq!(*received.borrow(), vec![0, 1]);
+    assert_eq!(store.state().items, vec![(1, "second")]);
+}
+
+#[test]
+fn notifications_arrive_before_subscriptions() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut store = Store::new_with_notifications(reducer, State::default());
+
+    let captured = events.clone();
+    store.subscribe(move |_: &State| captured.borrow_mut().push("subscription"));
+    let captured = events.clone();
+    store.subscribe_notifications(move |_: &Added| captured.borrow_mut().push("notification"));
+
+    store.dispatch(Action::Add("item"));
+
+    assert_eq!(*events.borrow(), vec!["notification", "subscription"]);
+}
+
+#[test]
+fn notifications_kept_apart_from_extensions() {
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let mut store = Store::new_with_notifications(reducer, State::default());
+    *store.extensions_mut() = Extensions::new();
+
+    let captured = received.clone();
+    store.subscribe_notifications(move |added: &Added| captured.borrow_mut().push(added.0));
+    store.dispatch(Action::Add("item"));
+
+    assert_eq!(*received.borrow(), vec![0]);
+}
+
+#[test]
+#[should_panic(expected = "store has no notifications of this type")]
+fn subscribing_unknown_notifications_panics() {
+    let mut store = Store::new(|state: &u8, _: ()| *state, 0);
+    store.subscribe_notifications(|_: &Added| {});
+}
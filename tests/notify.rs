@@ -0,0 +1,29 @@
+use redux_rs::Store;
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn notify_current_state() {
+    let mut store = Store::new(reducer, 0);
+    store.dispatch(Action::Increment);
+
+    let seen = Rc::new(Cell::new(None));
+    let listener_seen = seen.clone();
+    store.subscribe(move |state: &State| listener_seen.set(Some(*state)));
+
+    store.notify();
+    assert_eq!(seen.get(), Some(1));
+    assert_eq!(*store.state(), 1);
+}
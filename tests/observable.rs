@@ -0,0 +1,73 @@
+use redux_rs::{IntoObservable, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[test]
+fn observable_map_distinct() {
+    let mut store = Store::new(reducer, 0);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let observer_seen = seen.clone();
+    let above = store
+        .observe()
+        .map(|state: &State| *state >= 2)
+        .distinct_until_changed();
+    above.subscribe(move |above: &bool| observer_seen.borrow_mut().push(*above));
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(*seen.borrow(), vec![false, true, false]);
+}
+
+#[test]
+fn observable_filter() {
+    let mut store = Store::new(reducer, 0);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let observer_seen = seen.clone();
+    let even = store.observe().filter(|state: &State| state % 2 == 0);
+    even.subscribe(move |state: &State| observer_seen.borrow_mut().push(*state));
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*seen.borrow(), vec![2, 4]);
+}
+
+#[test]
+fn observable_unsubscribes_on_drop() {
+    let mut store = Store::new(reducer, 0);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let observer_seen = seen.clone();
+    let doubled = store.into_observable().map(|state: &State| state * 2);
+    doubled.subscribe(move |value: &State| observer_seen.borrow_mut().push(*value));
+    assert_eq!(store.subscription_count(), 1);
+
+    store.dispatch(Action::Increment);
+    drop(doubled);
+    assert_eq!(store.subscription_count(), 0);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*seen.borrow(), vec![2]);
+}
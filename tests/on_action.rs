@@ -0,0 +1,71 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Navigate(&'static str),
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Navigate(_) => *state,
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn on_action_matching_only() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let observed = events.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.on_action(
+        |action: &Action| matches!(action, Action::Navigate(_)),
+        move |action: &Action| observed.borrow_mut().push(action.clone()),
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Navigate("/home"));
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*events.borrow(), vec![Action::Navigate("/home")]);
+}
+
+#[test]
+fn on_action_after_reducer() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let observed = events.clone();
+    store.on_action(
+        |action: &Action| *action == Action::Increment,
+        move |_: &Action| observed.borrow_mut().push("action"),
+    );
+    let subscribed = events.clone();
+    store.subscribe(move |_: &State| subscribed.borrow_mut().push("subscription"));
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*events.borrow(), vec!["action", "subscription"]);
+}
+
+#[test]
+fn on_action_halted_by_middleware() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let observed = events.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+    store.on_action(
+        |_: &Action| true,
+        move |action: &Action| observed.borrow_mut().push(action.clone()),
+    );
+
+    store.dispatch(Action::Increment);
+
+    assert!(events.borrow().is_empty());
+}
@@ -0,0 +1,72 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[test]
+fn on_first_dispatch_fires_once() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = calls.clone();
+    store.on_first_dispatch(move |state: &State, action: &Action| {
+        captured.borrow_mut().push((*state, *action));
+    });
+
+    store.dispatch(Action::Decrement);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*calls.borrow(), vec![(0, Action::Decrement)]);
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn on_first_dispatch_halted_action() {
+    let calls = Rc::new(RefCell::new(0));
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+    let captured = calls.clone();
+    store.on_first_dispatch(move |_: &State, _: &Action| *captured.borrow_mut() += 1);
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*calls.borrow(), 1);
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn on_first_dispatch_ignores_run_middleware() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = calls.clone();
+    store.on_first_dispatch(move |state: &State, action: &Action| {
+        captured.borrow_mut().push((*state, *action));
+    });
+
+    assert_eq!(
+        store.run_middleware(Action::Decrement),
+        Some(Action::Decrement)
+    );
+    assert!(calls.borrow().is_empty());
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*calls.borrow(), vec![(0, Action::Increment)]);
+}
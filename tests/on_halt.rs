@@ -0,0 +1,48 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+fn pass_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    Some(action)
+}
+
+fn only_increment_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Decrement => None,
+    }
+}
+
+#[test]
+fn on_halt_reports_index_and_action() {
+    let halts = Rc::new(RefCell::new(Vec::new()));
+    let recorded = halts.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(pass_middleware);
+    store.add_middleware(only_increment_middleware);
+    store.on_halt(move |index: usize, action: &Action| {
+        recorded.borrow_mut().push((index, action.clone()))
+    });
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(*halts.borrow(), vec![(1, Action::Decrement)]);
+    assert_eq!(*store.state(), 1);
+}
@@ -0,0 +1,46 @@
+use redux_rs::{counting_reducer, CounterAction, OverflowPolicy, Store};
+
+#[test]
+fn overflow_saturate() {
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Saturate), i8::MAX);
+    store.dispatch(CounterAction::Increment);
+    assert_eq!(*store.state(), i8::MAX);
+
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Saturate), u8::MIN);
+    store.dispatch(CounterAction::Decrement);
+    assert_eq!(*store.state(), u8::MIN);
+}
+
+#[test]
+fn overflow_wrap() {
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Wrap), i8::MAX);
+    store.dispatch(CounterAction::Increment);
+    assert_eq!(*store.state(), i8::MIN);
+
+    store.dispatch(CounterAction::Decrement);
+    assert_eq!(*store.state(), i8::MAX);
+}
+
+#[test]
+fn overflow_halt() {
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Halt), i8::MIN);
+    store.dispatch(CounterAction::Decrement);
+    assert_eq!(*store.state(), i8::MIN);
+
+    store.dispatch(CounterAction::Increment);
+    assert_eq!(*store.state(), i8::MIN + 1);
+}
+
+#[test]
+#[should_panic(expected = "counter overflowed on Increment")]
+fn overflow_panic() {
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Panic), u32::MAX);
+    store.dispatch(CounterAction::Increment);
+}
+
+#[test]
+fn overflow_panic_within_bounds() {
+    let mut store = Store::new(counting_reducer(OverflowPolicy::Panic), u32::MAX - 1);
+    store.dispatch(CounterAction::Increment);
+    assert_eq!(*store.state(), u32::MAX);
+}
@@ -0,0 +1,63 @@
+use redux_rs::{QueueOverflowPolicy, Store};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Double,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Double => state * 2,
+    }
+}
+
+#[test]
+fn paused_dispatches_apply_on_resume() {
+    let notified = Rc::new(Cell::new(0));
+    let mut store = Store::new(reducer, 1);
+    let captured = notified.clone();
+    store.subscribe(move |_: &State| captured.set(captured.get() + 1));
+
+    store.pause_dispatch();
+    assert!(store.is_dispatch_paused());
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Double);
+    assert_eq!(*store.state(), 1);
+    assert_eq!(notified.get(), 0);
+
+    store.resume_dispatch();
+    assert!(!store.is_dispatch_paused());
+    assert_eq!(*store.state(), 4);
+    assert_eq!(notified.get(), 2);
+}
+
+#[test]
+fn drain_pending_waits_for_resume() {
+    let mut store = Store::new(reducer, 0);
+    store.enqueue(Action::Increment);
+
+    store.pause_dispatch();
+    store.drain_pending();
+    assert_eq!(*store.state(), 0);
+
+    store.resume_dispatch();
+    assert_eq!(*store.state(), 1);
+}
+
+#[test]
+fn paused_dispatches_respect_queue_capacity() {
+    let mut store = Store::new(reducer, 1);
+    store.set_queue_capacity(1, QueueOverflowPolicy::RejectNewest);
+
+    store.pause_dispatch();
+    store.dispatch(Action::Double);
+    store.dispatch(Action::Increment);
+    store.resume_dispatch();
+
+    assert_eq!(*store.state(), 2);
+}
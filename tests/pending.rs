@@ -0,0 +1,50 @@
+use redux_rs::Store;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Burst(u8),
+    Chain(u8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        _ => *state,
+    }
+}
+
+fn enqueue_middleware(store: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Burst(count) => {
+            (0..count).for_each(|_| store.enqueue(Action::Increment));
+            None
+        }
+        Action::Chain(count) if count > 0 => {
+            store.enqueue(Action::Increment);
+            store.enqueue(Action::Chain(count - 1));
+            None
+        }
+        action => Some(action),
+    }
+}
+
+#[test]
+fn drain_pending_all() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(enqueue_middleware);
+    store.dispatch(Action::Burst(3));
+    assert_eq!(*store.state(), 0);
+    store.drain_pending();
+    assert_eq!(*store.state(), 3);
+}
+
+#[test]
+fn drain_pending_enqueued_while_draining() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(enqueue_middleware);
+    store.dispatch(Action::Chain(4));
+    store.drain_pending();
+    assert_eq!(*store.state(), 4);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "persist")]
+
+use redux_rs::{ManualClock, PersistSubscription, StateStore, Store};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[derive(Clone, Default)]
+struct MemoryBackend {
+    saves: Rc<RefCell<Vec<String>>>,
+}
+
+impl StateStore<State> for MemoryBackend {
+    fn save(&self, state: &State) {
+        self.saves.borrow_mut().push(state.to_string());
+    }
+}
+
+#[test]
+fn persist_latest_state() {
+    let backend = MemoryBackend::default();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(PersistSubscription::new(backend.clone()));
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(backend.saves.borrow().last().unwrap(), "3");
+    assert_eq!(backend.saves.borrow().len(), 3);
+}
+
+#[test]
+fn persist_debounced() {
+    let backend = MemoryBackend::default();
+    let clock = ManualClock::new();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(
+        PersistSubscription::new(backend.clone())
+            .with_debounce(Duration::from_secs(1))
+            .with_clock(clock.clone()),
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    clock.advance(Duration::from_secs(1));
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*backend.saves.borrow(), vec!["1", "3"]);
+}
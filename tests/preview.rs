@@ -0,0 +1,33 @@
+use redux_rs::Store;
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+#[test]
+fn preview_leaves_state() {
+    let notified = Rc::new(Cell::new(false));
+    let subscribed = notified.clone();
+
+    let mut store = Store::new(reducer, 3);
+    store.subscribe(move |_: &State| subscribed.set(true));
+    store.add_middleware(|_: &Store<State, Action>, _: Action| None);
+
+    assert_eq!(store.preview(&Action::Increment), 4);
+    assert_eq!(store.preview(&Action::Decrement), 2);
+    assert_eq!(*store.state(), 3);
+    assert!(!notified.get());
+}
@@ -0,0 +1,36 @@
+use redux_rs::{assert_pure, Store};
+use std::cell::Cell;
+
+type State = i8;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn assert_pure_passes() {
+    let mut store = Store::new(assert_pure(reducer), 0);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "reducer is not pure"))]
+fn assert_pure_nondeterministic() {
+    let calls = Cell::new(0);
+    let nondeterministic = move |state: &State, action: Action| {
+        calls.set(calls.get() + 1);
+        reducer(state, action) + calls.get()
+    };
+
+    let mut store = Store::new(assert_pure(nondeterministic), 0);
+    store.dispatch(Action::Increment);
+}
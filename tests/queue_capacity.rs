@@ -0,0 +1,57 @@
+use redux_rs::{QueueOverflowPolicy, Store};
+
+type State = Vec<u8>;
+
+fn reducer(state: &State, value: u8) -> State {
+    let mut state = state.clone();
+    state.push(value);
+    state
+}
+
+fn bounded_store(policy: QueueOverflowPolicy) -> Store<State, u8> {
+    let mut store = Store::new(reducer, Vec::new());
+    store.set_queue_capacity(2, policy);
+    store
+}
+
+#[test]
+fn queue_capacity_reject_newest() {
+    let mut store = bounded_store(QueueOverflowPolicy::RejectNewest);
+    for value in 1..=4 {
+        store.enqueue(value);
+    }
+
+    store.drain_pending();
+    assert_eq!(*store.state(), vec![1, 2]);
+}
+
+#[test]
+fn queue_capacity_drop_oldest() {
+    let mut store = bounded_store(QueueOverflowPolicy::DropOldest);
+    for value in 1..=4 {
+        store.enqueue(value);
+    }
+
+    store.drain_pending();
+    assert_eq!(*store.state(), vec![3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "action queue capacity of 2 exceeded")]
+fn queue_capacity_panic() {
+    let store = bounded_store(QueueOverflowPolicy::Panic);
+    for value in 1..=3 {
+        store.enqueue(value);
+    }
+}
+
+#[test]
+fn queue_capacity_unbounded_by_default() {
+    let mut store = Store::new(reducer, Vec::new());
+    for value in 0..100 {
+        store.enqueue(value);
+    }
+
+    store.drain_pending();
+    assert_eq!(store.state().len(), 100);
+}
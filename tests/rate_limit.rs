@@ -0,0 +1,47 @@
+use redux_rs::{ManualClock, RateLimitMiddleware, Store};
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn rate_limit_halts_excess() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(RateLimitMiddleware::new(5, Duration::from_secs(60)));
+
+    for _ in 0..20 {
+        store.dispatch(Action::Increment);
+    }
+
+    assert_eq!(*store.state(), 5);
+}
+
+#[test]
+fn rate_limit_next_window() {
+    let clock = ManualClock::new();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(
+        RateLimitMiddleware::new(2, Duration::from_secs(1)).with_clock(clock.clone()),
+    );
+
+    for _ in 0..4 {
+        store.dispatch(Action::Increment);
+    }
+    assert_eq!(*store.state(), 2);
+
+    clock.advance(Duration::from_secs(1));
+    for _ in 0..4 {
+        store.dispatch(Action::Increment);
+    }
+    assert_eq!(*store.state(), 4);
+}
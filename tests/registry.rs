@@ -0,0 +1,64 @@
+use redux_rs::{ReducerRegistry, Store};
+
+#[derive(Clone, Copy, Default)]
+struct State {
+    counter: i8,
+    history: u8,
+}
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+mod counter {
+    use super::{Action, State};
+    use redux_rs::ReducerRegistry;
+
+    fn reducer(state: &State, action: Action) -> State {
+        State {
+            counter: match action {
+                Action::Increment => state.counter + 1,
+                Action::Decrement => state.counter - 1,
+            },
+            ..*state
+        }
+    }
+
+    pub fn register(registry: &mut ReducerRegistry<State, Action>) {
+        registry.register(reducer);
+    }
+}
+
+mod history {
+    use super::{Action, State};
+    use redux_rs::ReducerRegistry;
+
+    fn reducer(state: &State, _: Action) -> State {
+        State {
+            history: state.history + 1,
+            ..*state
+        }
+    }
+
+    pub fn register(registry: &mut ReducerRegistry<State, Action>) {
+        registry.register(reducer);
+    }
+}
+
+#[test]
+fn registry_runs_all_reducers() {
+    let mut registry = ReducerRegistry::new();
+    counter::register(&mut registry);
+    history::register(&mut registry);
+    assert_eq!(registry.len(), 2);
+
+    let mut store = Store::new_with_registered(registry, State::default());
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(store.state().counter, 1);
+    assert_eq!(store.state().history, 3);
+}
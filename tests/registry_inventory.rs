@@ -0,0 +1,68 @@
+#![cfg(feature = "inventory")]
+
+use redux_rs::{ReducerRegistry, Store};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct State {
+    counter: i8,
+    history: u8,
+}
+
+#[derive(Clone)]
+enum Action {
+    Increment,
+    Decrement,
+}
+
+mod counter {
+    use super::{Action, State};
+
+    fn reducer(state: &State, action: Action) -> State {
+        State {
+            counter: match action {
+                Action::Increment => state.counter + 1,
+                Action::Decrement => state.counter - 1,
+            },
+            ..*state
+        }
+    }
+
+    redux_rs::register_reducer!(reducer);
+}
+
+mod history {
+    use super::{Action, State};
+
+    fn reducer(state: &State, _: Action) -> State {
+        State {
+            history: state.history + 1,
+            ..*state
+        }
+    }
+
+    redux_rs::register_reducer!(reducer);
+}
+
+#[test]
+fn collected_reducers_all_run() {
+    let registry = ReducerRegistry::<State, Action>::collected();
+    assert_eq!(registry.len(), 2);
+
+    let mut store = Store::new_with_registered(registry, State::default());
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Decrement);
+
+    assert_eq!(
+        *store.state(),
+        State {
+            counter: 1,
+            history: 3
+        }
+    );
+}
+
+#[test]
+fn collected_ignores_other_types() {
+    assert!(ReducerRegistry::<u8, ()>::collected().is_empty());
+}
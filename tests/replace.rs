@@ -1,4 +1,7 @@
 use redux_rs::Store;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
 
 type State = i8;
 
@@ -21,6 +24,10 @@ fn double_reducer(state: &State, action: Action) -> State {
     }
 }
 
+fn cow_reducer<'a>(state: &'a State, _: Action) -> Cow<'a, State> {
+    Cow::Borrowed(state)
+}
+
 #[test]
 fn replace_increment() {
     let mut store = Store::new(reducer, 0);
@@ -40,3 +47,23 @@ fn replace_decrement() {
     store.dispatch(Action::Decrement);
     assert_eq!(*store.state(), -3);
 }
+
+#[test]
+fn replace_keeps_change_detection() {
+    let mut store = Store::new_with_change_detection(reducer, 0);
+    store.replace_reducer(|state: &State, _: Action| *state);
+
+    let notified = Rc::new(Cell::new(0));
+    let counter = notified.clone();
+    store.subscribe(move |_: &State| counter.set(counter.get() + 1));
+
+    store.dispatch(Action::Increment);
+    assert_eq!(notified.get(), 0);
+}
+
+#[test]
+#[should_panic(expected = "can't be replaced by a plain one")]
+fn replace_rejects_cow_reducer() {
+    let mut store = Store::new_cow(cow_reducer, 0);
+    store.replace_reducer(double_reducer);
+}
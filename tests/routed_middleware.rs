@@ -0,0 +1,45 @@
+use redux_rs::Store;
+use std::cell::Cell;
+use std::mem;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Add(i8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Add(value) => state + value,
+    }
+}
+
+#[test]
+fn routed_middleware_only_matching() {
+    let mut store = Store::new(reducer, 0);
+
+    let calls = Rc::new(Cell::new(0));
+    let middleware_calls = calls.clone();
+    store.add_routed_middleware(
+        mem::discriminant(&Action::Add(0)),
+        move |_: &Store<State, Action>, action: Action| {
+            middleware_calls.set(middleware_calls.get() + 1);
+            match action {
+                Action::Add(value) => Some(Action::Add(value * 2)),
+                action => Some(action),
+            }
+        },
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(calls.get(), 0);
+    assert_eq!(*store.state(), 2);
+
+    store.dispatch(Action::Add(3));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(*store.state(), 8);
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "rpc")]
+
+use redux_rs::{Store, StoreRpcHandler};
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Add(i8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Add(value) => state + value,
+    }
+}
+
+// Accepts `"increment"` or `{"add": <value>}`, standing in for a real deserializer.
+fn decode_action(params: &str) -> Result<Action, String> {
+    let params = params.replace(' ', "");
+    if params == r#""increment""# {
+        return Ok(Action::Increment);
+    }
+    params
+        .strip_prefix(r#"{"add":"#)
+        .and_then(|rest| rest.strip_suffix('}'))
+        .and_then(|value| value.parse().ok())
+        .map(Action::Add)
+        .ok_or_else(|| format!("unknown action {}", params))
+}
+
+fn encode_state(state: &State) -> String {
+    format!(r#"{{"counter":{}}}"#, state)
+}
+
+type Codec = (fn(&str) -> Result<Action, String>, fn(&State) -> String);
+
+fn handler() -> StoreRpcHandler<Codec> {
+    StoreRpcHandler::new((decode_action, encode_state))
+}
+
+#[test]
+fn rpc_dispatch_and_get_state() {
+    let mut store = Store::new(reducer, 0);
+    let handler = handler();
+
+    assert_eq!(
+        handler.handle(
+            &mut store,
+            r#"{"jsonrpc": "2.0", "method": "dispatch", "params": "increment", "id": 1}"#
+        ),
+        Some(r#"{"jsonrpc":"2.0","result":null,"id":1}"#.to_string())
+    );
+    assert_eq!(
+        handler.handle(
+            &mut store,
+            r#"{"jsonrpc": "2.0", "method": "dispatch", "params": {"add": 4}}"#
+        ),
+        None
+    );
+    assert_eq!(
+        handler.handle(
+            &mut store,
+            r#"{"jsonrpc": "2.0", "method": "get_state", "id": "state"}"#
+        ),
+        Some(r#"{"jsonrpc":"2.0","result":{"counter":5},"id":"state"}"#.to_string())
+    );
+}
+
+#[test]
+fn rpc_errors() {
+    let mut store = Store::new(reducer, 0);
+    let handler = handler();
+
+    assert_eq!(
+        handler.handle(&mut store, r#"{"jsonrpc": "2.0", "method": "#),
+        Some(
+            r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"parse error"},"id":null}"#
+                .to_string()
+        )
+    );
+    assert_eq!(
+        handler.handle(
+            &mut store,
+            r#"{"jsonrpc": "2.0", "method": "reset", "id": 1}"#
+        ),
+        Some(
+            r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"method not found"},"id":1}"#
+                .to_string()
+        )
+    );
+    assert_eq!(
+        handler.handle(
+            &mut store,
+            r#"{"jsonrpc": "2.0", "method": "dispatch", "params": [true], "id": 2}"#
+        ),
+        Some(
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"unknown action [true]"},"id":2}"#
+                .to_string()
+        )
+    );
+    assert_eq!(*store.state(), 0);
+}
+
+#[test]
+fn rpc_rejects_deep_nesting() {
+    let mut store = Store::new(reducer, 0);
+    let handler = handler();
+
+    let params = "[".repeat(100_000);
+    let request = format!(
+        r#"{{"jsonrpc": "2.0", "method": "dispatch", "params": {}, "id": 1}}"#,
+        params
+    );
+    assert_eq!(
+        handler.handle(&mut store, &request),
+        Some(
+            r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"parse error"},"id":null}"#
+                .to_string()
+        )
+    );
+}
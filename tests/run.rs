@@ -0,0 +1,56 @@
+use redux_rs::Store;
+use std::collections::VecDeque;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+        Action::Reset => 0,
+    }
+}
+
+#[test]
+fn run_dispatches_until_source_is_exhausted() {
+    let mut store = Store::new(reducer, 0);
+
+    let mut actions = VecDeque::from(vec![
+        Action::Increment,
+        Action::Increment,
+        Action::Decrement,
+    ]);
+    let mut pulls = 0;
+    store.run(|| {
+        pulls += 1;
+        actions.pop_front()
+    });
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(pulls, 4);
+}
+
+#[test]
+fn run_dispatches_queued_actions() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(
+        |store: &Store<State, Action>, action: Action| match action {
+            Action::Reset => {
+                store.enqueue(Action::Increment);
+                Some(Action::Reset)
+            }
+            action => Some(action),
+        },
+    );
+
+    let mut actions = vec![Action::Reset, Action::Increment].into_iter();
+    store.run(|| actions.next());
+
+    assert_eq!(*store.state(), 2);
+}
@@ -0,0 +1,113 @@
+#![cfg(all(feature = "async", feature = "test-util"))]
+
+use redux_rs::test::TestScheduler;
+use redux_rs::{DispatchTimeout, Store};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Search(&'static str),
+}
+
+fn reducer(_: &&'static str, action: Action) -> &'static str {
+    match action {
+        Action::Search(query) => query,
+    }
+}
+
+/// Dispatches searches 300ms after the last keystroke only.
+struct Debouncer {
+    scheduler: TestScheduler,
+    generation: Rc<Cell<u32>>,
+    ready: Rc<RefCell<Vec<Action>>>,
+}
+
+impl Debouncer {
+    fn keystroke(&self, query: &'static str) {
+        self.generation.set(self.generation.get() + 1);
+
+        let generation = self.generation.get();
+        let current = self.generation.clone();
+        let ready = self.ready.clone();
+        let sleep = self.scheduler.sleep(Duration::from_millis(300));
+        self.scheduler.spawn(async move {
+            sleep.await;
+            if current.get() == generation {
+                ready.borrow_mut().push(Action::Search(query));
+            }
+        });
+    }
+
+    fn flush(&self, store: &mut Store<&'static str, Action>) {
+        for action in self.ready.borrow_mut().drain(..) {
+            store.dispatch(action);
+        }
+    }
+}
+
+#[test]
+fn scheduler_debounced_action() {
+    let scheduler = TestScheduler::new();
+    let debouncer = Debouncer {
+        scheduler: scheduler.clone(),
+        generation: Rc::new(Cell::new(0)),
+        ready: Rc::new(RefCell::new(Vec::new())),
+    };
+    let mut store = Store::new(reducer, "");
+
+    debouncer.keystroke("r");
+    scheduler.advance(Duration::from_millis(100));
+    debouncer.keystroke("re");
+    scheduler.advance(Duration::from_millis(100));
+    debouncer.keystroke("redux");
+
+    scheduler.advance(Duration::from_millis(299));
+    debouncer.flush(&mut store);
+    assert_eq!(*store.state(), "");
+
+    scheduler.advance(Duration::from_millis(1));
+    debouncer.flush(&mut store);
+    assert_eq!(*store.state(), "redux");
+    assert!(debouncer.ready.borrow().is_empty());
+}
+
+fn slow_store(scheduler: &TestScheduler, delay: Duration) -> Store<&'static str, Action> {
+    let mut store = Store::new(reducer, "");
+    store.set_timer(scheduler.clone());
+
+    let scheduler = scheduler.clone();
+    store.add_async_middleware(move |_: &Store<&'static str, Action>, action: Action| {
+        let sleep = scheduler.sleep(delay);
+        async move {
+            sleep.await;
+            Some(action)
+        }
+    });
+    store
+}
+
+#[test]
+fn scheduler_dispatch_timeout_elapses() {
+    let scheduler = TestScheduler::new();
+    let mut store = slow_store(&scheduler, Duration::from_secs(60));
+
+    let result = scheduler
+        .block_on(store.dispatch_async_timeout(Action::Search("redux"), Duration::from_secs(1)));
+
+    assert_eq!(result, Err(DispatchTimeout));
+    assert_eq!(*store.state(), "");
+}
+
+#[test]
+fn scheduler_dispatch_timeout_completes() {
+    let scheduler = TestScheduler::new();
+    let mut store = slow_store(&scheduler, Duration::from_secs(1));
+
+    let result = scheduler
+        .block_on(store.dispatch_async_timeout(Action::Search("redux"), Duration::from_secs(60)));
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(*store.state(), "redux");
+}
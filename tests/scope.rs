@@ -0,0 +1,60 @@
+use redux_rs::Store;
+
+#[derive(Clone, Copy)]
+struct State {
+    counter: i8,
+    renames: u8,
+}
+
+enum CounterAction {
+    Increment,
+    Decrement,
+}
+
+enum Action {
+    Counter(CounterAction),
+    Rename,
+}
+
+fn counter_reducer(state: &i8, action: CounterAction) -> i8 {
+    match action {
+        CounterAction::Increment => state + 1,
+        CounterAction::Decrement => state - 1,
+    }
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Counter(action) => State {
+            counter: counter_reducer(&state.counter, action),
+            ..*state
+        },
+        Action::Rename => State {
+            renames: state.renames + 1,
+            ..*state
+        },
+    }
+}
+
+#[test]
+fn scope_dispatches_to_parent() {
+    let mut store = Store::new(
+        reducer,
+        State {
+            counter: 0,
+            renames: 0,
+        },
+    );
+
+    {
+        let mut counter = store.scope(|state: &State| &state.counter, Action::Counter);
+        counter.dispatch(CounterAction::Increment);
+        counter.dispatch(CounterAction::Increment);
+        counter.dispatch(CounterAction::Decrement);
+        assert_eq!(*counter.state(), 1);
+    }
+
+    store.dispatch(Action::Rename);
+    assert_eq!(store.state().counter, 1);
+    assert_eq!(store.state().renames, 1);
+}
@@ -0,0 +1,40 @@
+use redux_rs::{Store, Subscription};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn recording(calls: &Rc<RefCell<Vec<&'static str>>>, name: &'static str) -> impl Fn(&State) {
+    let calls = calls.clone();
+    move |_| calls.borrow_mut().push(name)
+}
+
+#[test]
+fn set_subscriptions_replaces_all() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(recording(&calls, "first"));
+    store.subscribe(recording(&calls, "second"));
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*calls.borrow(), vec!["first", "second"]);
+
+    calls.borrow_mut().clear();
+    let subscriptions: Vec<Box<dyn Subscription<State>>> =
+        vec![Box::new(recording(&calls, "replacement"))];
+    store.set_subscriptions(subscriptions);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*calls.borrow(), vec!["replacement"]);
+}
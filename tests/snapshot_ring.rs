@@ -0,0 +1,48 @@
+use redux_rs::Store;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn snapshot_ring_keeps_last_states() {
+    let mut store = Store::new(reducer, 0);
+    store.enable_snapshot_ring(3);
+
+    for _ in 0..5 {
+        store.dispatch(Action::Increment);
+    }
+
+    assert_eq!(store.recent_states(), &[3, 4, 5]);
+}
+
+#[test]
+fn snapshot_ring_disabled() {
+    let mut store = Store::new(reducer, 0);
+    store.dispatch(Action::Increment);
+
+    assert!(store.recent_states().is_empty());
+}
+
+#[test]
+fn snapshot_ring_shrinks() {
+    let mut store = Store::new(reducer, 0);
+    store.enable_snapshot_ring(3);
+    for _ in 0..3 {
+        store.dispatch(Action::Increment);
+    }
+
+    store.enable_snapshot_ring(1);
+    assert_eq!(store.recent_states(), &[3]);
+
+    store.dispatch(Action::Increment);
+    assert_eq!(store.recent_states(), &[4]);
+}
@@ -0,0 +1,49 @@
+use redux_rs::Store;
+
+#[derive(Debug, PartialEq)]
+struct State {
+    items: Vec<&'static str>,
+}
+
+enum Action {
+    Remove(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Remove(item) => State {
+            items: state
+                .items
+                .iter()
+                .copied()
+                .filter(|other| *other != item)
+                .collect(),
+        },
+    }
+}
+
+#[test]
+fn split_read_and_dispatch() {
+    let mut store = Store::new(
+        reducer,
+        State {
+            items: vec!["apple", "banana", "cherry"],
+        },
+    );
+
+    let (state, dispatcher) = store.split();
+    for item in &state.items {
+        if item.starts_with('b') || item.starts_with('c') {
+            dispatcher.dispatch(Action::Remove(item));
+        }
+    }
+    assert_eq!(state.items.len(), 3);
+
+    store.drain_pending();
+    assert_eq!(
+        *store.state(),
+        State {
+            items: vec!["apple"]
+        }
+    );
+}
@@ -0,0 +1,61 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Add(i8),
+    Forbidden,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Add(value) => state + value,
+        Action::Forbidden => unreachable!(),
+    }
+}
+
+fn clamp_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Add(value) => Some(Action::Add(value.min(10))),
+        Action::Forbidden => None,
+    }
+}
+
+#[test]
+fn run_middleware_then_apply_reducer() {
+    let notified = Rc::new(RefCell::new(Vec::new()));
+    let captured = notified.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(clamp_middleware);
+    store.subscribe(move |state: &State| captured.borrow_mut().push(*state));
+
+    let action = store.run_middleware(Action::Add(42));
+    assert_eq!(action, Some(Action::Add(10)));
+    assert_eq!(*store.state(), 0);
+    assert!(notified.borrow().is_empty());
+
+    store.apply_reducer(action.unwrap());
+    assert_eq!(*store.state(), 10);
+    assert_eq!(*notified.borrow(), vec![10]);
+}
+
+#[test]
+fn run_middleware_halted() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(clamp_middleware);
+
+    assert_eq!(store.run_middleware(Action::Forbidden), None);
+}
+
+#[test]
+fn apply_reducer_skips_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(clamp_middleware);
+
+    store.apply_reducer(Action::Add(42));
+    assert_eq!(*store.state(), 42);
+}
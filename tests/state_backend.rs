@@ -0,0 +1,79 @@
+use redux_rs::{InMemoryBackend, StateBackend, Store};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+struct CountingBackend {
+    state: State,
+    commits: Rc<Cell<usize>>,
+}
+
+impl StateBackend<State> for CountingBackend {
+    fn get(&self) -> &State {
+        &self.state
+    }
+
+    fn get_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    fn commit(&mut self) {
+        self.commits.set(self.commits.get() + 1);
+    }
+
+    fn into_state(self: Box<Self>) -> State {
+        self.state
+    }
+}
+
+#[test]
+fn in_memory_backend_behaves_like_inline_state() {
+    let mut inline = Store::new(reducer, 0);
+    let mut backed = Store::new_with_backend(reducer, InMemoryBackend::new(0));
+
+    let seen = Rc::new(Cell::new(0));
+    let captured = seen.clone();
+    backed.subscribe(move |state: &State| captured.set(*state));
+
+    for store in [&mut inline, &mut backed] {
+        store.dispatch(Action::Increment);
+        store.dispatch(Action::Increment);
+        store.dispatch(Action::Decrement);
+    }
+    assert_eq!(inline.state(), backed.state());
+    assert_eq!(seen.get(), 1);
+
+    backed.state_mut_scope(|state| *state = 5);
+    assert_eq!(seen.get(), 5);
+    assert_eq!(backed.into_state(), 5);
+}
+
+#[test]
+fn backend_commits_after_every_change() {
+    let commits = Rc::new(Cell::new(0));
+    let mut store = Store::new_with_backend(
+        reducer,
+        CountingBackend {
+            state: 0,
+            commits: commits.clone(),
+        },
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 2);
+    assert_eq!(commits.get(), 2);
+}
@@ -0,0 +1,50 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = Vec<i8>;
+
+enum Action {
+    Push(i8),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Push(value) => {
+            let mut state = state.clone();
+            state.push(value);
+            state
+        }
+    }
+}
+
+#[test]
+fn state_mut_scope_notifies() {
+    let notified = Rc::new(RefCell::new(Vec::new()));
+    let captured = notified.clone();
+
+    let mut store = Store::new(reducer, vec![1]);
+    store.subscribe(move |state: &State| captured.borrow_mut().push(state.clone()));
+
+    store.state_mut_scope(|state| {
+        state.push(2);
+        state[0] = 0;
+    });
+
+    assert_eq!(*store.state(), vec![0, 2]);
+    assert_eq!(*notified.borrow(), vec![vec![0, 2]]);
+
+    store.dispatch(Action::Push(3));
+    assert_eq!(*notified.borrow(), vec![vec![0, 2], vec![0, 2, 3]]);
+}
+
+#[test]
+fn state_mut_scope_skips_middleware() {
+    let mut store = Store::new(reducer, Vec::new());
+    store.add_middleware(|_: &Store<State, Action>, _: Action| -> Option<Action> {
+        panic!("middleware must not run")
+    });
+
+    store.state_mut_scope(|state| state.push(1));
+    assert_eq!(*store.state(), vec![1]);
+}
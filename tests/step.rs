@@ -0,0 +1,91 @@
+#![cfg(feature = "test-util")]
+
+use redux_rs::test::{StepReport, TraceEvent, TraceRecorder};
+use redux_rs::{Store, SubscriptionControl};
+
+type State = i8;
+
+#[derive(Debug)]
+enum Action {
+    Increment,
+    Forbidden,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Forbidden => unreachable!(),
+    }
+}
+
+fn guard_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Forbidden => None,
+        action => Some(action),
+    }
+}
+
+#[test]
+fn step_normal_action() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(guard_middleware);
+    let first = store.subscribe(|_: &State| {});
+    let second = store.subscribe(|_: &State| {});
+
+    assert_eq!(
+        store.step(Action::Increment),
+        StepReport {
+            halted: false,
+            state: 1,
+            subscriptions_fired: vec![first, second],
+        }
+    );
+}
+
+#[test]
+fn step_after_self_unsubscribe() {
+    let mut store = Store::new(reducer, 0);
+    let first = store
+        .subscribe_with_control(|_: &State, control: &SubscriptionControl| control.unsubscribe());
+    let second = store.subscribe(|_: &State| {});
+
+    assert_eq!(
+        store.step(Action::Increment).subscriptions_fired,
+        vec![first, second]
+    );
+    assert_eq!(
+        store.step(Action::Increment).subscriptions_fired,
+        vec![second]
+    );
+}
+
+#[test]
+fn step_halted_action() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(guard_middleware);
+    store.subscribe(|_: &State| {});
+
+    assert_eq!(
+        store.step(Action::Forbidden),
+        StepReport {
+            halted: true,
+            state: 0,
+            subscriptions_fired: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn step_keeps_trace_recorder() {
+    let recorder = TraceRecorder::new();
+    let mut store = Store::new(reducer, 0);
+    store.set_trace_recorder(recorder.clone());
+
+    store.step(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(
+        recorder.events(),
+        vec![TraceEvent::ReducerRan, TraceEvent::ReducerRan]
+    );
+}
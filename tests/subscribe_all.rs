@@ -0,0 +1,48 @@
+use redux_rs::{Store, Subscription};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+fn reducer(state: &State, increment: i8) -> State {
+    state + increment
+}
+
+fn recorder(seen: &Rc<RefCell<Vec<(usize, State)>>>, index: usize) -> Box<dyn Subscription<State>> {
+    let seen = seen.clone();
+    Box::new(move |state: &State| seen.borrow_mut().push((index, *state)))
+}
+
+#[test]
+fn subscribe_all_then_unsubscribe_all() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let ids = store.subscribe_all((0..3).map(|index| recorder(&seen, index)));
+    assert_eq!(ids.len(), 3);
+
+    store.dispatch(1);
+    assert_eq!(*seen.borrow(), vec![(0, 1), (1, 1), (2, 1)]);
+
+    for id in ids {
+        assert!(store.unsubscribe(id));
+    }
+
+    store.dispatch(1);
+    assert_eq!(seen.borrow().len(), 3);
+}
+
+#[test]
+fn unsubscribe_keeps_others() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let ids = store.subscribe_all(vec![recorder(&seen, 0), recorder(&seen, 1)]);
+    let first = ids[0];
+
+    assert!(store.unsubscribe(first));
+    assert!(!store.unsubscribe(first));
+
+    store.dispatch(2);
+    assert_eq!(*seen.borrow(), vec![(1, 2)]);
+}
@@ -0,0 +1,62 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct State {
+    count: i8,
+    label: &'static str,
+}
+
+enum Action {
+    Increment,
+    Rename(&'static str),
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => State {
+            count: state.count + 1,
+            label: state.label,
+        },
+        Action::Rename(label) => State {
+            count: state.count,
+            label,
+        },
+    }
+}
+
+#[test]
+fn subscribe_map_receives_projection() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut store = Store::new(reducer, State::default());
+
+    let captured = seen.clone();
+    store.subscribe_map(
+        |state: &State| state.count,
+        move |count: i8| captured.borrow_mut().push(count),
+    );
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+
+    assert_eq!(*seen.borrow(), vec![1, 2]);
+}
+
+#[test]
+fn subscribe_map_fires_without_change() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut store = Store::new(reducer, State::default());
+
+    let captured = seen.clone();
+    store.subscribe_map(
+        |state: &State| state.count,
+        move |count: i8| captured.borrow_mut().push(count),
+    );
+
+    store.dispatch(Action::Rename("first"));
+    store.dispatch(Action::Rename("second"));
+
+    assert_eq!(*seen.borrow(), vec![0, 0]);
+    assert_eq!(store.state().label, "second");
+}
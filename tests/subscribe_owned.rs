@@ -0,0 +1,69 @@
+use redux_rs::Store;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, PartialEq)]
+struct State {
+    counter: i8,
+}
+
+impl Clone for State {
+    fn clone(&self) -> Self {
+        CLONES.with(|clones| clones.set(clones.get() + 1));
+        State {
+            counter: self.counter,
+        }
+    }
+}
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => State {
+            counter: state.counter + 1,
+        },
+    }
+}
+
+#[test]
+fn subscribe_owned_clones_once() {
+    let borrowed = Rc::new(Cell::new(0));
+    let owned = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, State { counter: 0 });
+    let seen = borrowed.clone();
+    store.subscribe(move |state: &State| seen.set(state.counter));
+    let received = owned.clone();
+    store.subscribe_owned(move |state: State| received.borrow_mut().push(state));
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(CLONES.with(Cell::get), 1);
+    assert_eq!(borrowed.get(), 1);
+    assert_eq!(*owned.borrow(), vec![State { counter: 1 }]);
+}
+
+#[test]
+fn subscribe_owned_separate_clones() {
+    let first = Rc::new(RefCell::new(Vec::new()));
+    let second = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, State { counter: 0 });
+    let received = first.clone();
+    store.subscribe_owned(move |state: State| received.borrow_mut().push(state));
+    let received = second.clone();
+    store.subscribe_owned(move |state: State| received.borrow_mut().push(state));
+
+    store.dispatch(Action::Increment);
+
+    assert_eq!(CLONES.with(Cell::get), 2);
+    assert_eq!(*first.borrow(), vec![State { counter: 1 }]);
+    assert_eq!(*second.borrow(), vec![State { counter: 1 }]);
+}
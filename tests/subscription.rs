@@ -33,3 +33,15 @@ fn subscription_decrement() {
     store.subscribe(listener);
     store.dispatch(Action::Decrement);
 }
+
+#[test]
+fn has_subscriptions() {
+    let mut store = Store::new(reducer, 0);
+    assert!(!store.has_subscriptions());
+
+    let id = store.subscribe(|_: &State| {});
+    assert!(store.has_subscriptions());
+
+    store.unsubscribe(id);
+    assert!(!store.has_subscriptions());
+}
@@ -0,0 +1,55 @@
+use redux_rs::{Store, SubscriptionControl};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn subscription_unsubscribes_itself() {
+    let notified = Rc::new(RefCell::new(Vec::new()));
+    let others = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = notified.clone();
+    store.subscribe_with_control(move |state: &State, control: &SubscriptionControl| {
+        captured.borrow_mut().push(*state);
+        if *state == 2 {
+            control.unsubscribe();
+        }
+    });
+    let captured = others.clone();
+    store.subscribe(move |state: &State| captured.borrow_mut().push(*state));
+
+    for _ in 0..3 {
+        store.dispatch(Action::Increment);
+    }
+
+    assert_eq!(*notified.borrow(), vec![1, 2]);
+    assert_eq!(*others.borrow(), vec![1, 2, 3]);
+}
+
+#[test]
+fn subscription_control_id() {
+    let ids = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {});
+    let captured = ids.clone();
+    let id = store.subscribe_with_control(move |_: &State, control: &SubscriptionControl| {
+        captured.borrow_mut().push(control.id())
+    });
+
+    store.dispatch(Action::Increment);
+    assert_eq!(*ids.borrow(), vec![id]);
+    assert!(store.unsubscribe(id));
+}
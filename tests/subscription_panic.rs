@@ -0,0 +1,44 @@
+use redux_rs::{Store, SubscriptionPanicPolicy};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+type State = i8;
+
+fn reducer(state: &State, increment: i8) -> State {
+    state + increment
+}
+
+fn store_with_panicking_first(seen: &Rc<RefCell<Vec<(usize, State)>>>) -> Store<State, i8> {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| panic!("bad listener"));
+    for index in 1..3 {
+        let seen = seen.clone();
+        store.subscribe(move |state: &State| seen.borrow_mut().push((index, *state)));
+    }
+    store
+}
+
+#[test]
+fn subscription_panic_isolated() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut store = store_with_panicking_first(&seen);
+    store.set_subscription_panic_policy(SubscriptionPanicPolicy::Isolate);
+
+    store.dispatch(1);
+    store.dispatch(1);
+
+    assert_eq!(*store.state(), 2);
+    assert_eq!(*seen.borrow(), vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+}
+
+#[test]
+fn subscription_panic_propagated() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut store = store_with_panicking_first(&seen);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| store.dispatch(1)));
+
+    assert!(result.is_err());
+    assert!(seen.borrow().is_empty());
+}
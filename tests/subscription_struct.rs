@@ -0,0 +1,41 @@
+use redux_rs::{Store, Subscription};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+struct LabelledSubscriber {
+    label: &'static str,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl Subscription<State> for LabelledSubscriber {
+    fn update(&self, state: &State) {
+        self.log
+            .borrow_mut()
+            .push(format!("{}: {}", self.label, state));
+    }
+}
+
+#[test]
+fn subscription_struct() {
+    let mut store = Store::new(reducer, 0);
+    let log = Rc::new(RefCell::new(Vec::new()));
+    store.subscribe(LabelledSubscriber {
+        label: "counter",
+        log: log.clone(),
+    });
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert_eq!(*log.borrow(), vec!["counter: 1", "counter: 2"]);
+}
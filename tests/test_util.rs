@@ -0,0 +1,117 @@
+#![cfg(feature = "test-util")]
+
+use redux_rs::test::{
+    assert_halted, assert_no_change, assert_reducers_equivalent, assert_reduces, reduce_sequence,
+};
+use redux_rs::Store;
+
+type State = i8;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+        Action::Reset => 0,
+    }
+}
+
+#[test]
+fn reduce_sequence_increments() {
+    let state = reduce_sequence(
+        reducer,
+        0,
+        vec![Action::Increment, Action::Increment, Action::Increment],
+    );
+    assert_eq!(state, 3);
+}
+
+#[test]
+fn assert_reduces_decrement() {
+    assert_reduces(reducer, &0, Action::Decrement, -1);
+}
+
+#[test]
+#[should_panic]
+fn assert_reduces_mismatch() {
+    assert_reduces(reducer, &0, Action::Increment, -1);
+}
+
+fn halting_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Decrement => None,
+        action => Some(action),
+    }
+}
+
+#[test]
+fn assert_no_change_noop() {
+    let mut store = Store::new(reducer, 0);
+    assert_no_change(&mut store, Action::Reset);
+}
+
+#[test]
+#[should_panic]
+fn assert_no_change_changed() {
+    let mut store = Store::new(reducer, 0);
+    assert_no_change(&mut store, Action::Increment);
+}
+
+#[test]
+fn assert_halted_by_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(halting_middleware);
+    assert_halted(&mut store, Action::Decrement);
+}
+
+#[test]
+#[should_panic(expected = "action was not halted")]
+fn assert_halted_reached_reducer() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(halting_middleware);
+    assert_halted(&mut store, Action::Reset);
+}
+
+fn refactored_reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state.wrapping_add(1),
+        Action::Decrement => state.wrapping_sub(1),
+        Action::Reset => 0,
+    }
+}
+
+fn divergent_reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Reset => 1,
+        action => reducer(state, action),
+    }
+}
+
+#[test]
+fn assert_reducers_equivalent_refactor() {
+    assert_reducers_equivalent(
+        reducer,
+        refactored_reducer,
+        &[-1, 0, 1],
+        &[Action::Increment, Action::Decrement, Action::Reset],
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "reducers diverge for state 0 and action Reset: old reducer returned 0, new reducer returned 1"
+)]
+fn assert_reducers_equivalent_divergent() {
+    assert_reducers_equivalent(
+        reducer,
+        divergent_reducer,
+        &[0, 1],
+        &[Action::Increment, Action::Reset],
+    );
+}
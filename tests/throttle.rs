@@ -0,0 +1,89 @@
+use redux_rs::{ManualClock, Store, ThrottledSubscription};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+#[test]
+fn throttled_subscription_limits_runs() {
+    let clock = ManualClock::new();
+    let rendered = Rc::new(RefCell::new(Vec::new()));
+    let captured = rendered.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(
+        ThrottledSubscription::new(Duration::from_millis(16), move |state: &State| {
+            captured.borrow_mut().push(*state)
+        })
+        .with_clock(clock.clone()),
+    );
+
+    for _ in 0..10 {
+        store.dispatch(Action::Increment);
+        clock.advance(Duration::from_millis(5));
+    }
+
+    // Runs at 0ms, 20ms (after 16ms passed) and 40ms.
+    assert_eq!(*rendered.borrow(), vec![1, 5, 9]);
+}
+
+#[test]
+fn subscribe_throttled_runs_first_notification() {
+    let rendered = Rc::new(RefCell::new(Vec::new()));
+    let captured = rendered.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe_throttled(Duration::from_secs(60), move |state: &State| {
+        captured.borrow_mut().push(*state)
+    });
+
+    for _ in 0..10 {
+        store.dispatch(Action::Increment);
+    }
+
+    assert_eq!(*rendered.borrow(), vec![1]);
+}
+
+#[test]
+fn throttled_subscription_delivers_end_of_burst() {
+    let clock = ManualClock::new();
+    let rendered = Rc::new(RefCell::new(Vec::new()));
+    let captured = rendered.clone();
+    let throttled = ThrottledSubscription::new(Duration::from_millis(16), move |state: &State| {
+        captured.borrow_mut().push(*state)
+    })
+    .with_clock(clock.clone());
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(throttled.clone());
+
+    for _ in 0..3 {
+        store.dispatch(Action::Increment);
+    }
+    assert!(throttled.is_pending());
+
+    // Too early, the interval isn't over yet.
+    assert!(!throttled.flush());
+    assert_eq!(*rendered.borrow(), vec![1]);
+
+    clock.advance(Duration::from_millis(16));
+    assert!(throttled.flush());
+    assert!(!throttled.is_pending());
+    assert_eq!(*rendered.borrow(), vec![1, 3]);
+
+    // Nothing left to deliver.
+    clock.advance(Duration::from_millis(16));
+    assert!(!throttled.flush());
+    assert_eq!(*rendered.borrow(), vec![1, 3]);
+}
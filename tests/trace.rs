@@ -0,0 +1,77 @@
+#![cfg(feature = "test-util")]
+
+use redux_rs::test::{TraceEvent, TraceRecorder};
+use redux_rs::Store;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+fn passthrough_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    Some(action)
+}
+
+fn only_increment_middleware(_: &Store<State, Action>, action: Action) -> Option<Action> {
+    match action {
+        Action::Increment => Some(action),
+        Action::Decrement => None,
+    }
+}
+
+fn store() -> (Store<State, Action>, TraceRecorder) {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(passthrough_middleware);
+    store.add_middleware(only_increment_middleware);
+    store.subscribe(|_: &State| {});
+    store.subscribe(|_: &State| {});
+
+    let recorder = TraceRecorder::new();
+    store.set_trace_recorder(recorder.clone());
+    (store, recorder)
+}
+
+#[test]
+fn trace_forwarded() {
+    let (mut store, recorder) = store();
+    store.dispatch(Action::Increment);
+    assert_eq!(
+        recorder.events(),
+        vec![
+            TraceEvent::MiddlewareEntered(0),
+            TraceEvent::MiddlewareForwarded(0),
+            TraceEvent::MiddlewareEntered(1),
+            TraceEvent::MiddlewareForwarded(1),
+            TraceEvent::ReducerRan,
+            TraceEvent::SubscriptionFired(0),
+            TraceEvent::SubscriptionFired(1),
+        ]
+    );
+}
+
+#[test]
+fn trace_halted() {
+    let (mut store, recorder) = store();
+    store.dispatch(Action::Decrement);
+    assert_eq!(
+        recorder.events(),
+        vec![
+            TraceEvent::MiddlewareEntered(0),
+            TraceEvent::MiddlewareForwarded(0),
+            TraceEvent::MiddlewareEntered(1),
+            TraceEvent::MiddlewareHalted(1),
+        ]
+    );
+
+    recorder.clear();
+    assert!(recorder.events().is_empty());
+}
@@ -0,0 +1,92 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+type State = i8;
+
+enum Action {
+    Increment,
+    Decrement,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Decrement => state - 1,
+    }
+}
+
+fn recorded_store() -> (Store<State, Action>, Rc<RefCell<Vec<State>>>) {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let recorded = seen.clone();
+
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(move |state: &State| recorded.borrow_mut().push(*state));
+    (store, seen)
+}
+
+#[test]
+fn transaction_commit() {
+    let (mut store, seen) = recorded_store();
+
+    let committed = store.transaction(|store| {
+        store.dispatch(Action::Increment);
+        store.dispatch(Action::Increment);
+        true
+    });
+
+    assert!(committed);
+    assert_eq!(*store.state(), 2);
+    assert_eq!(*seen.borrow(), vec![2]);
+}
+
+#[test]
+fn transaction_rollback() {
+    let (mut store, seen) = recorded_store();
+    store.dispatch(Action::Increment);
+
+    let committed = store.transaction(|store| {
+        store.dispatch(Action::Decrement);
+        store.dispatch(Action::Decrement);
+        *store.state() >= 0
+    });
+
+    assert!(!committed);
+    assert_eq!(*store.state(), 1);
+    assert_eq!(*seen.borrow(), vec![1]);
+}
+
+#[test]
+fn transaction_nested_rollback() {
+    let (mut store, seen) = recorded_store();
+
+    store.transaction(|store| {
+        store.dispatch(Action::Increment);
+        store.transaction(|store| {
+            store.dispatch(Action::Increment);
+            false
+        });
+        assert_eq!(*store.state(), 1);
+        true
+    });
+
+    assert_eq!(*store.state(), 1);
+    assert_eq!(*seen.borrow(), vec![1]);
+}
+
+#[test]
+fn transaction_ends_on_panic() {
+    let (mut store, seen) = recorded_store();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        store.transaction(|store| {
+            store.dispatch(Action::Increment);
+            panic!("boom")
+        })
+    }));
+    assert!(result.is_err());
+
+    store.dispatch(Action::Decrement);
+    assert_eq!(*seen.borrow(), vec![0]);
+}
@@ -0,0 +1,92 @@
+use redux_rs::{checked_reduce, try_combine, Store, TryReducible};
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    balance: u8,
+    withdrawals: u8,
+}
+
+#[derive(Clone)]
+enum Action {
+    Withdraw(u8),
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    InsufficientFunds,
+    TooManyWithdrawals,
+}
+
+fn withdraw(state: &State, action: Action) -> Result<State, Error> {
+    match action {
+        Action::Withdraw(amount) => Ok(State {
+            balance: state
+                .balance
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientFunds)?,
+            withdrawals: state.withdrawals,
+        }),
+    }
+}
+
+fn count_withdrawals(state: &State, _: Action) -> Result<State, Error> {
+    if state.withdrawals >= 2 {
+        return Err(Error::TooManyWithdrawals);
+    }
+    Ok(State {
+        balance: state.balance,
+        withdrawals: state.withdrawals + 1,
+    })
+}
+
+#[test]
+fn try_combine_chains() {
+    let reducer = try_combine(withdraw, count_withdrawals);
+    let state = State {
+        balance: 10,
+        withdrawals: 0,
+    };
+
+    assert_eq!(
+        reducer.try_reduce(&state, Action::Withdraw(3)),
+        Ok(State {
+            balance: 7,
+            withdrawals: 1
+        })
+    );
+}
+
+#[test]
+fn try_combine_second_errors() {
+    let reducer = try_combine(withdraw, count_withdrawals);
+    let state = State {
+        balance: 10,
+        withdrawals: 2,
+    };
+
+    assert_eq!(
+        reducer.try_reduce(&state, Action::Withdraw(3)),
+        Err(Error::TooManyWithdrawals)
+    );
+
+    let mut store = Store::new(
+        checked_reduce(move |state: &State, action: Action| reducer.try_reduce(state, action).ok()),
+        state.clone(),
+    );
+    store.dispatch(Action::Withdraw(3));
+    assert_eq!(*store.state(), state);
+}
+
+#[test]
+fn try_combine_first_errors() {
+    let reducer = try_combine(withdraw, count_withdrawals);
+    let state = State {
+        balance: 1,
+        withdrawals: 0,
+    };
+
+    assert_eq!(
+        reducer.try_reduce(&state, Action::Withdraw(3)),
+        Err(Error::InsufficientFunds)
+    );
+}
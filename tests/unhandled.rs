@@ -0,0 +1,57 @@
+use redux_rs::Store;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Action {
+    Increment,
+    Unknown,
+}
+
+fn reducer(state: &State, action: Action) -> Cow<'_, State> {
+    match action {
+        Action::Increment => Cow::Owned(state + 1),
+        Action::Unknown => Cow::Borrowed(state),
+    }
+}
+
+fn plain_reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+        Action::Unknown => *state,
+    }
+}
+
+#[test]
+fn unhandled_action_triggers_handler() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let recorded = unhandled.clone();
+
+    let mut store = Store::new_cow(reducer, 0);
+    store.set_unhandled_action_handler(move |state: &State, action: &Action| {
+        recorded.borrow_mut().push((*state, action.clone()))
+    });
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Unknown);
+
+    assert_eq!(*unhandled.borrow(), vec![(1, Action::Unknown)]);
+}
+
+#[test]
+fn unhandled_action_plain_reducer() {
+    let unhandled = Rc::new(RefCell::new(Vec::new()));
+    let recorded = unhandled.clone();
+
+    let mut store = Store::new(plain_reducer, 0);
+    store.set_unhandled_action_handler(move |_: &State, action: &Action| {
+        recorded.borrow_mut().push(action.clone())
+    });
+
+    store.dispatch(Action::Unknown);
+
+    assert!(unhandled.borrow().is_empty());
+}
@@ -0,0 +1,36 @@
+use redux_rs::Store;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type State = i8;
+
+fn reducer(state: &State, increment: i8) -> State {
+    state + increment
+}
+
+#[test]
+fn update_state_notifies_subscriptions() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let mut store = Store::new(reducer, 0);
+    let captured = seen.clone();
+    store.subscribe(move |state: &State| captured.borrow_mut().push(*state));
+
+    store.update_state(|state| state + 1);
+    store.dispatch(2);
+    store.update_state(|state| state + 1);
+
+    assert_eq!(*store.state(), 4);
+    assert_eq!(*seen.borrow(), vec![1, 3, 4]);
+}
+
+#[test]
+fn update_state_skips_middleware() {
+    let mut store = Store::new(reducer, 0);
+    store.add_middleware(|_: &Store<State, i8>, _: i8| None);
+
+    store.dispatch(1);
+    store.update_state(|state| state + 5);
+
+    assert_eq!(*store.state(), 5);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "async")]
+
+use redux_rs::Store;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn poll<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+    Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+}
+
+#[test]
+fn wait_for_target_state() {
+    let mut store = Store::new(reducer, 0);
+    let mut ready = store.wait_for(|state: &State| *state == 3);
+
+    store.dispatch(Action::Increment);
+    store.dispatch(Action::Increment);
+    assert!(poll(&mut ready).is_pending());
+
+    store.dispatch(Action::Increment);
+    assert!(poll(&mut ready).is_ready());
+}
+
+#[test]
+fn wait_for_already_satisfied() {
+    let mut store = Store::new(reducer, 5);
+    let mut ready = store.wait_for(|state: &State| *state > 0);
+    assert!(poll(&mut ready).is_ready());
+}
+
+#[test]
+fn wait_for_unsubscribes_once_resolved() {
+    let mut store = Store::new(reducer, 0);
+    store.subscribe(|_: &State| {});
+    let mut ready = store.wait_for(|state: &State| *state == 1);
+    assert_eq!(store.subscription_count(), 2);
+
+    store.dispatch(Action::Increment);
+    assert!(poll(&mut ready).is_ready());
+    assert_eq!(store.subscription_count(), 1);
+}
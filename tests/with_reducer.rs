@@ -0,0 +1,40 @@
+use redux_rs::Store;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+type State = i8;
+
+enum Action {
+    Increment,
+}
+
+fn reducer(state: &State, action: Action) -> State {
+    match action {
+        Action::Increment => state + 1,
+    }
+}
+
+fn stub_reducer(_: &State, _: Action) -> State {
+    100
+}
+
+#[test]
+fn with_reducer_restores() {
+    let mut store = Store::new(reducer, 0);
+    store.with_reducer(stub_reducer, |store| {
+        store.dispatch(Action::Increment);
+        assert_eq!(*store.state(), 100);
+    });
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 101);
+}
+
+#[test]
+fn with_reducer_restores_on_panic() {
+    let mut store = Store::new(reducer, 0);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        store.with_reducer(stub_reducer, |_| panic!("scope failed"));
+    }));
+    assert!(result.is_err());
+    store.dispatch(Action::Increment);
+    assert_eq!(*store.state(), 1);
+}